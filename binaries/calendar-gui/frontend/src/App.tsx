@@ -5,7 +5,8 @@ import { DayView } from './components/Calendar/DayView';
 import { TerminalInput } from './components/Terminal/TerminalInput';
 import { SettingsPanel } from './components/Settings/SettingsPanel';
 import { EventModal } from './components/Event/EventModal';
-import { CalendarEvent } from './types/event';
+import { CategoryLegend } from './components/Calendar/CategoryLegend';
+import { CalendarEvent, CategoryDefinition } from './types/event';
 import { invoke } from '@tauri-apps/api/tauri';
 
 type ViewMode = 'month' | 'week' | 'day';
@@ -19,6 +20,12 @@ function App() {
   const [showEventModal, setShowEventModal] = useState(false);
   const [selectedDate, setSelectedDate] = useState<string | null>(null);
   const [viewMode, setViewMode] = useState<ViewMode>('month');
+  const [categories, setCategories] = useState<CategoryDefinition[]>([]);
+  const [holidays, setHolidays] = useState<CalendarEvent[]>([]);
+
+  useEffect(() => {
+    loadCategories();
+  }, []);
 
   useEffect(() => {
     loadEvents();
@@ -40,6 +47,7 @@ function App() {
       });
       
       setEvents(loadedEvents);
+      loadHolidays(startDateStr, endDateStr);
     } catch (error) {
       console.error('Failed to load events:', error);
     } finally {
@@ -47,6 +55,26 @@ function App() {
     }
   };
 
+  // Holidays of the country in the user's locale, e.g. `en-GB` -> `GB`
+  const loadHolidays = async (startDate: string, endDate: string) => {
+    const country = navigator.language.split('-')[1];
+    if (!country) return;
+    try {
+      setHolidays(await invoke<CalendarEvent[]>('get_holidays', { startDate, endDate, country }));
+    } catch (error) {
+      // No built-in calendar for this country
+      setHolidays([]);
+    }
+  };
+
+  const loadCategories = async () => {
+    try {
+      setCategories(await invoke<CategoryDefinition[]>('get_categories'));
+    } catch (error) {
+      console.error('Failed to load categories:', error);
+    }
+  };
+
   const handleEventClick = (event: CalendarEvent) => {
     setSelectedEvent(event);
     setShowEventModal(true);
@@ -118,6 +146,8 @@ function App() {
                 events={events}
                 onEventClick={handleEventClick}
                 onDateClick={handleDateClick}
+                categories={categories}
+                holidays={holidays}
               />
             )}
             {viewMode === 'week' && (
@@ -148,6 +178,7 @@ function App() {
                 }
               }}
             />
+            <CategoryLegend categories={categories} />
           </div>
         </div>
       </main>
@@ -168,6 +199,7 @@ function App() {
             setSelectedDate(null);
           }}
           onSave={loadEvents}
+          categories={categories}
         />
       )}
     </div>
@@ -1,6 +1,6 @@
 import React, { useMemo, useState, useEffect } from 'react';
-import { CalendarEvent } from '../../types/event';
-import { generateCalendarDays } from '../../utils/date';
+import { CalendarEvent, CategoryDefinition, categoryColor } from '../../types/event';
+import { generateCalendarDays, occursOn } from '../../utils/date';
 
 interface CalendarGridProps {
   currentDate: Date;
@@ -8,6 +8,9 @@ interface CalendarGridProps {
   onEventClick?: (event: CalendarEvent) => void;
   onDateClick?: (date: Date) => void;
   onEventDrop?: (event: CalendarEvent, newDate: string) => void;
+  categories?: CategoryDefinition[];
+  /** Read-only all-day overlays from `get_holidays` */
+  holidays?: CalendarEvent[];
 }
 
 export const CalendarGrid: React.FC<CalendarGridProps> = ({
@@ -15,6 +18,8 @@ export const CalendarGrid: React.FC<CalendarGridProps> = ({
   events,
   onEventClick,
   onDateClick,
+  categories = [],
+  holidays = [],
 }) => {
   const [currentDate, setCurrentDate] = useState(propCurrentDate);
   const [draggedEvent, setDraggedEvent] = useState<CalendarEvent | null>(null);
@@ -45,9 +50,11 @@ export const CalendarGrid: React.FC<CalendarGridProps> = ({
         updatedAt: new Date().toISOString(),
       };
       
+      // Dragging an occurrence moves just that occurrence
       await invoke('update_event', {
         eventId: draggedEvent.id,
         eventData: updatedEvent,
+        scope: draggedEvent.seriesId ? 'this' : undefined,
       });
       
       setDraggedEvent(null);
@@ -122,7 +129,8 @@ export const CalendarGrid: React.FC<CalendarGridProps> = ({
           const dateStr = day.date.toISOString().split('T')[0];
           const isToday = day.isToday;
           const isCurrentMonth = day.isCurrentMonth;
-          const dayEvents = events.filter(e => e.date === dateStr);
+          const dayEvents = events.filter(e => occursOn(e, dateStr));
+          const dayHolidays = holidays.filter(h => h.date === dateStr);
 
           return (
             <div
@@ -143,6 +151,15 @@ export const CalendarGrid: React.FC<CalendarGridProps> = ({
               `}>
                 {day.date.getDate()}
               </div>
+              {dayHolidays.map((holiday) => (
+                <div
+                  key={holiday.id}
+                  className="text-xs text-amber-400 truncate"
+                  title={holiday.event}
+                >
+                  🎉 {holiday.event}
+                </div>
+              ))}
               {dayEvents.length > 0 && (
                 <div className="mt-1 space-y-1">
                   {dayEvents.slice(0, 3).map((event) => {
@@ -153,23 +170,13 @@ export const CalendarGrid: React.FC<CalendarGridProps> = ({
                       low: 'border-l-4 border-gray-500',
                     };
                     
-                    const categoryColors = {
-                      work: '#3B82F6',
-                      personal: '#10B981',
-                      health: '#EF4444',
-                      social: '#8B5CF6',
-                      finance: '#F59E0B',
-                      education: '#06B6D4',
-                      other: '#6B7280',
-                    };
-                    
                     return (
                       <div
                         key={event.id}
                         draggable
                         onDragStart={(e) => handleDragStart(e, event)}
                         className={`text-xs px-1 py-0.5 rounded truncate flex items-center gap-1 ${priorityColors[event.priority]}`}
-                        style={{ backgroundColor: event.color || categoryColors[event.category] || '#6B7280' }}
+                        style={{ backgroundColor: event.color || categoryColor(event.category, categories) }}
                         onClick={(e) => {
                           e.stopPropagation();
                           onEventClick?.(event);
@@ -0,0 +1,33 @@
+import React from 'react';
+import { CategoryDefinition } from '../../types/event';
+
+interface CategoryLegendProps {
+  categories: CategoryDefinition[];
+}
+
+/** Colors and icons of the built-in and user-defined categories */
+export const CategoryLegend: React.FC<CategoryLegendProps> = ({ categories }) => {
+  if (categories.length === 0) return null;
+
+  return (
+    <div className="bg-gray-800 rounded-xl p-4 mt-6">
+      <h3 className="text-sm font-semibold text-gray-400 mb-3">Categories</h3>
+      <ul className="grid grid-cols-2 gap-2">
+        {categories.map((definition) => (
+          <li
+            key={definition.category}
+            className="flex items-center gap-2 text-sm"
+            title={definition.keywords.join(', ')}
+          >
+            <span
+              className="w-3 h-3 rounded-full"
+              style={{ backgroundColor: definition.color }}
+            />
+            {definition.icon && <span>{definition.icon}</span>}
+            <span>{definition.displayName}</span>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+};
@@ -1,5 +1,6 @@
 import React from 'react';
 import { CalendarEvent } from '../../types/event';
+import { occursOn } from '../../utils/date';
 
 interface DayViewProps {
   currentDate: Date;
@@ -9,7 +10,7 @@ interface DayViewProps {
 
 export const DayView: React.FC<DayViewProps> = ({ currentDate, events, onEventClick }) => {
   const dateStr = currentDate.toISOString().split('T')[0];
-  const dayEvents = events.filter((e) => e.date === dateStr);
+  const dayEvents = events.filter((e) => occursOn(e, dateStr));
   const hours = Array.from({ length: 24 }, (_, i) => i);
   
   return (
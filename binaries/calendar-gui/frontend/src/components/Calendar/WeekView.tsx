@@ -1,5 +1,6 @@
 import React from 'react';
 import { CalendarEvent } from '../../types/event';
+import { occursOn } from '../../utils/date';
 
 interface WeekViewProps {
   currentDate: Date;
@@ -43,8 +44,8 @@ export const WeekView: React.FC<WeekViewProps> = ({ currentDate, events, onEvent
               const dateStr = day.toISOString().split('T')[0];
               const hourStr = hour.toString().padStart(2, '0');
               const dayEvents = events.filter((e) => {
-                if (e.date !== dateStr) return false;
-                if (!e.time) return hour === 0;
+                if (!occursOn(e, dateStr)) return false;
+                if (e.allDay || !e.time || e.date !== dateStr) return hour === 0;
                 const eventHour = parseInt(e.time.split(':')[0]);
                 return eventHour === hour;
               });
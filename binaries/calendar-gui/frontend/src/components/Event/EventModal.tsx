@@ -1,5 +1,5 @@
 import React, { useState } from 'react';
-import { CalendarEvent } from '../../types/event';
+import { Attendee, CalendarEvent, CategoryDefinition, EditScope, FieldError, ParticipationStatus, categoryInfo } from '../../types/event';
 import { invoke } from '@tauri-apps/api/tauri';
 
 interface EventModalProps {
@@ -7,9 +7,14 @@ interface EventModalProps {
   date?: string;
   onClose: () => void;
   onSave: () => void;
+  categories?: CategoryDefinition[];
 }
 
-export const EventModal: React.FC<EventModalProps> = ({ event, date, onClose, onSave }) => {
+export const EventModal: React.FC<EventModalProps> = ({ event, date, onClose, onSave, categories = [] }) => {
+  const categoryOptions = categories.length > 0
+    ? categories.map((c) => ({ value: c.category, label: c.displayName }))
+    : Object.entries(categoryInfo).map(([value, info]) => ({ value, label: info.name }));
+
   const [formData, setFormData] = useState({
     event: event?.event || '',
     date: event?.date || date || new Date().toISOString().split('T')[0],
@@ -19,15 +24,31 @@ export const EventModal: React.FC<EventModalProps> = ({ event, date, onClose, on
     priority: event?.priority || 'medium',
     category: event?.category || 'personal',
     tags: event?.tags?.join(', ') || '',
+    attendees: event?.attendees?.map(a => a.email).join(', ') || '',
   });
   
+  const [attendees, setAttendees] = useState<Attendee[]>(event?.attendees || []);
+  
+  const [scope, setScope] = useState<EditScope>('this');
   const [saving, setSaving] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [fieldErrors, setFieldErrors] = useState<FieldError[]>([]);
+  
+  const handleRsvp = async (email: string, status: ParticipationStatus) => {
+    if (!event?.id) return;
+    try {
+      const updated = await invoke<CalendarEvent>('set_attendee_status', { eventId: event.id, email, status });
+      setAttendees(updated.attendees);
+    } catch (err) {
+      setError(err as string);
+    }
+  };
   
   const handleSubmit = async (e: React.FormEvent) => {
     e.preventDefault();
     setSaving(true);
     setError(null);
+    setFieldErrors([]);
     
     try {
       const eventData = {
@@ -40,11 +61,33 @@ export const EventModal: React.FC<EventModalProps> = ({ event, date, onClose, on
         priority: formData.priority,
         category: formData.category,
         tags: formData.tags.split(',').map(t => t.trim()).filter(t => t),
+        // Keep role and RSVP for people already invited
+        attendees: formData.attendees.split(',').map(e => e.trim()).filter(e => e).map(email =>
+          attendees.find(a => a.email.toLowerCase() === email.toLowerCase())
+            ?? { email, role: 'required', status: 'needsAction' }),
         updatedAt: new Date().toISOString(),
       };
       
-      if (event?.id) {
-        await invoke('update_event', { eventId: event.id, eventData });
+      const problems = await invoke<FieldError[]>('validate_event', { eventData });
+      if (problems.length > 0) {
+        setFieldErrors(problems);
+        return;
+      }
+      
+      if (event?.id && !event.seriesId) {
+        // Send only what changed so concurrent edits to other fields survive
+        const patch = Object.fromEntries(
+          Object.entries(eventData).filter(([key, value]) =>
+            key !== 'updatedAt'
+            && JSON.stringify(value) !== JSON.stringify(event[key as keyof CalendarEvent] ?? null))
+        );
+        await invoke('patch_event', { eventId: event.id, patch });
+      } else if (event?.id) {
+        await invoke('update_event', {
+          eventId: event.id,
+          eventData,
+          scope,
+        });
       } else {
         await invoke('create_event', { eventData });
       }
@@ -71,6 +114,16 @@ export const EventModal: React.FC<EventModalProps> = ({ event, date, onClose, on
           </div>
         )}
         
+        {fieldErrors.length > 0 && (
+          <ul className="bg-red-500 bg-opacity-20 border border-red-500 text-red-300 px-4 py-2 rounded mb-4 list-disc list-inside">
+            {fieldErrors.map((fieldError) => (
+              <li key={`${fieldError.field}:${fieldError.message}`}>
+                <span className="font-mono">{fieldError.field}</span>: {fieldError.message}
+              </li>
+            ))}
+          </ul>
+        )}
+        
         <form onSubmit={handleSubmit} className="space-y-4">
           <div>
             <label className="block text-sm font-medium mb-1">Event Title</label>
@@ -114,13 +167,9 @@ export const EventModal: React.FC<EventModalProps> = ({ event, date, onClose, on
                 onChange={(e) => setFormData({ ...formData, category: e.target.value })}
                 className="w-full px-3 py-2 bg-gray-700 rounded border border-gray-600 focus:border-blue-500 focus:outline-none"
               >
-                <option value="work">Work</option>
-                <option value="personal">Personal</option>
-                <option value="health">Health</option>
-                <option value="social">Social</option>
-                <option value="finance">Finance</option>
-                <option value="education">Education</option>
-                <option value="other">Other</option>
+                {categoryOptions.map((option) => (
+                  <option key={option.value} value={option.value}>{option.label}</option>
+                ))}
               </select>
             </div>
             
@@ -160,6 +209,55 @@ export const EventModal: React.FC<EventModalProps> = ({ event, date, onClose, on
             />
           </div>
           
+          <div>
+            <label className="block text-sm font-medium mb-1">Attendees (comma-separated emails)</label>
+            <input
+              type="text"
+              value={formData.attendees}
+              onChange={(e) => setFormData({ ...formData, attendees: e.target.value })}
+              className="w-full px-3 py-2 bg-gray-700 rounded border border-gray-600 focus:border-blue-500 focus:outline-none"
+              placeholder="ana@example.com, bo@example.com"
+            />
+            {event?.id && !event.seriesId && attendees.length > 0 && (
+              <ul className="mt-2 space-y-1">
+                {attendees.map((attendee) => (
+                  <li key={attendee.email} className="flex items-center justify-between text-sm">
+                    <span>
+                      {attendee.name || attendee.email}
+                      {attendee.role === 'organizer' && <span className="text-gray-400"> (organizer)</span>}
+                    </span>
+                    <select
+                      value={attendee.status}
+                      onChange={(e) => handleRsvp(attendee.email, e.target.value as ParticipationStatus)}
+                      className="px-2 py-1 bg-gray-700 rounded border border-gray-600"
+                    >
+                      <option value="needsAction">No response</option>
+                      <option value="accepted">Accepted</option>
+                      <option value="tentative">Tentative</option>
+                      <option value="declined">Declined</option>
+                      <option value="delegated">Delegated</option>
+                    </select>
+                  </li>
+                ))}
+              </ul>
+            )}
+          </div>
+          
+          {event?.seriesId && (
+            <div>
+              <label className="block text-sm font-medium mb-1">Apply changes to</label>
+              <select
+                value={scope}
+                onChange={(e) => setScope(e.target.value as EditScope)}
+                className="w-full px-3 py-2 bg-gray-700 rounded border border-gray-600 focus:border-blue-500 focus:outline-none"
+              >
+                <option value="this">This event</option>
+                <option value="thisAndFollowing">This and following events</option>
+                <option value="all">All events</option>
+              </select>
+            </div>
+          )}
+          
           <div className="flex justify-end gap-3 pt-4">
             <button
               type="button"
@@ -37,7 +37,7 @@ export const TerminalInput: React.FC<TerminalInputProps> = ({ onEventCreated })
         status: 'confirmed',
         visibility: 'private',
         recurring: null,
-        reminder: null,
+        reminders: [],
         location: null,
         metadata: {},
       };
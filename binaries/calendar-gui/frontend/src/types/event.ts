@@ -3,10 +3,39 @@ import { z } from 'zod';
 const uuidSchema = z.string().uuid();
 const timestampSchema = z.string().datetime();
 const prioritySchema = z.enum(['low', 'medium', 'high', 'urgent']);
-const categorySchema = z.enum(['work', 'personal', 'health', 'social', 'finance', 'education', 'other']);
+// Built-in names or user-defined lowercase slugs (see `get_categories`)
+const categorySchema = z.string().max(50).regex(/^[a-z0-9][a-z0-9_-]*$/);
 const eventStatusSchema = z.enum(['tentative', 'confirmed', 'cancelled', 'completed']);
 const visibilitySchema = z.enum(['public', 'private']);
 
+const attendeeRoleSchema = z.enum(['organizer', 'chair', 'required', 'optional', 'nonParticipant']);
+const participationStatusSchema = z.enum(['needsAction', 'accepted', 'declined', 'tentative', 'delegated']);
+
+export const attendeeSchema = z.object({
+  name: z.string().nullish(),
+  email: z.string().email(),
+  role: attendeeRoleSchema.default('required'),
+  status: participationStatusSchema.default('needsAction'),
+});
+
+const reminderChannelSchema = z.enum(['display', 'audio', 'email']);
+
+export const reminderSchema = z.object({
+  minutesBefore: z.number().int().min(0).max(4 * 7 * 24 * 60),
+  repeatMinutes: z.number().int().min(1).nullish(),
+  maxReminders: z.number().int().min(1).default(1),
+  at: z.string().datetime({ offset: true }).nullish(),
+  channel: reminderChannelSchema.default('display'),
+});
+
+export const categoryDefinitionSchema = z.object({
+  category: categorySchema,
+  displayName: z.string().min(1).max(50),
+  color: z.string().regex(/^#[0-9A-Fa-f]{6}$/),
+  icon: z.string().nullish(),
+  keywords: z.array(z.string().min(1).max(50)).default([]),
+});
+
 export const calendarEventSchema = z.object({
   id: z.string().uuid(),
   createdAt: z.string().datetime(),
@@ -14,25 +43,44 @@ export const calendarEventSchema = z.object({
   date: z.string().regex(/^\d{4}-\d{2}-\d{2}$/),
   time: z.string().regex(/^\d{2}:\d{2}$/).optional(),
   endTime: z.string().regex(/^\d{2}:\d{2}$/).optional(),
+  endDate: z.string().regex(/^\d{4}-\d{2}-\d{2}$/).optional(),
+  allDay: z.boolean().default(false),
+  timezone: z.string().optional(),
   event: z.string().min(1).max(500),
   notes: z.string().max(5000).optional(),
   priority: prioritySchema.default('medium'),
   category: categorySchema.default('other'),
   color: z.string().regex(/^#[0-9A-Fa-f]{6}$/).optional(),
-  tags: z.array(z.string()).default([]),
+  tags: z.array(z.string().min(1).max(50)).default([]),
   status: eventStatusSchema.default('confirmed'),
   visibility: visibilitySchema.default('private'),
   recurring: z.any().optional(),
-  reminder: z.any().optional(),
+  seriesId: z.string().uuid().nullish(),
+  recurrenceId: z.string().regex(/^\d{4}-\d{2}-\d{2}$/).nullish(),
+  reminders: z.array(reminderSchema).default([]),
   location: z.any().optional(),
+  attendees: z.array(attendeeSchema).default([]),
   metadata: z.record(z.unknown()).default({}),
 });
 
 export type CalendarEvent = z.infer<typeof calendarEventSchema>;
+export type Attendee = z.infer<typeof attendeeSchema>;
+export type Reminder = z.infer<typeof reminderSchema>;
+export type ParticipationStatus = z.infer<typeof participationStatusSchema>;
+export type EditScope = 'this' | 'thisAndFollowing' | 'all';
+
+/** A validation problem reported by the backend, keyed by field path */
+export interface FieldError {
+  field: string;
+  message: string;
+}
 export type Priority = z.infer<typeof prioritySchema>;
 export type Category = z.infer<typeof categorySchema>;
+export type CategoryDefinition = z.infer<typeof categoryDefinitionSchema>;
+export type BuiltInCategory = 'work' | 'personal' | 'health' | 'social' | 'finance' | 'education' | 'other';
 
-export const categoryInfo: Record<Category, { name: string; color: string; icon: string }> = {
+/** Defaults for the built-in categories, used until `get_categories` answers */
+export const categoryInfo: Record<BuiltInCategory, { name: string; color: string; icon: string }> = {
   work: { name: 'Work', color: '#3B82F6', icon: '💼' },
   personal: { name: 'Personal', color: '#10B981', icon: '🏠' },
   health: { name: 'Health', color: '#EF4444', icon: '❤️' },
@@ -42,9 +90,147 @@ export const categoryInfo: Record<Category, { name: string; color: string; icon:
   other: { name: 'Other', color: '#6B7280', icon: '📌' },
 };
 
+/** Color of a category, from the loaded definitions or the built-in defaults */
+export function categoryColor(category: Category, definitions: CategoryDefinition[] = []): string {
+  const definition = definitions.find((d) => d.category === category);
+  return definition?.color ?? categoryInfo[category as BuiltInCategory]?.color ?? categoryInfo.other.color;
+}
+
 export const priorityInfo: Record<Priority, { level: number; label: string; emoji: string }> = {
   low: { level: 0, label: 'Low', emoji: '🟢' },
   medium: { level: 1, label: 'Medium', emoji: '🟡' },
   high: { level: 2, label: 'High', emoji: '🟠' },
   urgent: { level: 3, label: 'Urgent', emoji: '🔴' },
 };
+
+/** Working time of one day (`HH:MM`); breaks are cut out of it */
+export interface WorkingDay {
+  start: string;
+  end: string;
+  breaks?: { start: string; end: string }[];
+}
+
+/** Days left out are days off */
+export type WorkingHours = Partial<
+  Record<'monday' | 'tuesday' | 'wednesday' | 'thursday' | 'friday' | 'saturday' | 'sunday', WorkingDay>
+>;
+
+/** Argument of the `get_free_busy` command; times are `YYYY-MM-DDTHH:MM:SS` */
+export interface FreeBusyQuery {
+  start: string;
+  end: string;
+  minMinutes?: number;
+  workingHours?: WorkingHours;
+  bufferMinutes?: number;
+  holidays?: HolidayCalendar;
+}
+
+export interface FreeBusy {
+  busy: { start: string; end: string; eventIds: string[]; titles: string[] }[];
+  free: { start: string; end: string }[];
+}
+
+/** Argument of the `propose_slots` command; times as in `FreeBusyQuery` */
+export interface SlotRequest {
+  durationMinutes: number;
+  start: string;
+  end: string;
+  preferred?: { start: string; end: string };
+  priority?: Priority;
+  bufferMinutes?: number;
+  workingHours?: WorkingHours;
+  holidays?: HolidayCalendar;
+}
+
+/** Higher scores fit the request better */
+export interface SlotProposal {
+  start: string;
+  end: string;
+  score: number;
+}
+
+/** Options of the `check_conflicts` command */
+export interface ConflictCheck {
+  bufferMinutes?: number;
+  includeAllDay?: boolean;
+  horizonDays?: number;
+}
+
+/** Another event in the way of one occurrence of the checked event */
+export interface Conflict {
+  kind: 'overlap' | 'buffer' | 'allDay';
+  eventId: string;
+  seriesId: string | null;
+  title: string;
+  start: string;
+  end: string;
+  occurrenceStart: string;
+  overlapMinutes: number;
+  gapMinutes: number;
+}
+
+/** When a holiday falls each year; `n` is 1 to 5, or -1 for the last */
+export type HolidayRule =
+  | { rule: 'fixed'; month: number; day: number; observed?: 'actual' | 'nearestWeekday' | 'followingMonday' }
+  | { rule: 'nthWeekday'; month: number; weekday: 'Mon' | 'Tue' | 'Wed' | 'Thu' | 'Fri' | 'Sat' | 'Sun'; n: number }
+  | { rule: 'easter'; offset: number };
+
+export type Holiday = { name: string } & HolidayRule;
+
+/** Days off besides the weekend, as in a holiday calendar JSON file */
+export interface HolidayCalendar {
+  name: string;
+  holidays: Holiday[];
+  weekend?: ('Mon' | 'Tue' | 'Wed' | 'Thu' | 'Fri' | 'Sat' | 'Sun')[];
+}
+
+/**
+ * Result of the `search_events` command, which takes a query such as
+ * `cat:work pri:>=high -status:cancelled standup`
+ */
+export interface SearchHit {
+  event: CalendarEvent;
+  score: number;
+  /** Excerpt of the best matching field; matched terms are `highlighted` */
+  snippet: { text: string; highlighted: boolean }[];
+}
+
+/** Argument of the `query_events` command; dates are `YYYY-MM-DD` */
+export interface EventQuery {
+  start?: string;
+  end?: string;
+  categories?: Category[];
+  priorities?: Priority[];
+  statuses?: z.infer<typeof eventStatusSchema>[];
+  /** All must be present */
+  tags?: string[];
+  visibilities?: z.infer<typeof visibilitySchema>[];
+  text?: string;
+  hasReminder?: boolean;
+  hasLocation?: boolean;
+  excludeCategories?: Category[];
+  excludePriorities?: Priority[];
+  excludeStatuses?: z.infer<typeof eventStatusSchema>[];
+  excludeTags?: string[];
+  excludeVisibilities?: z.infer<typeof visibilitySchema>[];
+  /** Each is searched on its own and its matches left out */
+  excludeText?: string[];
+  sort?: 'start' | 'startDesc' | 'priority' | 'updated' | 'title';
+  limit?: number;
+  offset?: number;
+  /** `nextCursor` of the previous page */
+  cursor?: string;
+}
+
+export interface EventPage {
+  events: CalendarEvent[];
+  total: number;
+  nextCursor: string | null;
+}
+
+/** Event in the trash, returned by the `get_trash` command */
+export interface TrashedEvent {
+  event: CalendarEvent;
+  /** RFC 3339 timestamp */
+  deletedAt: string;
+}
@@ -1,3 +1,5 @@
+import { CalendarEvent } from '../types/event';
+
 interface CalendarDay {
   date: Date;
   isCurrentMonth: boolean;
@@ -53,3 +55,7 @@ export function parseDateString(dateStr: string): Date | null {
   const parsed = new Date(dateStr);
   return isNaN(parsed.getTime()) ? null : parsed;
 }
+
+export function occursOn(event: CalendarEvent, dateStr: string): boolean {
+  return event.date <= dateStr && (event.endDate ?? event.date) >= dateStr;
+}
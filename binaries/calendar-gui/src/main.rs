@@ -1,29 +1,61 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use std::path::PathBuf;
+//! Tauri commands behind the desktop calendar.
+//!
+//! Commands taking a `timezone` work in the viewer's zone, the local one
+//! when it is not given: events come back converted into it, and relative
+//! dates and wall-clock times in requests are read in it.
+
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tauri::{Manager, State};
 use storage_engine::CalendarRepository;
-use calendar_core::CalendarEvent;
+use calendar_core::{
+    CalendarEvent, CalendarEventBuilder, Category, CategoryDefinition, Conflict, ConflictCheck, EditScope, EventPage,
+    EventQuery, FieldError, FreeBusy, FreeBusyQuery, HolidayCalendar, OccurrenceId, ParticipationStatus,
+    RetentionPolicy, SearchHit, SlotProposal, SlotRequest, TrashedEvent, Validator, parse_query,
+};
+use calendar_core::time::{SharedClock, TimeZone, system_clock};
 
 struct AppState {
     repository: Arc<CalendarRepository>,
+    clock: SharedClock,
 }
 
 #[tauri::command]
 async fn get_events(
-    start_date: String,
-    end_date: String,
+    start_date: chrono::NaiveDate,
+    end_date: chrono::NaiveDate,
+    timezone: Option<String>,
     state: State<'_, AppState>,
 ) -> Result<Vec<CalendarEvent>, String> {
+    let zone = TimeZone::parse(timezone.as_deref().unwrap_or("local"))
+        .map_err(|e| e.to_string())?;
     let repository = state.repository.clone();
     
-    tokio::task::spawn_blocking(move || {
-        repository.get_by_date_range(&start_date, &end_date)
+    let events = tokio::task::spawn_blocking(move || {
+        repository.get_by_date_range(start_date, end_date)
     })
     .await
     .map_err(|e| format!("Task error: {}", e))?
-    .map_err(|e| format!("Failed to get events: {}", e))
+    .map_err(|e| format!("Failed to get events: {}", e))?;
+    
+    Ok(events.iter().map(|event| event.in_zone(zone, zone)).collect())
+}
+
+/// Stored events matching the query's filters, one page at a time;
+/// recurring series are returned once rather than per occurrence
+#[tauri::command]
+async fn query_events(
+    query: EventQuery,
+    state: State<'_, AppState>,
+) -> Result<EventPage, String> {
+    let repository = state.repository.clone();
+    
+    tokio::task::spawn_blocking(move || repository.query_events(&query))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to query events: {}", e))
 }
 
 #[tauri::command]
@@ -36,7 +68,8 @@ async fn create_event(
         .map_err(|e| format!("Invalid event data: {}", e))?;
     
     // Validate
-    event.validate()
+    let event = CalendarEventBuilder::from(event)
+        .build()
         .map_err(|e| format!("Event validation failed: {}", e))?;
     
     // Save to database (spawn_blocking for sync repository)
@@ -53,81 +86,310 @@ async fn create_event(
     Ok(event)
 }
 
+/// Field-level problems with an event, for the editor to show before saving
+#[tauri::command]
+fn validate_event(event_data: serde_json::Value) -> Result<Vec<FieldError>, String> {
+    let event: CalendarEvent = serde_json::from_value(event_data)
+        .map_err(|e| format!("Invalid event data: {}", e))?;
+    Ok(Validator::validate_event(&event))
+}
+
+/// What an event would run into, so the editor can warn before saving
+#[tauri::command]
+async fn check_conflicts(
+    event_data: serde_json::Value,
+    check: Option<ConflictCheck>,
+    timezone: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<Conflict>, String> {
+    let event: CalendarEvent = serde_json::from_value(event_data)
+        .map_err(|e| format!("Invalid event data: {}", e))?;
+    let zone = TimeZone::parse(timezone.as_deref().unwrap_or("local"))
+        .map_err(|e| e.to_string())?;
+    let check = check.unwrap_or_default();
+    check.validate().map_err(|e| format!("Invalid conflict check: {}", e))?;
+    let repository = state.repository.clone();
+
+    tokio::task::spawn_blocking(move || repository.check_conflicts(&event, &check, zone))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to check conflicts: {}", e))
+}
+
 #[tauri::command]
 async fn update_event(
     event_id: String,
     event_data: serde_json::Value,
+    scope: Option<EditScope>,
+    timezone: Option<String>,
     state: State<'_, AppState>,
 ) -> Result<CalendarEvent, String> {
+    let zone = TimeZone::parse(timezone.as_deref().unwrap_or("local"))
+        .map_err(|e| e.to_string())?;
     let mut event: CalendarEvent = serde_json::from_value(event_data)
         .map_err(|e| format!("Invalid event data: {}", e))?;
     
     // Ensure ID matches
     event.id = event_id.parse()
         .map_err(|_| "Invalid event ID".to_string())?;
-    event.updated_at = chrono::Utc::now();
+    event.updated_at = state.clock.now();
     
-    event.validate()
+    let event = CalendarEventBuilder::from(event)
+        .build()
         .map_err(|e| format!("Event validation failed: {}", e))?;
     
     let repository = state.repository.clone();
-    let event_clone = event.clone();
     
+    // The edited copy is the one shown in the viewer's zone; store it back
+    // in the zone of the event or series. Occurrences of a series are
+    // edited according to the scope.
     tokio::task::spawn_blocking(move || {
-        repository.save_event(&event_clone)
+        let stored = repository.get_by_id(&event.series_id.unwrap_or(event.id).to_string())?;
+        let event = match stored {
+            Some(stored) => event.in_zone(stored.zone(zone), zone),
+            None => event,
+        };
+        repository.update_occurrence(&event, scope.unwrap_or_default())?;
+        Ok::<_, calendar_core::AppError>(event)
+    })
+    .await
+    .map_err(|e| format!("Task error: {}", e))?
+    .map_err(|e| format!("Failed to update event: {}", e))
+}
+
+/// Change only the fields present in `patch` (JSON Merge Patch, RFC 7396);
+/// `null` clears a field
+#[tauri::command]
+async fn patch_event(
+    event_id: String,
+    patch: serde_json::Value,
+    timezone: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<CalendarEvent, String> {
+    let zone = TimeZone::parse(timezone.as_deref().unwrap_or("local"))
+        .map_err(|e| e.to_string())?;
+    let repository = state.repository.clone();
+    
+    tokio::task::spawn_blocking(move || {
+        repository.patch_event(&event_id, &patch, zone)
     })
     .await
     .map_err(|e| format!("Task error: {}", e))?
-    .map_err(|e| format!("Failed to update event: {}", e))?;
+    .map_err(|e| format!("Failed to patch event: {}", e))
+}
+
+/// Record an attendee's RSVP (`accepted`, `declined`, `tentative`, ...)
+#[tauri::command]
+async fn set_attendee_status(
+    event_id: String,
+    email: String,
+    status: ParticipationStatus,
+    state: State<'_, AppState>,
+) -> Result<CalendarEvent, String> {
+    let repository = state.repository.clone();
     
-    Ok(event)
+    tokio::task::spawn_blocking(move || {
+        repository.set_attendee_status(&event_id, &email, status)
+    })
+    .await
+    .map_err(|e| format!("Task error: {}", e))?
+    .map_err(|e| format!("Failed to update RSVP: {}", e))
 }
 
 #[tauri::command]
 async fn delete_event(
     event_id: String,
+    scope: Option<EditScope>,
     state: State<'_, AppState>,
 ) -> Result<bool, String> {
     let repository = state.repository.clone();
     
+    // `<series-id>:<date>` ids address a single occurrence of a series
     tokio::task::spawn_blocking(move || {
-        repository.delete_event(&event_id)
+        match event_id.parse::<OccurrenceId>() {
+            Ok(occurrence) => repository.delete_occurrence(&occurrence, scope.unwrap_or_default()),
+            Err(_) => repository.delete_event(&event_id),
+        }
     })
     .await
     .map_err(|e| format!("Task error: {}", e))?
     .map_err(|e| format!("Failed to delete event: {}", e))
 }
 
+/// Deleted events, most recently deleted first
 #[tauri::command]
-async fn search_events(
-    query: String,
+async fn get_trash(state: State<'_, AppState>) -> Result<Vec<TrashedEvent>, String> {
+    let repository = state.repository.clone();
+    
+    tokio::task::spawn_blocking(move || repository.get_trash())
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to load trash: {}", e))
+}
+
+#[tauri::command]
+async fn restore_event(event_id: String, state: State<'_, AppState>) -> Result<bool, String> {
+    let repository = state.repository.clone();
+    
+    tokio::task::spawn_blocking(move || repository.restore_event(&event_id))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to restore event: {}", e))
+}
+
+/// Permanently delete one event from the trash
+#[tauri::command]
+async fn purge_event(event_id: String, state: State<'_, AppState>) -> Result<bool, String> {
+    let repository = state.repository.clone();
+    
+    tokio::task::spawn_blocking(move || repository.purge_event(&event_id))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to purge event: {}", e))
+}
+
+/// Permanently delete events deleted more than `retention_days` ago, or
+/// everything in the trash when not given; returns how many were purged
+#[tauri::command]
+async fn empty_trash(retention_days: Option<u32>, state: State<'_, AppState>) -> Result<usize, String> {
+    let repository = state.repository.clone();
+    let policy = RetentionPolicy::days(retention_days.unwrap_or(0));
+    
+    tokio::task::spawn_blocking(move || repository.apply_retention(&policy))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to empty trash: {}", e))
+}
+
+/// Built-in and user-defined categories, for the legend and pickers
+#[tauri::command]
+async fn get_categories(state: State<'_, AppState>) -> Result<Vec<CategoryDefinition>, String> {
+    let repository = state.repository.clone();
+    
+    let categories = tokio::task::spawn_blocking(move || repository.get_categories())
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to get categories: {}", e))?;
+    
+    Ok(categories.definitions().to_vec())
+}
+
+/// Add a category, or change the color, icon or keywords of an existing one
+#[tauri::command]
+async fn save_category(
+    definition: serde_json::Value,
     state: State<'_, AppState>,
-) -> Result<Vec<CalendarEvent>, String> {
-    // Simple search - get all events and filter (can be optimized later)
-    let today = chrono::Local::now().date_naive();
-    let start_date = (today - chrono::Duration::days(365)).format("%Y-%m-%d").to_string();
-    let end_date = (today + chrono::Duration::days(365)).format("%Y-%m-%d").to_string();
+) -> Result<CategoryDefinition, String> {
+    let definition: CategoryDefinition = serde_json::from_value(definition)
+        .map_err(|e| format!("Invalid category: {}", e))?;
+    definition.validate()
+        .map_err(|e| format!("Category validation failed: {}", e))?;
     
     let repository = state.repository.clone();
+    let definition_clone = definition.clone();
     
-    let events = tokio::task::spawn_blocking(move || {
-        repository.get_by_date_range(&start_date, &end_date)
-    })
-    .await
-    .map_err(|e| format!("Task error: {}", e))?
-    .map_err(|e| format!("Failed to search events: {}", e))?;
+    tokio::task::spawn_blocking(move || repository.save_category(&definition_clone))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to save category: {}", e))?;
     
-    let query_lower = query.to_lowercase();
-    let filtered: Vec<CalendarEvent> = events
-        .into_iter()
-        .filter(|e| {
-            e.event.to_lowercase().contains(&query_lower) ||
-            e.notes.as_ref().map(|n| n.to_lowercase().contains(&query_lower)).unwrap_or(false) ||
-            e.tags.iter().any(|t| t.to_lowercase().contains(&query_lower))
-        })
-        .collect();
+    Ok(definition)
+}
+
+/// Remove a user-defined category, or reset a built-in to its defaults
+#[tauri::command]
+async fn delete_category(
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<bool, String> {
+    let category: Category = name.parse().map_err(|e: calendar_core::AppError| e.to_string())?;
+    let repository = state.repository.clone();
+    
+    tokio::task::spawn_blocking(move || repository.delete_category(&category))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to delete category: {}", e))
+}
+
+/// Busy blocks and free slots for the query
+#[tauri::command]
+async fn get_free_busy(
+    query: FreeBusyQuery,
+    timezone: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<FreeBusy, String> {
+    let zone = TimeZone::parse(timezone.as_deref().unwrap_or("local"))
+        .map_err(|e| e.to_string())?;
+    if let Some(hours) = &query.working_hours {
+        hours.validate().map_err(|e| format!("Invalid working hours: {}", e))?;
+    }
+    let repository = state.repository.clone();
+
+    tokio::task::spawn_blocking(move || repository.free_busy(&query, zone))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to compute free/busy: {}", e))
+}
+
+/// Best times for a new event
+#[tauri::command]
+async fn propose_slots(
+    request: SlotRequest,
+    timezone: Option<String>,
+    limit: Option<usize>,
+    state: State<'_, AppState>,
+) -> Result<Vec<SlotProposal>, String> {
+    let zone = TimeZone::parse(timezone.as_deref().unwrap_or("local"))
+        .map_err(|e| e.to_string())?;
+    if let Some(hours) = &request.working_hours {
+        hours.validate().map_err(|e| format!("Invalid working hours: {}", e))?;
+    }
+    let limit = limit.unwrap_or(calendar_core::scheduling::DEFAULT_PROPOSALS);
+    let repository = state.repository.clone();
+
+    tokio::task::spawn_blocking(move || repository.propose_slots(&request, zone, limit))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to propose slots: {}", e))
+}
+
+/// Public holidays between two dates as read-only all-day events, for
+/// showing on the calendar; `country` is a code like `US` or a path to a
+/// holiday calendar JSON file
+#[tauri::command]
+fn get_holidays(
+    start_date: chrono::NaiveDate,
+    end_date: chrono::NaiveDate,
+    country: String,
+) -> Result<Vec<CalendarEvent>, String> {
+    let calendar = match HolidayCalendar::country(&country) {
+        Ok(calendar) => calendar,
+        Err(_) => HolidayCalendar::load(Path::new(&country))
+            .map_err(|e| format!("Failed to load holidays: {}", e))?,
+    };
+    Ok(calendar.overlay_events(start_date, end_date))
+}
+
+/// Search with the query language, e.g. `cat:work pri:>=high standup`.
+/// Text matches come best first with a highlighted snippet.
+#[tauri::command]
+async fn search_events(
+    query: String,
+    limit: Option<usize>,
+    timezone: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<SearchHit>, String> {
+    let zone = TimeZone::parse(timezone.as_deref().unwrap_or("local"))
+        .map_err(|e| e.to_string())?;
+    let query = parse_query(&query, state.clock.today_in(zone))
+        .map_err(|e| e.to_string())?
+        .limit(limit.unwrap_or(calendar_core::search::DEFAULT_SEARCH_LIMIT));
+    let repository = state.repository.clone();
     
-    Ok(filtered)
+    tokio::task::spawn_blocking(move || repository.search(&query))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to search events: {}", e))
 }
 
 fn main() {
@@ -137,20 +399,43 @@ fn main() {
         .unwrap_or_else(|| PathBuf::from("calendar.db"));
     
     // Initialize repository (synchronous now)
+    let clock = system_clock();
     let repository = CalendarRepository::new(&db_path)
-        .expect("Failed to initialize database");
+        .expect("Failed to initialize database")
+        .with_clock(clock.clone());
+    
+    // Events deleted long enough ago are not coming back
+    if let Err(e) = repository.apply_retention(&RetentionPolicy::default()) {
+        eprintln!("Failed to purge trash: {}", e);
+    }
     
     let app_state = AppState {
         repository: Arc::new(repository),
+        clock,
     };
     
     tauri::Builder::default()
         .manage(app_state)
         .invoke_handler(tauri::generate_handler![
             get_events,
+            query_events,
             create_event,
+            validate_event,
+            check_conflicts,
             update_event,
+            patch_event,
+            set_attendee_status,
             delete_event,
+            get_trash,
+            restore_event,
+            purge_event,
+            empty_trash,
+            get_categories,
+            save_category,
+            delete_category,
+            get_free_busy,
+            propose_slots,
+            get_holidays,
             search_events
         ])
         .run(tauri::generate_context!())
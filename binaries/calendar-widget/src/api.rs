@@ -1 +1,135 @@
 pub use deepseek_client::{DeepSeekClient, DeepSeekConfig};
+
+use calendar_core::{
+    AppError, AppResult, CalendarEvent, CalendarEventBuilder, Location, RecurrenceConfig,
+    ReminderConfig,
+};
+use chrono::NaiveDate;
+use deepseek_client::models::CalendarEventOutput;
+use deepseek_client::prompts::PromptTemplates;
+
+/// Ask the AI to extract an event from free text, resolving relative dates
+/// against `today`
+pub async fn extract_event(
+    client: &DeepSeekClient,
+    input: &str,
+    today: NaiveDate,
+) -> AppResult<CalendarEventBuilder> {
+    let prompt = format!("Current date: {}\n{}", today, input);
+    let response = client
+        .chat_completion(PromptTemplates::new().build_extraction_prompt(&prompt))
+        .await
+        .map_err(|e| AppError::Ai(format!("Request failed: {}", e)))?;
+    let content = response.choices.into_iter().next()
+        .map(|choice| choice.message.content)
+        .ok_or_else(|| AppError::Ai("Empty response".to_string()))?;
+    let output: CalendarEventOutput = serde_json::from_str(content.trim())
+        .map_err(|e| AppError::Ai(format!("Unreadable response: {}", e)))?;
+    event_builder_from_ai(output)
+}
+
+/// Typed builder for an event extracted by the AI; fails on malformed dates,
+/// times or recurrence frequencies. Unknown priorities and categories fall
+/// back to the defaults.
+pub fn event_builder_from_ai(output: CalendarEventOutput) -> AppResult<CalendarEventBuilder> {
+    let parse_date = |date: &str| {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| AppError::Validation(format!("Invalid date: {}", date)))
+    };
+    let parse_time = |time: &str| {
+        calendar_core::time::parse_hhmm(time)
+            .ok_or_else(|| AppError::Validation(format!("Invalid time: {}", time)))
+    };
+
+    let mut builder = CalendarEvent::builder(output.event, parse_date(&output.date)?)
+        .priority(output.priority.parse().unwrap_or_default())
+        .category(output.category.parse().unwrap_or_default())
+        .tags(output.tags)
+        .metadata_entry("source", "DeepSeek");
+    if let Some(time) = &output.time {
+        builder = builder.time(parse_time(time)?);
+    }
+    if let Some(end_time) = &output.end_time {
+        builder = builder.end_time(parse_time(end_time)?);
+    }
+    if let Some(notes) = output.notes {
+        builder = builder.notes(notes);
+    }
+    if let Some(recurring) = output.recurring {
+        builder = builder.recurrence(RecurrenceConfig {
+            frequency: recurring.frequency.parse()?,
+            interval: recurring.interval,
+            days_of_week: recurring.days_of_week,
+            end_date: recurring.end_date.as_deref().map(parse_date).transpose()?,
+            occurrences: recurring.occurrences,
+            except_dates: recurring.except_dates.iter().map(|d| parse_date(d)).collect::<AppResult<_>>()?,
+            ..Default::default()
+        });
+    }
+    if let Some(reminder) = output.reminder {
+        builder = builder.reminder(ReminderConfig {
+            minutes_before: reminder.minutes_before,
+            repeat_minutes: reminder.repeat_minutes,
+            max_reminders: reminder.max_reminders,
+            ..Default::default()
+        });
+    }
+    if let Some(location) = output.location {
+        builder = builder.location(Location {
+            location_type: location.location_type.parse()?,
+            address: location.address,
+            coordinates: None,
+        });
+    }
+    Ok(builder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calendar_core::{Category, LocationType, RecurrenceFrequency};
+
+    fn output() -> CalendarEventOutput {
+        serde_json::from_value(serde_json::json!({
+            "event": "Dentist",
+            "date": "2026-03-04",
+            "time": "10:30",
+            "end_time": "11:00",
+            "notes": null,
+            "priority": "high",
+            "category": "health",
+            "recurring": {
+                "frequency": "monthly",
+                "interval": 6,
+                "days_of_week": [],
+                "end_date": null,
+                "occurrences": 4,
+                "except_dates": []
+            },
+            "reminder": null,
+            "location": { "location_type": "physical", "address": "Main St 1" },
+            "tags": ["checkup"],
+            "clarification_questions": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ai_output_to_event() {
+        let event = event_builder_from_ai(output()).unwrap().build().unwrap();
+
+        assert_eq!(event.date, NaiveDate::from_ymd_opt(2026, 3, 4).unwrap());
+        assert_eq!(event.time, calendar_core::time::parse_hhmm("10:30"));
+        assert_eq!(event.category, Category::Health);
+        assert_eq!(event.recurring.unwrap().frequency, RecurrenceFrequency::Monthly);
+        assert_eq!(event.location.unwrap().location_type, LocationType::Physical);
+        assert_eq!(event.metadata["source"], "DeepSeek");
+    }
+
+    #[test]
+    fn test_ai_output_rejects_bad_time() {
+        let mut bad = output();
+        bad.time = Some("25:99".to_string());
+        assert!(event_builder_from_ai(bad).is_err());
+    }
+}
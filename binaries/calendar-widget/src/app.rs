@@ -1,22 +1,31 @@
 use std::sync::Arc;
 use crate::AppState;
+use crate::api;
 use crate::input::{InputHandler, Command};
 use crate::input::parser::ParsedEvent;
 use crate::export::Exporter;
-use calendar_core::CalendarEvent;
+use calendar_core::{AppError, CalendarEvent, Conflict, ConflictCheck, SlotProposal};
+use calendar_core::scheduling::DEFAULT_PROPOSALS;
+use calendar_core::search::DEFAULT_SEARCH_LIMIT;
+use calendar_core::validation::MAX_REMINDER_MINUTES;
 use uuid::Uuid;
 use std::path::PathBuf;
 
 pub struct App {
     state: Arc<AppState>,
     input_handler: InputHandler,
+    /// Last `/schedule` request and the times offered for it
+    pending_proposals: Option<(ParsedEvent, Vec<SlotProposal>)>,
 }
 
 impl App {
     pub fn new(state: Arc<AppState>) -> Result<Self, std::io::Error> {
+        let mut input_handler = InputHandler::new(state.clock.clone(), state.settings.time_zone());
+        input_handler.set_holidays(state.holidays.as_ref().clone());
         Ok(Self { 
+            input_handler,
+            pending_proposals: None,
             state,
-            input_handler: InputHandler::new(),
         })
     }
 
@@ -30,26 +39,24 @@ impl App {
             loop {
                 interval.tick().await;
                 
-                // Get today's and tomorrow's events
-                let today = chrono::Local::now().date_naive();
-                let tomorrow = today + chrono::Duration::days(1);
-                let start_date = today.format("%Y-%m-%d").to_string();
-                let end_date = tomorrow.format("%Y-%m-%d").to_string();
+                // Absolute reminders can fire weeks ahead of their event, so
+                // look as far ahead as the longest allowed lead time
+                let today = state.clock.today_in(state.settings.time_zone());
+                let horizon = today + chrono::Duration::days(MAX_REMINDER_MINUTES as i64 / (24 * 60) + 1);
                 
                 let repository = state.repository.clone();
                 
                 match tokio::task::spawn_blocking(move || {
-                    repository.0.get_by_date_range(&start_date, &end_date)
+                    repository.0.get_by_date_range(today, horizon)
                 }).await {
                     Ok(Ok(events)) => {
-                        for event in events {
-                            if state.notification_service.should_notify(
-                                &event,
-                                state.settings.notifications.default_reminder_minutes
-                            ) {
-                                if let Err(e) = state.notification_service.send_notification(&event) {
-                                    eprintln!("Failed to send notification: {}", e);
-                                }
+                        let due = state.notification_service.due_reminders(
+                            &events,
+                            state.settings.notifications.default_reminder_minutes
+                        );
+                        for (event, reminder) in &due {
+                            if let Err(e) = state.notification_service.send_reminder(event, reminder) {
+                                eprintln!("Failed to send notification: {}", e);
                             }
                         }
                     }
@@ -62,11 +69,17 @@ impl App {
     pub async fn run(&mut self) -> Result<(), std::io::Error> {
         println!("UberCalendurr Widget v0.1.0");
         println!("Type /help for available commands");
+        if let Some(date) = self.state.settings.simulated_date {
+            println!("⏳ Simulating {} (set simulated_date in settings.toml)", date);
+        }
         println!();
         
         // Start background notification checker
         self.start_notification_checker();
         
+        self.reload_categories().await;
+        self.purge_expired_trash().await;
+        
         self.run_interactive().await
     }
 
@@ -100,7 +113,17 @@ impl App {
                         println!("Available commands:");
                         println!("  /help          - Show this help");
                         println!("  /today         - Show today's events");
-                        println!("  /search <term> - Search events");
+                        println!("  /search <query> - Search, e.g. /search cat:work pri:>=high -status:cancelled standup");
+                        println!("  /todo <text>   - Add a task");
+                        println!("  /tasks         - Show open tasks");
+                        println!("  /done <id>     - Mark a task done");
+                        println!("  /trash         - Show deleted events");
+                        println!("  /restore <id>  - Restore a deleted event");
+                        println!("  /categories    - Show categories and their keywords");
+                        println!("  /holidays      - Show upcoming public holidays");
+                        println!("  /free <when>   - Show free time, e.g. /free thursday afternoon for 1h");
+                        println!("  /schedule <what> - Propose times, e.g. /schedule 1h focus block sometime this week");
+                        println!("  /accept <n>    - Book proposal n from /schedule");
                         println!("  /export <fmt>  - Export events (json/csv/ics)");
                         println!("  /exit          - Exit application");
                         continue;
@@ -110,8 +133,47 @@ impl App {
                         continue;
                     }
                     Command::Search(query) => {
-                        println!("Search: {}", query);
-                        // TODO: Implement search
+                        self.search_events(&query).await;
+                        continue;
+                    }
+                    Command::AddTask(text) => {
+                        self.add_task(&text).await?;
+                        continue;
+                    }
+                    Command::ShowTasks => {
+                        self.show_open_tasks().await?;
+                        continue;
+                    }
+                    Command::ShowCategories => {
+                        self.show_categories().await;
+                        continue;
+                    }
+                    Command::ShowHolidays => {
+                        self.show_holidays();
+                        continue;
+                    }
+                    Command::FreeTime(text) => {
+                        self.show_free_time(&text).await?;
+                        continue;
+                    }
+                    Command::Schedule(text) => {
+                        self.propose_slots(&text).await;
+                        continue;
+                    }
+                    Command::Accept(choice) => {
+                        self.accept_proposal(&choice).await;
+                        continue;
+                    }
+                    Command::CompleteTask(id) => {
+                        self.complete_task(&id).await?;
+                        continue;
+                    }
+                    Command::ShowTrash => {
+                        self.show_trash().await;
+                        continue;
+                    }
+                    Command::Restore(id) => {
+                        self.restore_event(&id).await;
                         continue;
                     }
                     Command::Export(format) => {
@@ -138,71 +200,81 @@ impl App {
                 continue;
             }
 
-            // Handle natural language input
-            match self.input_handler.parse(&input_str) {
-                Ok(parsed_event) => {
-                    // Convert to CalendarEvent
-                    let event = CalendarEvent::from_parsed(
-                        parsed_event.event,
-                        parsed_event.date,
-                        parsed_event.time,
-                        parsed_event.end_time,
-                        parsed_event.notes,
-                        parsed_event.priority,
-                        parsed_event.category,
-                        parsed_event.tags,
-                        parsed_event.metadata,
-                        parsed_event.recurring,
-                    );
-
-                    // Validate
-                    if let Err(e) = event.validate() {
-                        println!("❌ Validation error: {}", e);
-                        continue;
-                    }
+            // "... sometime this week" asks for a time to be found
+            if self.input_handler.is_slot_request(&input_str) {
+                self.propose_slots(&input_str).await;
+                continue;
+            }
 
-                    // Save to database (spawn_blocking for sync repository)
-                    let repository = self.state.repository.clone();
-                    let event_clone = event.clone();
-                    
-                    match tokio::task::spawn_blocking(move || {
-                        repository.save_event(&event_clone)
-                    }).await {
-                        Ok(Ok(_)) => {
-                            // Success confirmation
-                            let time_str = event.time.as_deref().unwrap_or("--:--");
-                            let category_str = event.category.as_str();
-                            println!(
-                                "✅ [{} {}] {} ({}) — Saved.",
-                                event.date,
-                                time_str,
-                                event.event,
-                                category_str
-                            );
-                        }
-                        Ok(Err(e)) => {
-                            println!("❌ Failed to save: {}", e);
-                        }
+            // Handle natural language input; the AI, when configured, gets
+            // a go at what the local parser cannot read
+            let zone = self.state.settings.time_zone();
+            let builder = match (self.input_handler.parse(&input_str), &self.state.deepseek_client) {
+                (Ok(parsed_event), _) => parsed_event.into_builder(),
+                (Err(_), Some(client)) => {
+                    let today = self.state.clock.today_in(zone);
+                    match api::extract_event(client, &input_str, today).await {
+                        Ok(builder) => Ok(builder),
                         Err(e) => {
-                            println!("❌ Task error: {}", e);
+                            println!("❌ Failed to parse: {}", e);
+                            continue;
                         }
                     }
                 }
-                Err(e) => {
+                (Err(e), None) => {
                     println!("❌ Failed to parse: {}", e);
                     println!("   Try: 'Meeting tomorrow at 2pm' or 'Lunch with Sarah next Tuesday'");
+                    continue;
                 }
+            };
+
+            // Pin the wall-clock time to the configured zone so it stays
+            // correct when viewed from elsewhere
+            let event = match builder.and_then(|builder| builder.timezone(zone).build()) {
+                Ok(event) => event,
+                Err(e) => {
+                    Self::print_validation_error(&e);
+                    continue;
+                }
+            };
+
+            if let Some(holiday) = self.state.holidays.holiday_on(event.date) {
+                println!("⚠️  {} is {}", event.date, holiday);
             }
+            // Saved anyway; the user may well mean to double-book
+            for conflict in self.conflicts_of(&event).await {
+                println!("⚠️  {}", conflict);
+            }
+            self.save_event(event).await;
         }
 
         Ok(())
     }
 
+    /// Print a failed build, one line per field problem when there are any
+    fn print_validation_error(error: &AppError) {
+        match error.field_errors() {
+            [] => println!("❌ Validation error: {}", error),
+            errors => {
+                println!("❌ Validation failed:");
+                for field_error in errors {
+                    println!("   • {}", field_error);
+                }
+            }
+        }
+    }
+
     async fn show_today_events(&self) -> Result<(), std::io::Error> {
         let repository = self.state.repository.clone();
+        let zone = self.state.settings.time_zone();
+        let clock = self.state.clock.clone();
+        
+        if let Some(holiday) = self.state.holidays.holiday_on(clock.today_in(zone)) {
+            println!("🎉 Today is {}", holiday);
+        }
         
         match tokio::task::spawn_blocking(move || {
-            repository.get_today_events()
+            repository.get_today_events(zone, clock.as_ref())
         }).await {
             Ok(Ok(events)) => {
                 if events.is_empty() {
@@ -210,7 +282,11 @@ impl App {
                 } else {
                     println!("Today's events:");
                     for event in events {
-                        let time_str = event.time.as_deref().unwrap_or("--:--");
+                        let time_str = match event.time {
+                            _ if event.all_day => "all day".to_string(),
+                            Some(time) => time.format("%H:%M").to_string(),
+                            None => "--:--".to_string(),
+                        };
                         println!("  [{}] {} ({})", time_str, event.event, event.category.as_str());
                     }
                 }
@@ -226,17 +302,501 @@ impl App {
         Ok(())
     }
 
-    async fn handle_export(&self, format: &str) -> Result<(), std::io::Error> {
-        // Get all events
+    /// Search with the query language (`cat:work pri:>=high standup`),
+    /// best text matches first, showing the matching text when it is not
+    /// the title
+    async fn search_events(&self, input: &str) {
+        if input.trim().is_empty() {
+            println!("Usage: /search <query>, e.g. /search cat:work pri:>=high -status:cancelled standup");
+            return;
+        }
+
+        let today = self.state.clock.today_in(self.state.settings.time_zone());
+        let query = match calendar_core::parse_query(input, today) {
+            Ok(query) => query.limit(DEFAULT_SEARCH_LIMIT),
+            Err(e) => {
+                println!("❌ {}", e.message);
+                for line in e.pointer(input).lines() {
+                    println!("   {}", line);
+                }
+                return;
+            }
+        };
+
+        let repository = self.state.repository.clone();
+        let hits = match tokio::task::spawn_blocking(move || {
+            repository.0.search(&query)
+        }).await {
+            Ok(Ok(hits)) => hits,
+            Ok(Err(e)) => {
+                println!("❌ Search failed: {}", e);
+                return;
+            }
+            Err(e) => {
+                println!("❌ Task error: {}", e);
+                return;
+            }
+        };
+
+        if hits.is_empty() {
+            println!("No events match \"{}\".", input);
+            return;
+        }
+
+        println!("Events matching \"{}\":", input);
+        for hit in hits {
+            let event = &hit.event;
+            let time_str = match event.time {
+                _ if event.all_day => "all day".to_string(),
+                Some(time) => time.format("%H:%M").to_string(),
+                None => "--:--".to_string(),
+            };
+            println!(
+                "  {} [{}] {} ({})",
+                event.date.format("%a %Y-%m-%d"),
+                time_str,
+                event.event,
+                event.category.as_str()
+            );
+            let plain = hit.highlighted("", "");
+            if plain != event.event {
+                println!("      {}", hit.highlighted("\x1b[1m", "\x1b[0m"));
+            }
+        }
+    }
+
+    async fn add_task(&self, text: &str) -> Result<(), std::io::Error> {
+        let task = match self.input_handler.parse_task(text) {
+            Ok(task) => task,
+            Err(e) => {
+                Self::print_validation_error(&e);
+                return Ok(());
+            }
+        };
+
+        let repository = self.state.repository.clone();
+        let task_clone = task.clone();
+        match tokio::task::spawn_blocking(move || repository.save_task(&task_clone)).await {
+            Ok(Ok(_)) => {
+                let due = task.due_date
+                    .map(|date| format!(" — due {}", date))
+                    .unwrap_or_default();
+                println!("✅ [{}] {}{} — Saved.", Self::short_id(&task.id), task.title, due);
+            }
+            Ok(Err(e)) => println!("❌ Failed to save: {}", e),
+            Err(e) => println!("❌ Task error: {}", e),
+        }
+
+        Ok(())
+    }
+
+    async fn show_open_tasks(&self) -> Result<(), std::io::Error> {
+        let repository = self.state.repository.clone();
+        let today = self.state.clock.today_in(self.state.settings.time_zone());
+
+        let tasks = match tokio::task::spawn_blocking(move || repository.0.get_open_tasks()).await {
+            Ok(Ok(tasks)) => tasks,
+            Ok(Err(e)) => {
+                println!("❌ Failed to load tasks: {}", e);
+                return Ok(());
+            }
+            Err(e) => {
+                println!("❌ Task error: {}", e);
+                return Ok(());
+            }
+        };
+
+        if tasks.is_empty() {
+            println!("No open tasks.");
+            return Ok(());
+        }
+
+        // Subtasks are listed under their parent while it is still open
+        let is_open = |id: &Uuid| tasks.iter().any(|task| &task.id == id);
+        let print_task = |task: &calendar_core::Task, indent: &str| {
+            let due = match task.due_date {
+                Some(date) if task.is_overdue(today) => format!(" — overdue since {}", date),
+                Some(date) => format!(" — due {}", date),
+                None => String::new(),
+            };
+            let progress = match task.percent_complete {
+                0 => String::new(),
+                percent => format!(" ({}%)", percent),
+            };
+            println!(
+                "{}[{}] {} {}{}{}",
+                indent,
+                Self::short_id(&task.id),
+                task.priority.emoji(),
+                task.title,
+                progress,
+                due
+            );
+        };
+
+        println!("Open tasks:");
+        for task in tasks.iter().filter(|task| !task.parent_id.as_ref().is_some_and(is_open)) {
+            print_task(task, "  ");
+            for subtask in tasks.iter().filter(|subtask| subtask.parent_id == Some(task.id)) {
+                print_task(subtask, "      ");
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn complete_task(&self, id: &str) -> Result<(), std::io::Error> {
+        let repository = self.state.repository.clone();
+        let prefix = id.to_string();
+
+        match tokio::task::spawn_blocking(move || repository.complete_task_by_prefix(&prefix)).await {
+            Ok(Ok(task)) => println!("✅ Done: {}", task.title),
+            Ok(Err(AppError::NotFound)) => println!("❌ No open task with id {}", id),
+            Ok(Err(e)) => println!("❌ Failed to complete task: {}", e),
+            Err(e) => println!("❌ Task error: {}", e),
+        }
+
+        Ok(())
+    }
+
+    async fn show_trash(&self) {
+        let repository = self.state.repository.clone();
+        let trash = match tokio::task::spawn_blocking(move || repository.0.get_trash()).await {
+            Ok(Ok(trash)) => trash,
+            Ok(Err(e)) => {
+                println!("❌ Failed to load trash: {}", e);
+                return;
+            }
+            Err(e) => {
+                println!("❌ Task error: {}", e);
+                return;
+            }
+        };
+
+        if trash.is_empty() {
+            println!("Trash is empty.");
+            return;
+        }
+
+        let zone = self.state.settings.time_zone();
+        let retention = self.state.settings.trash_retention();
+        println!("Trash (restore with /restore <id>):");
+        for trashed in &trash {
+            println!(
+                "[{}] {} {} — deleted {}, purged after {}",
+                Self::short_id(&trashed.event.id),
+                trashed.event.date,
+                trashed.event.event,
+                zone.from_utc(trashed.deleted_at).format("%Y-%m-%d %H:%M"),
+                zone.from_utc(trashed.expires_at(&retention)).format("%Y-%m-%d"),
+            );
+        }
+    }
+
+    async fn restore_event(&self, id: &str) {
+        let repository = self.state.repository.clone();
+        let prefix = id.to_string();
+
+        match tokio::task::spawn_blocking(move || repository.restore_event_by_prefix(&prefix)).await {
+            Ok(Ok(event)) => println!("✅ Restored: {} on {}", event.event, event.date),
+            Ok(Err(AppError::NotFound)) => println!("❌ No deleted event with id {}", id),
+            Ok(Err(e)) => println!("❌ Failed to restore event: {}", e),
+            Err(e) => println!("❌ Task error: {}", e),
+        }
+    }
+
+    /// Purge events that have been in the trash longer than the settings
+    /// allow
+    async fn purge_expired_trash(&self) {
+        let repository = self.state.repository.clone();
+        let retention = self.state.settings.trash_retention();
+
+        match tokio::task::spawn_blocking(move || repository.0.apply_retention(&retention)).await {
+            Ok(Ok(0)) => {}
+            Ok(Ok(purged)) => println!("🗑  Purged {} event(s) deleted more than {} days ago", purged, retention.days),
+            Ok(Err(e)) => println!("❌ Failed to empty trash: {}", e),
+            Err(e) => println!("❌ Task error: {}", e),
+        }
+    }
+
+    /// Load the user's categories into the parser; keeps the built-ins if
+    /// they cannot be read
+    async fn reload_categories(&mut self) -> Option<calendar_core::CategoryRegistry> {
+        let repository = self.state.repository.clone();
+        match tokio::task::spawn_blocking(move || repository.0.get_categories()).await {
+            Ok(Ok(categories)) => {
+                self.input_handler.set_categories(categories.clone());
+                Some(categories)
+            }
+            Ok(Err(e)) => {
+                println!("❌ Failed to load categories: {}", e);
+                None
+            }
+            Err(e) => {
+                println!("❌ Task error: {}", e);
+                None
+            }
+        }
+    }
+
+    async fn show_categories(&mut self) {
+        let Some(categories) = self.reload_categories().await else {
+            return;
+        };
+
+        println!("Categories:");
+        for definition in categories.definitions() {
+            let keywords = match definition.keywords.as_slice() {
+                [] => String::new(),
+                keywords => format!(" — {}", keywords.join(", ")),
+            };
+            println!(
+                "  {} {} ({}){}",
+                definition.icon.as_deref().unwrap_or("•"),
+                definition.display_name,
+                definition.category,
+                keywords
+            );
+        }
+    }
+
+    /// Holidays in the coming year from the configured calendar
+    fn show_holidays(&self) {
+        let holidays = &self.state.holidays;
+        if holidays.holidays.is_empty() {
+            println!("No holidays configured (set holidays in settings.toml to a country code or a file).");
+            return;
+        }
+
+        let today = self.state.clock.today_in(self.state.settings.time_zone());
+        println!("Holidays ({}):", holidays.name);
+        for holiday in holidays.holidays_between(today, today + chrono::Duration::days(365)) {
+            println!("  {}  {}", holiday.date.format("%a %Y-%m-%d"), holiday.name);
+        }
+    }
+
+    async fn show_free_time(&self, text: &str) -> Result<(), std::io::Error> {
+        let query = self.input_handler
+            .parse_free_query(text)
+            .working_hours(self.state.settings.working_hours.clone())
+            .holidays(self.state.holidays.as_ref().clone());
+        let (start, end) = (query.start, query.end);
         let repository = self.state.repository.clone();
-        let today = chrono::Local::now().date_naive();
-        let start_date = (today - chrono::Duration::days(365)).format("%Y-%m-%d").to_string();
-        let end_date = (today + chrono::Duration::days(365)).format("%Y-%m-%d").to_string();
+        let zone = self.state.settings.time_zone();
+
+        let result = match tokio::task::spawn_blocking(move || repository.0.free_busy(&query, zone)).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => {
+                println!("❌ Failed to load events: {}", e);
+                return Ok(());
+            }
+            Err(e) => {
+                println!("❌ Task error: {}", e);
+                return Ok(());
+            }
+        };
+
+        let span = |from: chrono::NaiveDateTime, to: chrono::NaiveDateTime| {
+            if from.date() == start.date() && to.date() <= end.date() {
+                format!("{}–{}", from.format("%H:%M"), to.format("%H:%M"))
+            } else {
+                format!("{} {}–{}", from.format("%a %d"), from.format("%H:%M"), to.format("%H:%M"))
+            }
+        };
+
+        println!("{} {}–{}:", start.format("%A %Y-%m-%d"), start.format("%H:%M"), end.format("%H:%M"));
+        if result.free.is_empty() {
+            println!("  No free time within working hours.");
+        }
+        for slot in &result.free {
+            println!("  🟢 {} ({})", span(slot.start, slot.end), Self::format_minutes(slot.minutes()));
+        }
+        for block in &result.busy {
+            println!("  🔴 {} {}", span(block.start, block.end), block.titles.join(", "));
+        }
+
+        Ok(())
+    }
+
+    async fn save_event(&self, event: CalendarEvent) {
+        // Save to database (spawn_blocking for sync repository)
+        let repository = self.state.repository.clone();
+        let event_clone = event.clone();
         
-        let events = match tokio::task::spawn_blocking(move || {
-            repository.0.get_by_date_range(&start_date, &end_date)
+        match tokio::task::spawn_blocking(move || {
+            repository.save_event(&event_clone)
         }).await {
-            Ok(Ok(events)) => events,
+            Ok(Ok(_)) => {
+                // Success confirmation
+                let time_str = event.time
+                    .map(|t| t.format("%H:%M").to_string())
+                    .unwrap_or_else(|| "--:--".to_string());
+                let category_str = event.category.as_str();
+                println!(
+                    "✅ [{} {}] {} ({}) — Saved.",
+                    event.date,
+                    time_str,
+                    event.event,
+                    category_str
+                );
+            }
+            Ok(Err(e)) => {
+                println!("❌ Failed to save: {}", e);
+            }
+            Err(e) => {
+                println!("❌ Task error: {}", e);
+            }
+        }
+    }
+
+    /// What `event` runs into, keeping the configured buffer around other
+    /// events. Failures to check are reported and treated as no conflicts.
+    async fn conflicts_of(&self, event: &CalendarEvent) -> Vec<Conflict> {
+        let repository = self.state.repository.clone();
+        let zone = self.state.settings.time_zone();
+        let check = ConflictCheck::default().buffer_minutes(self.state.settings.buffer_minutes);
+        let event = event.clone();
+
+        match tokio::task::spawn_blocking(move || repository.0.check_conflicts(&event, &check, zone)).await {
+            Ok(Ok(conflicts)) => conflicts,
+            Ok(Err(e)) => {
+                println!("❌ Failed to check conflicts: {}", e);
+                Vec::new()
+            }
+            Err(e) => {
+                println!("❌ Task error: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Offer the best free times for what `text` describes, to book with
+    /// `/accept`
+    async fn propose_slots(&mut self, text: &str) {
+        let (parsed, request) = match self.input_handler.parse_slot_request(text) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                println!("❌ Failed to parse: {}", e);
+                return;
+            }
+        };
+        let request = request
+            .working_hours(self.state.settings.working_hours.clone())
+            .buffer_minutes(self.state.settings.buffer_minutes)
+            .holidays(self.state.holidays.as_ref().clone());
+        let duration = request.duration_minutes as i64;
+        let repository = self.state.repository.clone();
+        let zone = self.state.settings.time_zone();
+
+        let proposals = match tokio::task::spawn_blocking(move || {
+            repository.0.propose_slots(&request, zone, DEFAULT_PROPOSALS)
+        }).await {
+            Ok(Ok(proposals)) => proposals,
+            Ok(Err(e)) => {
+                println!("❌ Failed to load events: {}", e);
+                return;
+            }
+            Err(e) => {
+                println!("❌ Task error: {}", e);
+                return;
+            }
+        };
+
+        if proposals.is_empty() {
+            println!("No free {} slot for \"{}\" within working hours.", Self::format_minutes(duration), parsed.event);
+            self.pending_proposals = None;
+            return;
+        }
+
+        println!("Proposed times for \"{}\" ({}):", parsed.event, Self::format_minutes(duration));
+        for (i, proposal) in proposals.iter().enumerate() {
+            println!(
+                "  {}. {} {}–{}",
+                i + 1,
+                proposal.start.format("%a %Y-%m-%d"),
+                proposal.start.format("%H:%M"),
+                proposal.end.format("%H:%M")
+            );
+        }
+        println!("Book one with /accept <n>");
+        self.pending_proposals = Some((parsed, proposals));
+    }
+
+    /// Book proposal `choice` (1-based) from the last `/schedule`, if that
+    /// time is still free
+    async fn accept_proposal(&mut self, choice: &str) {
+        let Some((parsed, proposals)) = &self.pending_proposals else {
+            println!("❌ Nothing to accept; ask for times with /schedule first");
+            return;
+        };
+        let Some(proposal) = choice.parse::<usize>().ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|i| proposals.get(i))
+        else {
+            println!("❌ Pick a proposal from 1 to {}", proposals.len());
+            return;
+        };
+
+        let mut parsed = parsed.clone();
+        parsed.date = proposal.start.format("%Y-%m-%d").to_string();
+        parsed.time = Some(proposal.start.format("%H:%M").to_string());
+        parsed.end_time = Some(proposal.end.format("%H:%M").to_string());
+        let zone = self.state.settings.time_zone();
+        let event = match parsed.into_builder().and_then(|builder| builder.timezone(zone).build()) {
+            Ok(event) => event,
+            Err(e) => {
+                Self::print_validation_error(&e);
+                return;
+            }
+        };
+
+        // Something may have been booked since the times were proposed
+        let conflicts = self.conflicts_of(&event).await;
+        if !conflicts.is_empty() {
+            println!("❌ That time is no longer free; run /schedule again");
+            for conflict in &conflicts {
+                println!("   • {}", conflict);
+            }
+            return;
+        }
+
+        self.save_event(event).await;
+        self.pending_proposals = None;
+    }
+
+    /// "45m", "2h", "1h 30m"
+    fn format_minutes(minutes: i64) -> String {
+        match (minutes / 60, minutes % 60) {
+            (0, m) => format!("{}m", m),
+            (h, 0) => format!("{}h", h),
+            (h, m) => format!("{}h {}m", h, m),
+        }
+    }
+
+    /// First block of a UUID, enough to pick a task in `/done`
+    fn short_id(id: &Uuid) -> String {
+        id.to_string()[..8].to_string()
+    }
+
+    async fn handle_export(&self, format: &str) -> Result<(), std::io::Error> {
+        // Get all events; iCalendar keeps series whole, with their RRULE,
+        // while JSON and CSV list every occurrence
+        let repository = self.state.repository.clone();
+        let today = self.state.clock.today_in(self.state.settings.time_zone());
+        let start_date = today - chrono::Duration::days(365);
+        let end_date = today + chrono::Duration::days(365);
+        let series = format.eq_ignore_ascii_case("ics");
+        
+        let loaded = tokio::task::spawn_blocking(move || {
+            let events = if series {
+                repository.0.get_series_in_range(start_date, end_date)?
+            } else {
+                repository.0.get_by_date_range(start_date, end_date)?
+            };
+            Ok::<_, AppError>((events, repository.0.get_tasks()?))
+        }).await;
+        let (events, tasks) = match loaded {
+            Ok(Ok(loaded)) => loaded,
             Ok(Err(e)) => {
                 println!("❌ Failed to load events: {}", e);
                 return Ok(());
@@ -248,7 +808,7 @@ impl App {
         };
 
         // Generate filename
-        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        let timestamp = self.state.settings.time_zone().now(self.state.clock.as_ref()).format("%Y%m%d_%H%M%S");
         let extension = match format.to_lowercase().as_str() {
             "json" => "json",
             "csv" => "csv",
@@ -266,13 +826,17 @@ impl App {
         let result = match format.to_lowercase().as_str() {
             "json" => Exporter::export_json(&events, &export_path),
             "csv" => Exporter::export_csv(&events, &export_path),
-            "ics" => Exporter::export_ics(&events, &export_path),
+            "ics" => Exporter::export_ics(&events, &tasks, &export_path),
             _ => unreachable!(),
         };
 
         match result {
             Ok(_) => {
-                println!("✅ Exported {} events to {}", events.len(), filename);
+                if extension == "ics" && !tasks.is_empty() {
+                    println!("✅ Exported {} events and {} tasks to {}", events.len(), tasks.len(), filename);
+                } else {
+                    println!("✅ Exported {} events to {}", events.len(), filename);
+                }
             }
             Err(e) => {
                 println!("❌ Export failed: {}", e);
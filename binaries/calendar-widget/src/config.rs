@@ -3,6 +3,10 @@ use std::path::PathBuf;
 use std::fs;
 use anyhow::{Result, Context};
 use directories::BaseDirs;
+use std::sync::Arc;
+use chrono::NaiveDate;
+use calendar_core::{HolidayCalendar, RetentionPolicy, WorkingHours};
+use calendar_core::time::{OffsetClock, SharedClock, TimeZone, system_clock};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
@@ -15,6 +19,24 @@ pub struct Settings {
     pub database_path: PathBuf,
     pub deepseek_api_key: String,
     pub debug_mode: bool,
+    /// Default zone for new events and for display: `local`, `UTC` or an IANA name
+    pub timezone: String,
+    /// Pretend today is this date (`YYYY-MM-DD`), e.g. to plan ahead;
+    /// time keeps running from the current time of day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub simulated_date: Option<NaiveDate>,
+    /// When `/free` and `/schedule` look for free time
+    pub working_hours: WorkingHours,
+    /// Minutes kept free around existing events when scheduling and
+    /// checking for conflicts
+    pub buffer_minutes: u32,
+    /// Public holidays: a country code (`US`, `GB`, `DE`, `FR`) or the path
+    /// of a JSON holiday calendar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holidays: Option<String>,
+    /// Days deleted events stay in the trash before they are purged on
+    /// start; 0 empties it every time
+    pub trash_retention_days: u32,
 }
 
 impl Default for Settings {
@@ -28,6 +50,12 @@ impl Default for Settings {
             database_path: PathBuf::from("calendar.db"),
             deepseek_api_key: String::new(),
             debug_mode: false,
+            timezone: "local".to_string(),
+            simulated_date: None,
+            working_hours: WorkingHours::default(),
+            buffer_minutes: 10,
+            holidays: None,
+            trash_retention_days: calendar_core::trash::DEFAULT_RETENTION_DAYS,
         }
     }
 }
@@ -49,6 +77,40 @@ impl Settings {
         Ok(settings)
     }
 
+    /// How long deleted events are kept
+    pub fn trash_retention(&self) -> RetentionPolicy {
+        RetentionPolicy::days(self.trash_retention_days)
+    }
+
+    /// Parsed default zone; an unrecognised name falls back to the local zone
+    pub fn time_zone(&self) -> TimeZone {
+        TimeZone::parse(&self.timezone).unwrap_or(TimeZone::Local)
+    }
+
+    /// The configured holidays; weekends only when none are set
+    pub fn holiday_calendar(&self) -> Result<HolidayCalendar> {
+        let Some(holidays) = self.holidays.as_deref().map(str::trim) else {
+            return Ok(HolidayCalendar::default());
+        };
+        let builtin = calendar_core::holidays::COUNTRIES
+            .iter()
+            .any(|code| code.eq_ignore_ascii_case(holidays)) || holidays.eq_ignore_ascii_case("uk");
+        let calendar = if builtin {
+            HolidayCalendar::country(holidays)
+        } else {
+            HolidayCalendar::load(&PathBuf::from(holidays))
+        };
+        calendar.with_context(|| format!("Failed to load holidays from {}", holidays))
+    }
+
+    /// The system clock, or one starting on `simulated_date`
+    pub fn clock(&self) -> SharedClock {
+        match self.simulated_date {
+            Some(date) => Arc::new(OffsetClock::starting_on(date, self.time_zone())),
+            None => system_clock(),
+        }
+    }
+
     pub fn save(&self) -> Result<()> {
         let config_dir = get_config_dir()?;
         std::fs::create_dir_all(&config_dir)?;
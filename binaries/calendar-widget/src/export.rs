@@ -1,7 +1,11 @@
-use calendar_core::CalendarEvent;
+use calendar_core::{Attendee, AttendeeRole, CalendarEvent, ReminderChannel, ReminderConfig, Task};
+use calendar_core::time::TimeZone;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Offset, Utc};
+use std::collections::{BTreeMap, HashMap};
 use std::io::Write;
 use std::fs::File;
 use std::path::PathBuf;
+use uuid::Uuid;
 
 pub struct Exporter;
 
@@ -29,7 +33,7 @@ impl Exporter {
         
         // Write events
         for event in events {
-            let time_str = event.time.as_deref().unwrap_or("");
+            let time_str = event.time.map(|t| t.format("%H:%M").to_string()).unwrap_or_default();
             let notes_str = event.notes.as_deref().unwrap_or("").replace(',', ";");
             
             writeln!(
@@ -48,7 +52,13 @@ impl Exporter {
         Ok(())
     }
 
-    pub fn export_ics(events: &[CalendarEvent], path: &PathBuf) -> Result<(), String> {
+    /// Events as `VEVENT`s followed by tasks as `VTODO`s.
+    ///
+    /// Recurring series are expected once, as stored, and are written with
+    /// their `RRULE`. Events with a `series_id` and `recurrence_id` are
+    /// edited or cancelled occurrences: they share the series' `UID` and
+    /// name the occurrence they replace with `RECURRENCE-ID`.
+    pub fn export_ics(events: &[CalendarEvent], tasks: &[Task], path: &PathBuf) -> Result<(), String> {
         let mut file = File::create(path)
             .map_err(|e| format!("Failed to create file: {}", e))?;
         
@@ -60,20 +70,70 @@ impl Exporter {
         writeln!(file, "PRODID:-//UberCalendurr//EN")
             .map_err(|e| format!("Failed to write ICS prodid: {}", e))?;
         
+        for line in Self::format_ics_timezones(events) {
+            writeln!(file, "{}", line)
+                .map_err(|e| format!("Failed to write VTIMEZONE: {}", e))?;
+        }
+        
+        let series: HashMap<Uuid, &CalendarEvent> = events.iter()
+            .filter(|event| event.recurring.is_some() && event.recurrence_id.is_none())
+            .map(|event| (event.id, event))
+            .collect();
+        
         // Write events
         for event in events {
             writeln!(file, "BEGIN:VEVENT")
                 .map_err(|e| format!("Failed to write VEVENT start: {}", e))?;
             
-            writeln!(file, "UID:{}", event.id)
+            let exception = event.series_id.zip(event.recurrence_id);
+            writeln!(file, "UID:{}", exception.map_or(event.id, |(series_id, _)| series_id))
                 .map_err(|e| format!("Failed to write UID: {}", e))?;
             
-            writeln!(file, "DTSTART:{}", Self::format_ics_datetime(&event.date, event.time.as_deref()))
-                .map_err(|e| format!("Failed to write DTSTART: {}", e))?;
+            if let Some((series_id, original_date)) = exception {
+                // Identifies the occurrence by its original start
+                let master = series.get(&series_id).copied().unwrap_or(event);
+                let line = if master.all_day {
+                    format!("RECURRENCE-ID;VALUE=DATE:{}", original_date.format("%Y%m%d"))
+                } else {
+                    Self::format_ics_property("RECURRENCE-ID", master, original_date, master.time)
+                };
+                writeln!(file, "{}", line)
+                    .map_err(|e| format!("Failed to write RECURRENCE-ID: {}", e))?;
+            }
+            
+            if event.all_day {
+                // DTEND is exclusive for date values
+                writeln!(file, "DTSTART;VALUE=DATE:{}", event.date.format("%Y%m%d"))
+                    .map_err(|e| format!("Failed to write DTSTART: {}", e))?;
+                if let Some(end) = event.end_naive() {
+                    writeln!(file, "DTEND;VALUE=DATE:{}", end.format("%Y%m%d"))
+                        .map_err(|e| format!("Failed to write DTEND: {}", e))?;
+                }
+            } else {
+                writeln!(file, "{}", Self::format_ics_property("DTSTART", event, event.date, event.time))
+                    .map_err(|e| format!("Failed to write DTSTART: {}", e))?;
+                
+                if let Some(end_time) = event.end_time {
+                    writeln!(file, "{}", Self::format_ics_property("DTEND", event, event.last_date(), Some(end_time)))
+                        .map_err(|e| format!("Failed to write DTEND: {}", e))?;
+                }
+            }
             
-            if let Some(end_time) = &event.end_time {
-                writeln!(file, "DTEND:{}", Self::format_ics_datetime(&event.date, Some(end_time)))
-                    .map_err(|e| format!("Failed to write DTEND: {}", e))?;
+            if let Some(recurring) = event.recurring.as_ref().filter(|_| exception.is_none()) {
+                if let Some(rrule) = recurring.to_rrule() {
+                    writeln!(file, "RRULE:{}", rrule)
+                        .map_err(|e| format!("Failed to write RRULE: {}", e))?;
+                }
+                
+                for &except_date in &recurring.except_dates {
+                    let line = if event.all_day {
+                        format!("EXDATE;VALUE=DATE:{}", except_date.format("%Y%m%d"))
+                    } else {
+                        Self::format_ics_property("EXDATE", event, except_date, event.time)
+                    };
+                    writeln!(file, "{}", line)
+                        .map_err(|e| format!("Failed to write EXDATE: {}", e))?;
+                }
             }
             
             writeln!(file, "SUMMARY:{}", Self::escape_ics_text(&event.event))
@@ -84,6 +144,11 @@ impl Exporter {
                     .map_err(|e| format!("Failed to write DESCRIPTION: {}", e))?;
             }
             
+            for attendee in &event.attendees {
+                writeln!(file, "{}", Self::format_ics_attendee(attendee))
+                    .map_err(|e| format!("Failed to write ATTENDEE: {}", e))?;
+            }
+            
             writeln!(file, "STATUS:{}", match event.status {
                 calendar_core::EventStatus::Confirmed => "CONFIRMED",
                 calendar_core::EventStatus::Tentative => "TENTATIVE",
@@ -92,10 +157,24 @@ impl Exporter {
             })
             .map_err(|e| format!("Failed to write STATUS: {}", e))?;
             
+            for reminder in &event.reminders {
+                for line in Self::format_ics_alarm(event, reminder) {
+                    writeln!(file, "{}", line)
+                        .map_err(|e| format!("Failed to write VALARM: {}", e))?;
+                }
+            }
+            
             writeln!(file, "END:VEVENT")
                 .map_err(|e| format!("Failed to write VEVENT end: {}", e))?;
         }
         
+        for task in tasks {
+            for line in Self::format_ics_todo(task) {
+                writeln!(file, "{}", line)
+                    .map_err(|e| format!("Failed to write VTODO: {}", e))?;
+            }
+        }
+        
         // Write iCalendar footer
         writeln!(file, "END:VCALENDAR")
             .map_err(|e| format!("Failed to write ICS footer: {}", e))?;
@@ -103,11 +182,206 @@ impl Exporter {
         Ok(())
     }
 
-    fn format_ics_datetime(date: &str, time: Option<&str>) -> String {
-        if let Some(t) = time {
-            format!("{}T{}00", date.replace('-', ""), t.replace(':', ""))
+    fn format_ics_datetime(date: NaiveDate, time: Option<NaiveTime>) -> String {
+        date.and_time(time.unwrap_or(NaiveTime::MIN)).format("%Y%m%dT%H%M%S").to_string()
+    }
+
+    /// Date-time property carrying the event's zone: `TZID` for IANA zones,
+    /// a `Z` suffix for UTC, and floating local time otherwise
+    fn format_ics_property(name: &str, event: &CalendarEvent, date: NaiveDate, time: Option<NaiveTime>) -> String {
+        let value = Self::format_ics_datetime(date, time);
+        match event.timezone.as_deref().map(TimeZone::parse) {
+            Some(Ok(TimeZone::Utc)) => format!("{}:{}Z", name, value),
+            Some(Ok(TimeZone::Named(tz))) => format!("{};TZID={}:{}", name, tz.name(), value),
+            _ => format!("{}:{}", name, value),
+        }
+    }
+
+    /// A `VTIMEZONE` for every IANA zone the events use, so the `TZID`s
+    /// they reference resolve in other clients
+    fn format_ics_timezones(events: &[CalendarEvent]) -> Vec<String> {
+        let mut zones: BTreeMap<&'static str, (chrono_tz::Tz, i32, i32)> = BTreeMap::new();
+        for event in events {
+            if let Some(Ok(TimeZone::Named(tz))) = event.timezone.as_deref().map(TimeZone::parse) {
+                let (first, last) = (event.date.year(), event.last_date().year());
+                let years = zones.entry(tz.name()).or_insert((tz, first, last));
+                years.1 = years.1.min(first);
+                years.2 = years.2.max(last);
+            }
+        }
+        zones.into_iter()
+            .flat_map(|(tzid, (tz, first, last))| Self::format_ics_vtimezone(tzid, &tz, first, last))
+            .collect()
+    }
+
+    /// `VTIMEZONE` listing the zone's offset changes from the year before
+    /// `first_year` to the year after `last_year`. If the zone still
+    /// changes offset in that final year, its last standard and daylight
+    /// observances repeat yearly so open-ended series stay covered.
+    fn format_ics_vtimezone<Tz>(tzid: &str, tz: &Tz, first_year: i32, last_year: i32) -> Vec<String>
+    where
+        Tz: chrono::TimeZone,
+        Tz::Offset: std::fmt::Display,
+    {
+        let offset = |at: DateTime<Utc>| at.with_timezone(tz).offset().fix().local_minus_utc();
+        let year_start = |year| NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or_default().and_time(NaiveTime::MIN).and_utc();
+        let (start, end) = (year_start(first_year - 1), year_start(last_year + 2));
+        
+        // Offset changes as (first instant of the new offset, old, new)
+        let mut transitions = Vec::new();
+        let mut day = start;
+        while day < end {
+            let next = day + Duration::days(1);
+            if offset(day) != offset(next) {
+                let (mut before, mut after) = (day, next);
+                while after - before > Duration::seconds(1) {
+                    let middle = before + (after - before) / 2;
+                    if offset(middle) == offset(before) { before = middle } else { after = middle }
+                }
+                transitions.push((after, offset(before), offset(after)));
+            }
+            day = next;
+        }
+        
+        let utc_offset = |seconds: i32| {
+            let sign = if seconds < 0 { '-' } else { '+' };
+            let minutes = seconds.abs() / 60;
+            format!("{}{:02}{:02}", sign, minutes / 60, minutes % 60)
+        };
+        let observance = |kind: &str, dtstart: String, rrule: Option<String>, from: i32, to: i32, at: DateTime<Utc>| {
+            let mut lines = vec![format!("BEGIN:{}", kind), format!("DTSTART:{}", dtstart)];
+            lines.extend(rrule);
+            lines.push(format!("TZOFFSETFROM:{}", utc_offset(from)));
+            lines.push(format!("TZOFFSETTO:{}", utc_offset(to)));
+            lines.push(format!("TZNAME:{}", at.with_timezone(tz).offset()));
+            lines.push(format!("END:{}", kind));
+            lines
+        };
+        
+        let mut lines = vec!["BEGIN:VTIMEZONE".to_string(), format!("TZID:{}", tzid)];
+        if transitions.is_empty() {
+            let fixed = offset(start);
+            lines.extend(observance("STANDARD", "19700101T000000".to_string(), None, fixed, fixed, start));
+        }
+        let still_changing = transitions.last().is_some_and(|(at, _, _)| at.year() > last_year);
+        for (i, &(at, from, to)) in transitions.iter().enumerate() {
+            let daylight = to > from;
+            // Wall-clock time of the change, on the clock it changes from
+            let local = at.naive_utc() + Duration::seconds(from as i64);
+            let last_of_kind = !transitions[i + 1..].iter().any(|&(_, f, t)| (t > f) == daylight);
+            let rrule = (still_changing && last_of_kind).then(|| {
+                let days_in_month = (local.date() + chrono::Months::new(1)).with_day(1)
+                    .and_then(|next| next.pred_opt())
+                    .map_or(31, |last| last.day());
+                let week = if local.day() + 7 > days_in_month { -1 } else { (local.day() as i32 - 1) / 7 + 1 };
+                let weekday = local.format("%a").to_string()[..2].to_uppercase();
+                format!("RRULE:FREQ=YEARLY;BYMONTH={};BYDAY={}{}", local.month(), week, weekday)
+            });
+            let kind = if daylight { "DAYLIGHT" } else { "STANDARD" };
+            lines.extend(observance(kind, local.format("%Y%m%dT%H%M%S").to_string(), rrule, from, to, at));
+        }
+        lines.push("END:VTIMEZONE".to_string());
+        lines
+    }
+
+    /// `ORGANIZER` for the organizer, otherwise `ATTENDEE` with role and RSVP
+    fn format_ics_attendee(attendee: &Attendee) -> String {
+        let mut line = match attendee.role {
+            AttendeeRole::Organizer => "ORGANIZER".to_string(),
+            _ => "ATTENDEE".to_string(),
+        };
+        if let Some(name) = &attendee.name {
+            line.push_str(&format!(";CN={}", Self::quote_ics_param(name)));
+        }
+        if attendee.role != AttendeeRole::Organizer {
+            line.push_str(&format!(
+                ";ROLE={};PARTSTAT={}",
+                attendee.role.ical_role(),
+                attendee.status.ical_partstat()
+            ));
+        }
+        format!("{}:mailto:{}", line, attendee.email.trim())
+    }
+
+    /// `VALARM` block for a reminder: a relative trigger before the start,
+    /// or an absolute UTC trigger, with `REPEAT`/`DURATION` for repeats
+    fn format_ics_alarm(event: &CalendarEvent, reminder: &ReminderConfig) -> Vec<String> {
+        let mut lines = vec![
+            "BEGIN:VALARM".to_string(),
+            format!("ACTION:{}", reminder.channel.ical_action()),
+        ];
+        lines.push(match reminder.at {
+            Some(at) => format!("TRIGGER;VALUE=DATE-TIME:{}Z", at.format("%Y%m%dT%H%M%S")),
+            None => format!("TRIGGER:-PT{}M", reminder.minutes_before),
+        });
+        if let Some(repeat) = reminder.repeat_minutes.filter(|m| *m > 0) {
+            if reminder.max_reminders > 1 {
+                lines.push(format!("REPEAT:{}", reminder.max_reminders - 1));
+                lines.push(format!("DURATION:PT{}M", repeat));
+            }
+        }
+        
+        let summary = Self::escape_ics_text(&event.event);
+        match reminder.channel {
+            ReminderChannel::Display => lines.push(format!("DESCRIPTION:{}", summary)),
+            ReminderChannel::Audio => {}
+            ReminderChannel::Email => {
+                lines.push(format!("SUMMARY:{}", summary));
+                lines.push(format!(
+                    "DESCRIPTION:{}",
+                    Self::escape_ics_text(event.notes.as_deref().unwrap_or(&event.event))
+                ));
+                for attendee in &event.attendees {
+                    lines.push(format!("ATTENDEE:mailto:{}", attendee.email.trim()));
+                }
+            }
+        }
+        lines.push("END:VALARM".to_string());
+        lines
+    }
+
+    /// `VTODO` block for a task; subtasks point at their parent with `RELATED-TO`
+    fn format_ics_todo(task: &Task) -> Vec<String> {
+        let utc = |at: chrono::DateTime<chrono::Utc>| at.format("%Y%m%dT%H%M%SZ").to_string();
+        let mut lines = vec![
+            "BEGIN:VTODO".to_string(),
+            format!("UID:{}", task.id),
+            format!("DTSTAMP:{}", utc(task.updated_at)),
+            format!("SUMMARY:{}", Self::escape_ics_text(&task.title)),
+        ];
+        if let Some(notes) = &task.notes {
+            lines.push(format!("DESCRIPTION:{}", Self::escape_ics_text(notes)));
+        }
+        match (task.due_date, task.due_time) {
+            (Some(date), None) => lines.push(format!("DUE;VALUE=DATE:{}", date.format("%Y%m%d"))),
+            (Some(date), time) => lines.push(format!("DUE:{}", Self::format_ics_datetime(date, time))),
+            (None, _) => {}
+        }
+        lines.push(format!("PRIORITY:{}", task.priority.ical_priority()));
+        lines.push(format!("CATEGORIES:{}", task.category.as_str().to_uppercase()));
+        lines.push(format!("PERCENT-COMPLETE:{}", task.percent_complete));
+        lines.push(format!("STATUS:{}", match task.completed_at {
+            Some(_) => "COMPLETED",
+            None if task.percent_complete > 0 => "IN-PROCESS",
+            None => "NEEDS-ACTION",
+        }));
+        if let Some(completed_at) = task.completed_at {
+            lines.push(format!("COMPLETED:{}", utc(completed_at)));
+        }
+        if let Some(parent_id) = task.parent_id {
+            lines.push(format!("RELATED-TO;RELTYPE=PARENT:{}", parent_id));
+        }
+        lines.push("END:VTODO".to_string());
+        lines
+    }
+
+    /// Parameter values containing `:`, `;` or `,` must be quoted
+    fn quote_ics_param(value: &str) -> String {
+        let value = value.replace('"', "'");
+        if value.contains([':', ';', ',']) {
+            format!("\"{}\"", value)
         } else {
-            format!("{}T000000", date.replace('-', ""))
+            value
         }
     }
 
@@ -123,20 +397,21 @@ impl Exporter {
 mod tests {
     use super::*;
     use std::fs;
-    use calendar_core::{CalendarEvent, Priority, Category};
-    
+    use calendar_core::{CalendarEvent, Priority, Category, RecurrenceConfig};
+    use calendar_core::testing::{date, time};
+
     fn create_test_events() -> Vec<CalendarEvent> {
         vec![
             {
-                let mut e = CalendarEvent::new("Event 1".to_string(), "2026-01-20".to_string());
-                e.time = Some("14:00".to_string());
+                let mut e = CalendarEvent::new("Event 1".to_string(), date("2026-01-20"));
+                e.time = Some(time("14:00"));
                 e.priority = Priority::High;
                 e.category = Category::Work;
                 e
             },
             {
-                let mut e = CalendarEvent::new("Event 2".to_string(), "2026-01-21".to_string());
-                e.time = Some("12:00".to_string());
+                let mut e = CalendarEvent::new("Event 2".to_string(), date("2026-01-21"));
+                e.time = Some(time("12:00"));
                 e.notes = Some("Lunch notes".to_string());
                 e
             },
@@ -177,25 +452,254 @@ mod tests {
         let events = create_test_events();
         let path = PathBuf::from("test_export.ics");
         
-        Exporter::export_ics(&events, &path).unwrap();
+        Exporter::export_ics(&events, &[Task::new("Pay rent")], &path).unwrap();
         
         let content = fs::read_to_string(&path).unwrap();
         assert!(content.contains("BEGIN:VCALENDAR"));
         assert!(content.contains("BEGIN:VEVENT"));
         assert!(content.contains("SUMMARY:Event 1"));
+        assert!(content.contains("BEGIN:VTODO"));
+        assert!(content.contains("SUMMARY:Pay rent"));
         assert!(content.contains("END:VCALENDAR"));
         
         fs::remove_file(&path).unwrap();
     }
     
+    #[test]
+    fn test_export_ics_recurrence() {
+        let mut event = CalendarEvent::new("Planning".to_string(), date("2026-01-13"));
+        event.time = Some(time("10:00"));
+        let mut recurring = RecurrenceConfig::from_rrule("FREQ=MONTHLY;BYDAY=TU;BYSETPOS=2").unwrap();
+        recurring.except_dates = vec![date("2026-03-10")];
+        event.recurring = Some(recurring);
+        
+        let path = PathBuf::from("test_export_recurrence.ics");
+        Exporter::export_ics(&[event], &[], &path).unwrap();
+        
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("RRULE:FREQ=MONTHLY;BYDAY=TU;BYSETPOS=2"));
+        assert!(content.contains("EXDATE:20260310T100000"));
+        
+        fs::remove_file(&path).unwrap();
+    }
+    
+    #[test]
+    fn test_export_ics_series_roundtrip() {
+        use calendar_core::EditScope;
+        use storage_engine::CalendarRepository;
+        
+        let repo = CalendarRepository::new(&PathBuf::from(":memory:")).unwrap();
+        let mut series = CalendarEvent::new("Weekly sync".to_string(), date("2026-01-06"));
+        series.time = Some(time("10:00"));
+        series.recurring = Some(RecurrenceConfig::from_rrule("FREQ=WEEKLY;COUNT=4").unwrap());
+        repo.save_event(&series).unwrap();
+        
+        let (start, end) = (date("2026-01-01"), date("2026-01-31"));
+        let instances = repo.get_by_date_range(start, end).unwrap();
+        let mut moved = instances[1].clone();
+        moved.date = date("2026-01-14");
+        repo.update_occurrence(&moved, EditScope::This).unwrap();
+        repo.delete_occurrence(&instances[2].occurrence_id().unwrap(), EditScope::This).unwrap();
+        
+        let path = PathBuf::from("test_export_series.ics");
+        Exporter::export_ics(&repo.get_series_in_range(start, end).unwrap(), &[], &path).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        
+        // Re-import: expand each RRULE, drop EXDATEs and the occurrences a
+        // RECURRENCE-ID replaces, then add back the ones not cancelled
+        let value = |line: &str| line.rsplit(':').next().unwrap().to_string();
+        let ics_date = |line: &str| NaiveDate::parse_from_str(&value(line)[..8], "%Y%m%d").unwrap();
+        let vevents: Vec<Vec<&str>> = content
+            .split("BEGIN:VEVENT")
+            .skip(1)
+            .map(|block| block.lines().collect())
+            .collect();
+        assert_eq!(vevents.len(), 3);
+        assert!(vevents.iter().all(|lines| lines.contains(&format!("UID:{}", series.id).as_str())));
+        assert_eq!(content.matches("RRULE:").count(), 1);
+        
+        let mut imported = Vec::new();
+        for lines in &vevents {
+            let find = |name: &str| lines.iter().find(|line| line.starts_with(name)).copied();
+            let dtstart = ics_date(find("DTSTART").unwrap());
+            if let Some(rrule) = find("RRULE:") {
+                let rule = RecurrenceConfig::from_rrule(rrule).unwrap();
+                imported.extend(rule.occurrences_between(dtstart, dtstart, end));
+            }
+        }
+        for lines in &vevents {
+            let find = |name: &str| lines.iter().find(|line| line.starts_with(name)).copied();
+            if let Some(recurrence_id) = find("RECURRENCE-ID") {
+                imported.retain(|day| *day != ics_date(recurrence_id));
+                if find("STATUS:") != Some("STATUS:CANCELLED") {
+                    imported.push(ics_date(find("DTSTART").unwrap()));
+                }
+            }
+        }
+        imported.sort();
+        
+        let expected: Vec<_> = repo.get_by_date_range(start, end).unwrap().iter().map(|e| e.date).collect();
+        assert_eq!(imported.len(), 3);
+        assert_eq!(imported, expected);
+    }
+    
+    #[test]
+    fn test_export_ics_all_day_span() {
+        let mut event = CalendarEvent::new("Offsite".to_string(), date("2026-04-27"));
+        event.all_day = true;
+        event.end_date = Some(date("2026-04-29"));
+        
+        let path = PathBuf::from("test_export_all_day.ics");
+        Exporter::export_ics(&[event], &[], &path).unwrap();
+        
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("DTSTART;VALUE=DATE:20260427"));
+        assert!(content.contains("DTEND;VALUE=DATE:20260430"));
+        
+        fs::remove_file(&path).unwrap();
+    }
+    
+    #[test]
+    fn test_export_ics_timezones() {
+        let mut event = CalendarEvent::new("Sync".to_string(), date("2026-03-09"));
+        event.time = Some(time("09:00"));
+        event.end_time = Some(time("09:30"));
+        event.timezone = Some("America/New_York".to_string());
+        
+        assert_eq!(
+            Exporter::format_ics_property("DTSTART", &event, event.date, event.time),
+            "DTSTART;TZID=America/New_York:20260309T090000"
+        );
+        
+        event.timezone = Some("UTC".to_string());
+        assert_eq!(
+            Exporter::format_ics_property("DTEND", &event, event.date, event.end_time),
+            "DTEND:20260309T093000Z"
+        );
+        
+        event.timezone = None;
+        assert_eq!(
+            Exporter::format_ics_property("DTSTART", &event, event.date, event.time),
+            "DTSTART:20260309T090000"
+        );
+    }
+    
+    #[test]
+    fn test_ics_vtimezone() {
+        let mut sync = CalendarEvent::new("Sync".to_string(), date("2026-03-09"));
+        sync.timezone = Some("America/New_York".to_string());
+        let mut standup = sync.clone();
+        standup.date = date("2026-06-01");
+        let mut call = CalendarEvent::new("Call".to_string(), date("2026-06-01"));
+        call.timezone = Some("Asia/Tokyo".to_string());
+        let floating = CalendarEvent::new("Lunch".to_string(), date("2026-06-01"));
+        
+        let lines = Exporter::format_ics_timezones(&[sync, standup, call, floating]);
+        let tzids: Vec<_> = lines.iter().filter(|line| line.starts_with("TZID:")).collect();
+        assert_eq!(tzids, ["TZID:America/New_York", "TZID:Asia/Tokyo"]);
+        
+        let vtimezones = lines.join("\n");
+        assert!(vtimezones.contains(
+            "BEGIN:DAYLIGHT\nDTSTART:20260308T020000\nTZOFFSETFROM:-0500\nTZOFFSETTO:-0400\nTZNAME:EDT\nEND:DAYLIGHT"
+        ));
+        assert!(vtimezones.contains(
+            "BEGIN:STANDARD\nDTSTART:20261101T020000\nTZOFFSETFROM:-0400\nTZOFFSETTO:-0500\nTZNAME:EST\nEND:STANDARD"
+        ));
+        assert!(vtimezones.contains("DTSTART:20270314T020000\nRRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU"));
+        assert!(vtimezones.contains("DTSTART:20271107T020000\nRRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU"));
+        assert_eq!(lines.iter().filter(|line| line.starts_with("RRULE:")).count(), 2);
+        
+        assert!(vtimezones.ends_with(
+            "TZID:Asia/Tokyo\nBEGIN:STANDARD\nDTSTART:19700101T000000\nTZOFFSETFROM:+0900\nTZOFFSETTO:+0900\nTZNAME:JST\nEND:STANDARD\nEND:VTIMEZONE"
+        ));
+    }
+    
+    #[test]
+    fn test_ics_attendees() {
+        use calendar_core::ParticipationStatus;
+        
+        let organizer = Attendee::named("Ana", "ana@example.com").with_role(AttendeeRole::Organizer);
+        assert_eq!(
+            Exporter::format_ics_attendee(&organizer),
+            "ORGANIZER;CN=Ana:mailto:ana@example.com"
+        );
+        
+        let guest = Attendee::named("Bo, Jr.", "bo@example.com")
+            .with_role(AttendeeRole::Optional)
+            .with_status(ParticipationStatus::Accepted);
+        assert_eq!(
+            Exporter::format_ics_attendee(&guest),
+            "ATTENDEE;CN=\"Bo, Jr.\";ROLE=OPT-PARTICIPANT;PARTSTAT=ACCEPTED:mailto:bo@example.com"
+        );
+    }
+    
+    #[test]
+    fn test_ics_alarms() {
+        let mut event = CalendarEvent::new("Launch".to_string(), date("2026-03-02"));
+        event.attendees = vec![Attendee::new("ana@example.com")];
+        
+        let repeating = ReminderConfig {
+            repeat_minutes: Some(5),
+            max_reminders: 3,
+            ..ReminderConfig::before(15)
+        };
+        assert_eq!(Exporter::format_ics_alarm(&event, &repeating), vec![
+            "BEGIN:VALARM",
+            "ACTION:DISPLAY",
+            "TRIGGER:-PT15M",
+            "REPEAT:2",
+            "DURATION:PT5M",
+            "DESCRIPTION:Launch",
+            "END:VALARM",
+        ]);
+        
+        let friday = ReminderConfig::at("2026-02-27T09:00:00Z".parse().unwrap())
+            .with_channel(ReminderChannel::Email);
+        assert_eq!(Exporter::format_ics_alarm(&event, &friday), vec![
+            "BEGIN:VALARM",
+            "ACTION:EMAIL",
+            "TRIGGER;VALUE=DATE-TIME:20260227T090000Z",
+            "SUMMARY:Launch",
+            "DESCRIPTION:Launch",
+            "ATTENDEE:mailto:ana@example.com",
+            "END:VALARM",
+        ]);
+    }
+    
+    #[test]
+    fn test_ics_todo() {
+        let parent = Task::new("Move house");
+        let mut task = Task::subtask_of(&parent, "Book van; cheap")
+            .due(date("2026-05-01"));
+        task.priority = Priority::Urgent;
+        task.percent_complete = 40;
+        
+        let lines = Exporter::format_ics_todo(&task);
+        assert_eq!(lines.first().map(String::as_str), Some("BEGIN:VTODO"));
+        assert!(lines.contains(&"SUMMARY:Book van\\; cheap".to_string()));
+        assert!(lines.contains(&"DUE;VALUE=DATE:20260501".to_string()));
+        assert!(lines.contains(&"PRIORITY:1".to_string()));
+        assert!(lines.contains(&"STATUS:IN-PROCESS".to_string()));
+        assert!(lines.contains(&format!("RELATED-TO;RELTYPE=PARENT:{}", parent.id)));
+        
+        task.due_time = Some(time("17:00"));
+        task.complete("2026-04-30T12:00:00Z".parse().unwrap());
+        let lines = Exporter::format_ics_todo(&task);
+        assert!(lines.contains(&"DUE:20260501T170000".to_string()));
+        assert!(lines.contains(&"STATUS:COMPLETED".to_string()));
+        assert!(lines.contains(&"COMPLETED:20260430T120000Z".to_string()));
+        assert!(lines.contains(&"PERCENT-COMPLETE:100".to_string()));
+    }
+    
     #[test]
     fn test_ics_datetime_formatting() {
         assert_eq!(
-            Exporter::format_ics_datetime("2026-01-20", Some("14:00")),
+            Exporter::format_ics_datetime(date("2026-01-20"), Some(time("14:00"))),
             "20260120T140000"
         );
         assert_eq!(
-            Exporter::format_ics_datetime("2026-01-20", None),
+            Exporter::format_ics_datetime(date("2026-01-20"), None),
             "20260120T000000"
         );
     }
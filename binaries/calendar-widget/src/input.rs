@@ -4,6 +4,7 @@ use std::sync::Arc;
 pub mod parser;
 
 use parser::{SimpleParser, ParsedEvent};
+use calendar_core::time::{SharedClock, TimeZone};
 
 pub enum ParserStrategy {
     SimpleParser,
@@ -30,6 +31,16 @@ impl CommandParser {
             "/search" => Some(Command::Search(parts.get(1).map(|s| s.to_string()).unwrap_or_default())),
             "/settings" => Some(Command::Settings),
             "/clear" => Some(Command::Clear),
+            "/todo" => Some(Command::AddTask(parts.get(1).map(|s| s.to_string()).unwrap_or_default())),
+            "/tasks" => Some(Command::ShowTasks),
+            "/categories" => Some(Command::ShowCategories),
+            "/holidays" => Some(Command::ShowHolidays),
+            "/free" => Some(Command::FreeTime(parts.get(1).map(|s| s.to_string()).unwrap_or_default())),
+            "/schedule" => Some(Command::Schedule(parts.get(1).map(|s| s.to_string()).unwrap_or_default())),
+            "/accept" => Some(Command::Accept(parts.get(1).map(|s| s.trim().to_string()).unwrap_or_default())),
+            "/done" => Some(Command::CompleteTask(parts.get(1).map(|s| s.trim().to_string()).unwrap_or_default())),
+            "/trash" => Some(Command::ShowTrash),
+            "/restore" => Some(Command::Restore(parts.get(1).map(|s| s.trim().to_string()).unwrap_or_default())),
             "/export" => Some(Command::Export(parts.get(1).map(|s| s.to_string()).unwrap_or_default())),
             "/exit" | "/quit" => Some(Command::Exit),
             _ => None,
@@ -41,6 +52,16 @@ pub enum Command {
     Help,
     ShowToday,
     Search(String),
+    AddTask(String),
+    ShowTasks,
+    ShowCategories,
+    ShowHolidays,
+    FreeTime(String),
+    Schedule(String),
+    Accept(String),
+    CompleteTask(String),
+    ShowTrash,
+    Restore(String),
     Settings,
     Clear,
     Export(String),
@@ -49,15 +70,15 @@ pub enum Command {
 
 pub struct InputHandler {
     simple_parser: SimpleParser,
-    command_parser: CommandParser,
+    pub command_parser: CommandParser,
     input_history: VecDeque<String>,
     history_position: Option<usize>,
 }
 
 impl InputHandler {
-    pub fn new() -> Self {
+    pub fn new(clock: SharedClock, zone: TimeZone) -> Self {
         Self {
-            simple_parser: SimpleParser,
+            simple_parser: SimpleParser::default().with_clock(clock).with_zone(zone),
             command_parser: CommandParser,
             input_history: VecDeque::with_capacity(100),
             history_position: None,
@@ -75,6 +96,34 @@ impl InputHandler {
         self.simple_parser.parse(trimmed)
     }
 
+    /// Use the user's categories when detecting categories
+    pub fn set_categories(&mut self, categories: calendar_core::CategoryRegistry) {
+        self.simple_parser.set_categories(categories);
+    }
+
+    /// Use these holidays for business days and holiday names in dates
+    pub fn set_holidays(&mut self, holidays: calendar_core::HolidayCalendar) {
+        self.simple_parser.set_holidays(holidays);
+    }
+
+    pub fn parse_task(&self, input: &str) -> calendar_core::AppResult<calendar_core::Task> {
+        self.simple_parser.parse_task(input.trim())
+    }
+
+    pub fn parse_free_query(&self, input: &str) -> calendar_core::FreeBusyQuery {
+        self.simple_parser.parse_free_query(input.trim())
+    }
+
+    pub fn parse_slot_request(&self, input: &str) -> Result<(ParsedEvent, calendar_core::SlotRequest), String> {
+        self.simple_parser.parse_slot_request(input.trim())
+    }
+
+    /// Free text asking for a time to be found rather than giving one,
+    /// e.g. "1h focus block sometime this week"
+    pub fn is_slot_request(&self, input: &str) -> bool {
+        input.split_whitespace().any(|word| word.eq_ignore_ascii_case("sometime"))
+    }
+
     pub fn handle_input(&mut self, input: &str) -> InputResult {
         let trimmed = input.trim();
 
@@ -98,6 +147,16 @@ impl InputHandler {
             Command::Help => InputResult::ShowHelp,
             Command::ShowToday => InputResult::Info("Showing today's events".to_string()),
             Command::Search(query) => InputResult::Search(query),
+            Command::AddTask(text) => InputResult::Processing(text),
+            Command::ShowTasks => InputResult::Info("Showing open tasks".to_string()),
+            Command::ShowCategories => InputResult::Info("Showing categories".to_string()),
+            Command::ShowHolidays => InputResult::Info("Showing holidays".to_string()),
+            Command::FreeTime(_) => InputResult::Info("Showing free time".to_string()),
+            Command::Schedule(text) => InputResult::Processing(text),
+            Command::Accept(choice) => InputResult::Info(format!("Accepting proposal {}", choice)),
+            Command::CompleteTask(id) => InputResult::Info(format!("Completing task {}", id)),
+            Command::ShowTrash => InputResult::Info("Showing trash".to_string()),
+            Command::Restore(id) => InputResult::Info(format!("Restoring event {}", id)),
             Command::Settings => InputResult::OpenSettings,
             Command::Clear => InputResult::Clear,
             Command::Export(format) => InputResult::Export(format),
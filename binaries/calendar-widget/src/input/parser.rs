@@ -1,16 +1,22 @@
 use regex::Regex;
-use chrono::{Local, Duration, NaiveTime};
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
+use calendar_core::availability::DEFAULT_MIN_FREE_MINUTES;
+use calendar_core::time::{SharedClock, TimeZone, find_natural_date_in, system_clock};
+use calendar_core::{AppError, AppResult, CalendarEvent, CalendarEventBuilder, Category, CategoryRegistry, FreeBusyQuery, HolidayCalendar, SlotRequest, Task};
 use once_cell::sync::Lazy;
 
 static TIME_REGEX: Lazy<Regex> = 
     Lazy::new(|| Regex::new(r"(?i)(\d{1,2})(?::(\d{2}))?\s*(am|pm)?").unwrap());
 
-static DATE_REGEX: Lazy<Regex> = 
-    Lazy::new(|| Regex::new(r"(?i)(today|tomorrow|next\s+(monday|tuesday|wednesday|thursday|friday|saturday|sunday))").unwrap());
-
 static RELATIVE_TIME_REGEX: Lazy<Regex> = 
     Lazy::new(|| Regex::new(r"(?i)(morning|afternoon|evening|noon|lunch|dinner)").unwrap());
 
+static FOR_DURATION_REGEX: Lazy<Regex> = 
+    Lazy::new(|| Regex::new(r"(?i)\bfor\s+(\d+)\s*(m|mins?|minutes?|h|hrs?|hours?)\b").unwrap());
+
+static DURATION_REGEX: Lazy<Regex> = 
+    Lazy::new(|| Regex::new(r"(?i)\b(?:for\s+)?(?:(\d+)\s*(m|mins?|minutes?|h|hrs?|hours?)|(half an hour)|an? hour)\b").unwrap());
+
 static RECURRING_REGEX: Lazy<Regex> = 
     Lazy::new(|| Regex::new(r"(?i)(every|daily|weekly|monthly|yearly)(\s+(monday|tuesday|wednesday|thursday|friday|saturday|sunday))?").unwrap());
 
@@ -28,20 +34,92 @@ pub struct ParsedEvent {
     pub recurring: Option<calendar_core::RecurrenceConfig>,
 }
 
-pub struct SimpleParser;
+impl ParsedEvent {
+    /// Typed builder for the parsed event; fails on malformed dates or times.
+    /// Unknown priorities and categories fall back to the defaults.
+    pub fn into_builder(self) -> AppResult<CalendarEventBuilder> {
+        let date = NaiveDate::parse_from_str(&self.date, "%Y-%m-%d")
+            .map_err(|_| AppError::Validation(format!("Invalid date: {}", self.date)))?;
+        let parse_time = |time: &str| {
+            calendar_core::time::parse_hhmm(time)
+                .ok_or_else(|| AppError::Validation(format!("Invalid time: {}", time)))
+        };
+
+        let mut builder = CalendarEvent::builder(self.event, date)
+            .priority(self.priority.parse().unwrap_or_default())
+            .category(self.category.parse().unwrap_or_default())
+            .tags(self.tags)
+            .metadata(self.metadata);
+        if let Some(time) = &self.time {
+            builder = builder.time(parse_time(time)?);
+        }
+        if let Some(end_time) = &self.end_time {
+            builder = builder.end_time(parse_time(end_time)?);
+        }
+        if let Some(notes) = self.notes {
+            builder = builder.notes(notes);
+        }
+        if let Some(recurring) = self.recurring {
+            builder = builder.recurrence(recurring);
+        }
+        Ok(builder)
+    }
+}
+
+/// Offline parser for free-text input
+#[derive(Debug, Clone)]
+pub struct SimpleParser {
+    /// Categories to detect, with their keywords
+    categories: CategoryRegistry,
+    /// Today, for relative dates
+    clock: SharedClock,
+    /// Zone "today" and "now" are read in
+    zone: TimeZone,
+    /// Holidays for business days and holiday names
+    holidays: HolidayCalendar,
+}
+
+impl Default for SimpleParser {
+    fn default() -> Self {
+        Self {
+            categories: CategoryRegistry::default(),
+            clock: system_clock(),
+            zone: TimeZone::Local,
+            holidays: HolidayCalendar::default(),
+        }
+    }
+}
 
 impl SimpleParser {
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn with_zone(mut self, zone: TimeZone) -> Self {
+        self.zone = zone;
+        self
+    }
+
+    pub fn set_categories(&mut self, categories: CategoryRegistry) {
+        self.categories = categories;
+    }
+
+    pub fn set_holidays(&mut self, holidays: HolidayCalendar) {
+        self.holidays = holidays;
+    }
+
     pub fn parse(&self, input: &str) -> Result<ParsedEvent, String> {
-        let input_lower = input.to_lowercase();
+        let (date, rest) = self.split_date(input);
         
         let mut event = ParsedEvent {
-            event: Self::extract_event_title(input),
-            date: Self::parse_date(input).unwrap_or_else(|| Self::today()),
-            time: Self::parse_time(input),
+            event: Self::extract_event_title(&rest),
+            date: date.unwrap_or_else(|| self.clock.today_in(self.zone)).format("%Y-%m-%d").to_string(),
+            time: Self::parse_time(&rest),
             end_time: None,
             notes: None,
             priority: Self::detect_priority(input),
-            category: Self::detect_category(input),
+            category: self.detect_category(input).as_str().to_string(),
             tags: Self::extract_tags(input),
             metadata: Self::extract_project_metadata(input),
             recurring: Self::detect_recurring(input),
@@ -60,52 +138,144 @@ impl SimpleParser {
         Ok(event)
     }
 
-    fn extract_event_title(input: &str) -> String {
-        let mut cleaned = input.to_string();
-        
-        // Remove common date/time words
-        let patterns = vec![
-            "today", "tomorrow", "next", "monday", "tuesday", "wednesday",
-            "thursday", "friday", "saturday", "sunday",
-            "am", "pm", "morning", "afternoon", "evening", "noon", "lunch", "dinner",
-            "at", "on", "in"
-        ];
-        
-        for pattern in patterns {
-            cleaned = cleaned.replace(pattern, "");
+    /// Parse a to-do. It is only due when a date is mentioned, and only
+    /// gets a due time alongside that date.
+    pub fn parse_task(&self, input: &str) -> AppResult<Task> {
+        let (date, rest) = self.split_date(input);
+        let mut task = Task::new(Self::extract_event_title(&rest));
+        task.priority = Self::detect_priority(input).parse().unwrap_or_default();
+        task.category = self.detect_category(input);
+        task.tags = Self::extract_tags(input);
+
+        if let Some(date) = date {
+            task.due_date = Some(date);
+            task.due_time = Self::parse_time(&rest)
+                .and_then(|time| calendar_core::time::parse_hhmm(&time));
         }
-        
-        // Remove time patterns
-        cleaned = TIME_REGEX.replace_all(&cleaned, "").to_string();
-        
-        // Remove extra whitespace
-        cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+
+        task.validate()?;
+        Ok(task)
     }
 
-    fn parse_date(input: &str) -> Option<String> {
-        let input_lower = input.to_lowercase();
-        let today = Local::now().date_naive();
-        
-        if input_lower.contains("today") {
-            Some(today.format("%Y-%m-%d").to_string())
-        } else if input_lower.contains("tomorrow") {
-            Some((today + Duration::days(1)).format("%Y-%m-%d").to_string())
-        } else if let Some(caps) = DATE_REGEX.captures(input) {
-            if let Some(weekday_str) = caps.get(2) {
-                let weekday = Self::parse_weekday(weekday_str.as_str())?;
-                let mut date = today;
-                while date.weekday() != weekday {
-                    date = date.succ_opt()?;
-                }
-                Some(date.format("%Y-%m-%d").to_string())
-            } else {
-                None
+    /// The first date mentioned in `input`, and `input` without it so
+    /// its digits are not mistaken for times or kept in the title
+    fn split_date(&self, input: &str) -> (Option<NaiveDate>, String) {
+        match find_natural_date_in(input, self.clock.today_in(self.zone), &self.holidays) {
+            Some(found) => {
+                let rest = format!("{} {}", &input[..found.start], &input[found.end..]);
+                (Some(found.date), rest)
+            }
+            None => (None, input.to_string()),
+        }
+    }
+
+    /// What `/free` asks about: the date mentioned (today by default),
+    /// narrowed to the morning, afternoon or evening if one is named, with
+    /// "for 1h" as the shortest slot wanted. Today's window starts no
+    /// earlier than now.
+    pub fn parse_free_query(&self, input: &str) -> FreeBusyQuery {
+        let (date, rest) = self.split_date(input);
+        let date = date.unwrap_or_else(|| self.clock.today_in(self.zone));
+        let rest = rest.to_lowercase();
+
+        let (from, to) = Self::part_of_day(&rest).unwrap_or((0, 24));
+        let start = date.and_time(NaiveTime::from_hms_opt(from, 0, 0).unwrap_or_default());
+        let end = start + Duration::hours((to - from) as i64);
+        let now = self.zone.from_utc(self.clock.now());
+
+        let min_minutes = FOR_DURATION_REGEX.captures(&rest)
+            .and_then(|caps| {
+                let amount: u32 = caps[1].parse().ok()?;
+                Some(if caps[2].starts_with('h') { amount * 60 } else { amount })
+            })
+            .unwrap_or(DEFAULT_MIN_FREE_MINUTES);
+
+        FreeBusyQuery::new(start.max(now).min(end), end).min_minutes(min_minutes)
+    }
+
+    /// What `/schedule` asks for, e.g. "1h focus block sometime this
+    /// week": the event as parsed without its length and window, and the
+    /// slot to find. "this week" or "next week" searches the rest of that
+    /// week, another date just that day, and no date the coming week; the
+    /// length defaults to an hour and a named part of the day is preferred.
+    pub fn parse_slot_request(&self, input: &str) -> Result<(ParsedEvent, SlotRequest), String> {
+        let today = self.clock.today_in(self.zone);
+        let (duration_minutes, input) = match DURATION_REGEX.captures(input) {
+            Some(caps) => {
+                let minutes = match (caps.get(1), caps.get(2), caps.get(3)) {
+                    (Some(amount), Some(unit), _) => {
+                        let amount: u32 = amount.as_str().parse().unwrap_or(1);
+                        if unit.as_str().to_lowercase().starts_with('h') { amount * 60 } else { amount }
+                    }
+                    (_, _, Some(_)) => 30,
+                    _ => 60,
+                };
+                let whole = caps.get(0).map(|m| m.range()).unwrap_or_default();
+                (minutes, format!("{} {}", &input[..whole.start], &input[whole.end..]))
+            }
+            None => (60, input.to_string()),
+        };
+        let input = input
+            .split_whitespace()
+            .filter(|word| !word.eq_ignore_ascii_case("sometime"))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let (first, last) = match find_natural_date_in(&input, today, &self.holidays) {
+            Some(found) if matches!(input[found.range()].to_lowercase().as_str(), "this week" | "next week") => {
+                let sunday = found.date + Duration::days(6 - found.date.weekday().num_days_from_monday() as i64);
+                (found.date, sunday)
             }
+            Some(found) => (found.date, found.date),
+            None => (today, today + Duration::days(6)),
+        };
+        let start = first.and_time(NaiveTime::MIN);
+        let end = (last + Duration::days(1)).and_time(NaiveTime::MIN);
+        let now = self.zone.from_utc(self.clock.now());
+
+        let mut request = SlotRequest::new(duration_minutes, start.max(now).min(end), end)
+            .priority(Self::detect_priority(&input).parse().unwrap_or_default());
+        if let Some((from, to)) = Self::part_of_day(&input.to_lowercase()) {
+            let hour = |h: u32| NaiveTime::from_hms_opt(h, 0, 0).unwrap_or_default();
+            request = request.preferred(hour(from), hour(to));
+        }
+
+        let mut event = self.parse(&input)?;
+        event.time = None;
+        event.end_time = None;
+        Ok((event, request))
+    }
+
+    /// Hours of the morning, afternoon or evening named in `input`
+    fn part_of_day(input: &str) -> Option<(u32, u32)> {
+        if input.contains("morning") {
+            Some((6, 12))
+        } else if input.contains("afternoon") {
+            Some((12, 17))
+        } else if input.contains("evening") {
+            Some((17, 22))
         } else {
             None
         }
     }
 
+    fn extract_event_title(input: &str) -> String {
+        // Remove time patterns
+        let cleaned = TIME_REGEX.replace_all(input, "");
+        
+        // Remove leftover time-of-day and filler words
+        let fillers = [
+            "am", "pm", "morning", "afternoon", "evening", "noon", "lunch", "dinner",
+            "at", "on", "in", "the",
+        ];
+        
+        cleaned
+            .split_whitespace()
+            .filter(|word| !fillers.contains(&word.to_lowercase().as_str()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     fn parse_weekday(input: &str) -> Option<chrono::Weekday> {
         match input.to_lowercase().as_str() {
             "monday" => Some(chrono::Weekday::Mon),
@@ -150,10 +320,6 @@ impl SimpleParser {
         None
     }
 
-    fn today() -> String {
-        Local::now().date_naive().format("%Y-%m-%d").to_string()
-    }
-
     fn detect_priority(input: &str) -> String {
         let input_lower = input.to_lowercase();
         
@@ -170,21 +336,10 @@ impl SimpleParser {
         }
     }
 
-    fn detect_category(input: &str) -> String {
-        let input_lower = input.to_lowercase();
-        
-        if input_lower.contains("lunch") || input_lower.contains("dinner") || 
-                  input_lower.contains("coffee") || input_lower.contains("meet") {
-            "social".to_string()
-        } else if input_lower.contains("doctor") || input_lower.contains("dentist") || 
-                  input_lower.contains("health") || input_lower.contains("appointment") {
-            "health".to_string()
-        } else if input_lower.contains("meeting") || input_lower.contains("work") || 
-                  input_lower.contains("call") || input_lower.contains("sync") {
-            "work".to_string()
-        } else {
-            "personal".to_string()
-        }
+    /// Best keyword match among the configured categories, `Personal`
+    /// when nothing matches
+    fn detect_category(&self, input: &str) -> Category {
+        self.categories.detect(input).unwrap_or(Category::Personal)
     }
 
     fn extract_project_metadata(input: &str) -> serde_json::Value {
@@ -234,6 +389,7 @@ impl SimpleParser {
                 end_date: None,
                 occurrences: None,
                 except_dates: vec![],
+                ..Default::default()
             });
         }
         
@@ -245,6 +401,7 @@ impl SimpleParser {
                 end_date: None,
                 occurrences: None,
                 except_dates: vec![],
+                ..Default::default()
             });
         }
         
@@ -256,6 +413,7 @@ impl SimpleParser {
                 end_date: None,
                 occurrences: None,
                 except_dates: vec![],
+                ..Default::default()
             });
         }
         
@@ -267,6 +425,7 @@ impl SimpleParser {
                 end_date: None,
                 occurrences: None,
                 except_dates: vec![],
+                ..Default::default()
             });
         }
         
@@ -274,7 +433,7 @@ impl SimpleParser {
         if let Some(caps) = RECURRING_REGEX.captures(input) {
             if let Some(weekday_str) = caps.get(3) {
                 if let Some(weekday) = Self::parse_weekday(weekday_str.as_str()) {
-                    let weekday_num = weekday.number_from_monday() - 1; // Convert to 0-indexed (Mon = 0)
+                    let weekday_num = weekday.num_days_from_sunday(); // RecurrenceConfig uses Sun = 0
                     
                     return Some(calendar_core::RecurrenceConfig {
                         frequency: calendar_core::RecurrenceFrequency::Weekly,
@@ -283,6 +442,7 @@ impl SimpleParser {
                         end_date: None,
                         occurrences: None,
                         except_dates: vec![],
+                        ..Default::default()
                     });
                 }
             }
@@ -295,103 +455,224 @@ impl SimpleParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
+    use calendar_core::time::FixedClock;
+    use calendar_core::testing::date;
+    
+    /// Parser that believes it is Sunday, March 1 2026
+    fn parser() -> SimpleParser {
+        SimpleParser::default().with_clock(Arc::new(FixedClock::on_date(date("2026-03-01"))))
+    }
+    
+    #[test]
+    fn test_into_builder() {
+        let parsed = parser().parse("Urgent dentist appointment tomorrow at 9am").unwrap();
+        let event = parsed.into_builder().unwrap().build().unwrap();
+        
+        assert_eq!(event.date, date("2026-03-02"));
+        assert_eq!(event.time, NaiveTime::from_hms_opt(9, 0, 0));
+        assert_eq!(event.end_time, NaiveTime::from_hms_opt(10, 0, 0));
+        assert_eq!(event.priority, calendar_core::Priority::Urgent);
+        assert_eq!(event.category, calendar_core::Category::Health);
+        assert_eq!(event.metadata["source"], "SimpleParser");
+    }
+    
+    #[test]
+    fn test_parse_task() {
+        let task = parser().parse_task("Urgent: renew passport tomorrow at 5pm #admin").unwrap();
+        
+        assert_eq!(task.due_date, Some(date("2026-03-02")));
+        assert_eq!(task.due_time, NaiveTime::from_hms_opt(17, 0, 0));
+        assert_eq!(task.priority, calendar_core::Priority::Urgent);
+        assert_eq!(task.tags, vec!["admin"]);
+        
+        // No date mentioned: not due, and stray numbers are not times
+        let task = parser().parse_task("Buy 2 tickets").unwrap();
+        assert_eq!(task.due_date, None);
+        assert_eq!(task.due_time, None);
+    }
     
     #[test]
     fn test_parse_today() {
-        let parser = SimpleParser;
-        let result = parser.parse("Meeting today at 2pm").unwrap();
+        let result = parser().parse("Meeting today at 2pm").unwrap();
         
-        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
-        assert_eq!(result.date, today);
+        assert_eq!(result.date, "2026-03-01");
         assert_eq!(result.time, Some("14:00".to_string()));
         assert!(result.event.contains("Meeting"));
     }
     
+    #[test]
+    fn test_parse_natural_date() {
+        let parser = parser();
+        let result = parser.parse("Dentist in 3 days at 3pm").unwrap();
+        
+        assert_eq!(result.date, "2026-03-04");
+        assert_eq!(result.time, Some("15:00".to_string()));
+        assert_eq!(result.event, "Dentist");
+        
+        // The day of month is not read as a time
+        let result = parser.parse("Pay rent on the 1st").unwrap();
+        assert_eq!(result.date, "2026-03-01");
+        assert_eq!(result.time, None);
+        assert_eq!(result.event, "Pay rent");
+    }
+    
+    #[test]
+    fn test_parse_business_days_and_holidays() {
+        let mut parser = parser();
+        parser.set_holidays(HolidayCalendar::country("US").unwrap());
+        
+        let result = parser.parse("Team offsite 2 business days after Thanksgiving").unwrap();
+        assert_eq!(result.date, "2026-11-30");
+        assert_eq!(result.event, "Team offsite");
+        
+        let result = parser.parse("BBQ on Independence Day at 5pm").unwrap();
+        assert_eq!(result.date, "2026-07-03");
+        assert_eq!(result.time, Some("17:00".to_string()));
+    }
+    
+    #[test]
+    fn test_today_in_configured_zone() {
+        // Late on March 1 in UTC is already March 2 in Kiritimati (UTC+14)
+        let clock = Arc::new(FixedClock(date("2026-03-01").and_hms_opt(23, 0, 0).unwrap().and_utc()));
+        let zone = TimeZone::parse("Pacific/Kiritimati").unwrap();
+        let parser = SimpleParser::default().with_clock(clock).with_zone(zone);
+        
+        assert_eq!(parser.parse("lunch at noon").unwrap().date, "2026-03-02");
+        assert_eq!(parser.parse("review tomorrow").unwrap().date, "2026-03-03");
+        
+        // Today's window starts at the zone's current time, 13:00
+        let query = parser.parse_free_query("today");
+        assert_eq!(query.start.to_string(), "2026-03-02 13:00:00");
+    }
+    
+    #[test]
+    fn test_parse_free_query() {
+        let query = parser().parse_free_query("thursday afternoon for 1h");
+        
+        assert_eq!(query.start.to_string(), "2026-03-05 12:00:00");
+        assert_eq!(query.end.to_string(), "2026-03-05 17:00:00");
+        assert_eq!(query.min_minutes, 60);
+        
+        // The clock is stopped at noon, so today's morning is already over
+        let query = parser().parse_free_query("this morning");
+        assert_eq!(query.start, query.end);
+        assert_eq!(parser().parse_free_query("").min_minutes, DEFAULT_MIN_FREE_MINUTES);
+    }
+    
+    #[test]
+    fn test_parse_slot_request() {
+        let (event, request) = parser().parse_slot_request("1h focus block sometime next week").unwrap();
+        
+        assert_eq!(event.event, "focus block");
+        assert_eq!(event.time, None);
+        assert_eq!(request.duration_minutes, 60);
+        assert_eq!(request.start.to_string(), "2026-03-02 00:00:00");
+        assert_eq!(request.end.to_string(), "2026-03-09 00:00:00");
+        assert_eq!(request.preferred, None);
+        
+        let (event, request) = parser().parse_slot_request("urgent 30m review tomorrow afternoon").unwrap();
+        assert_eq!(event.event, "urgent review");
+        assert_eq!(request.duration_minutes, 30);
+        assert_eq!(request.start.to_string(), "2026-03-02 00:00:00");
+        assert_eq!(request.end.to_string(), "2026-03-03 00:00:00");
+        assert_eq!(request.preferred.map(|p| p.start.to_string()).as_deref(), Some("12:00:00"));
+        assert_eq!(request.priority, calendar_core::Priority::Urgent);
+    }
+    
     #[test]
     fn test_parse_tomorrow() {
-        let parser = SimpleParser;
-        let result = parser.parse("Lunch tomorrow").unwrap();
+        let result = parser().parse("Lunch tomorrow").unwrap();
         
-        let tomorrow = (Local::now().date_naive() + Duration::days(1))
-            .format("%Y-%m-%d").to_string();
-        assert_eq!(result.date, tomorrow);
+        assert_eq!(result.date, "2026-03-02");
         assert_eq!(result.time, Some("12:00".to_string())); // "lunch" → 12:00
         assert_eq!(result.category, "social"); // "lunch" → social
     }
     
     #[test]
     fn test_parse_time_12hour_pm() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Event at 2pm").unwrap();
         assert_eq!(result.time, Some("14:00".to_string()));
     }
     
     #[test]
     fn test_parse_time_12hour_am() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Event at 9am").unwrap();
         assert_eq!(result.time, Some("09:00".to_string()));
     }
     
     #[test]
     fn test_parse_time_24hour() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Event at 14:30").unwrap();
         assert_eq!(result.time, Some("14:30".to_string()));
     }
     
     #[test]
     fn test_relative_time_morning() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Meeting in the morning").unwrap();
         assert_eq!(result.time, Some("09:00".to_string()));
     }
     
     #[test]
     fn test_relative_time_evening() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Dinner in the evening").unwrap();
         assert_eq!(result.time, Some("18:00".to_string()));
     }
     
     #[test]
     fn test_priority_urgent() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Urgent meeting tomorrow").unwrap();
         assert_eq!(result.priority, "urgent");
     }
     
     #[test]
     fn test_priority_deadline() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Project deadline next week").unwrap();
         assert_eq!(result.priority, "urgent");
     }
     
     #[test]
     fn test_category_work() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Team meeting tomorrow").unwrap();
         assert_eq!(result.category, "work");
     }
     
     #[test]
     fn test_category_social() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Coffee with Sarah").unwrap();
         assert_eq!(result.category, "social");
     }
     
+    #[test]
+    fn test_user_defined_category() {
+        let garden = calendar_core::CategoryDefinition::new("garden".parse().unwrap(), "Garden", "#22C55E")
+            .with_keywords(["lawn"]);
+        let mut parser = SimpleParser::default();
+        parser.set_categories(CategoryRegistry::with_definitions([garden]));
+        
+        assert_eq!(parser.parse("Mow the lawn tomorrow").unwrap().category, "garden");
+        assert_eq!(parser.parse("Coffee with Sarah").unwrap().category, "social");
+    }
+    
     #[test]
     fn test_category_health() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Doctor appointment tomorrow").unwrap();
         assert_eq!(result.category, "health");
     }
     
     #[test]
     fn test_tag_extraction_hashtag() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Meeting tomorrow #important #urgent").unwrap();
         
         assert!(result.tags.contains(&"important".to_string()));
@@ -400,7 +681,7 @@ mod tests {
     
     #[test]
     fn test_auto_tag_meeting() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Team meeting tomorrow").unwrap();
         
         assert!(result.tags.contains(&"meeting".to_string()));
@@ -408,7 +689,7 @@ mod tests {
     
     #[test]
     fn test_end_time_inferred() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Meeting at 2pm").unwrap();
         
         assert_eq!(result.time, Some("14:00".to_string()));
@@ -417,7 +698,7 @@ mod tests {
     
     #[test]
     fn test_recurring_daily() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Standup daily at 9am").unwrap();
         
         assert!(result.recurring.is_some());
@@ -426,7 +707,7 @@ mod tests {
     
     #[test]
     fn test_recurring_weekly() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Meeting every Monday at 2pm").unwrap();
         
         assert!(result.recurring.is_some());
@@ -437,7 +718,7 @@ mod tests {
     
     #[test]
     fn test_recurring_monthly() {
-        let parser = SimpleParser;
+        let parser = SimpleParser::default();
         let result = parser.parse("Report monthly").unwrap();
         
         assert!(result.recurring.is_some());
@@ -11,13 +11,14 @@ mod config;
 mod storage;
 mod api;
 mod input;
-mod ui;
 mod export;
 mod notifications;
 
 use crate::app::App;
 use crate::config::Settings;
 use crate::storage::Repository;
+use calendar_core::HolidayCalendar;
+use calendar_core::time::SharedClock;
 
 fn main() -> Result<()> {
     let subscriber = FmtSubscriber::builder()
@@ -68,6 +69,10 @@ struct AppState {
     repository: Repository,
     deepseek_client: Option<Arc<deepseek_client::DeepSeekClient>>,
     notification_service: Arc<notifications::NotificationService>,
+    /// Real time, or a simulated date from the settings
+    clock: SharedClock,
+    /// Public holidays from the settings
+    holidays: Arc<HolidayCalendar>,
     input_buffer: Arc<std::sync::RwLock<String>>,
     processing_state: Arc<std::sync::RwLock<ProcessingState>>,
 }
@@ -82,7 +87,8 @@ enum ProcessingState {
 
 impl AppState {
     fn new(settings: &Settings) -> Result<Self> {
-        let repository = Repository::new(&settings.database_path)?;
+        let clock = settings.clock();
+        let repository = Repository::new(&settings.database_path, clock.clone())?;
         
         // Make AI client optional - app works without API key
         let deepseek_client = if !settings.deepseek_api_key.is_empty() {
@@ -105,10 +111,18 @@ impl AppState {
             None
         };
 
+        // A bad holiday file should not keep the widget from starting
+        let holidays = settings.holiday_calendar().unwrap_or_else(|e| {
+            error!("{:#}. Continuing without holidays.", e);
+            HolidayCalendar::default()
+        });
+
         // Initialize notification service
         let notification_service = Arc::new(notifications::NotificationService::new(
             settings.notifications.enabled,
             settings.notifications.play_sound,
+            settings.time_zone(),
+            clock.clone(),
         ));
         
         Ok(Self {
@@ -116,6 +130,8 @@ impl AppState {
             repository,
             deepseek_client,
             notification_service,
+            clock,
+            holidays: Arc::new(holidays),
             input_buffer: Arc::new(std::sync::RwLock::new(String::new())),
             processing_state: Arc::new(std::sync::RwLock::new(ProcessingState::Idle)),
         })
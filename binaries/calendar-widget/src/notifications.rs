@@ -1,55 +1,91 @@
 use notify_rust::Notification;
-use calendar_core::CalendarEvent;
+use calendar_core::{CalendarEvent, ReminderChannel, ReminderConfig};
+use calendar_core::time::{SharedClock, TimeZone};
+use std::collections::HashSet;
 use std::time::Duration;
-use chrono::{Local, NaiveDateTime};
+use chrono::{DateTime, Utc};
 
 pub struct NotificationService {
     enabled: bool,
     play_sound: bool,
+    /// Zone for events without their own, and for the times shown to the user
+    zone: TimeZone,
+    clock: SharedClock,
 }
 
 impl NotificationService {
-    pub fn new(enabled: bool, play_sound: bool) -> Self {
+    pub fn new(enabled: bool, play_sound: bool, zone: TimeZone, clock: SharedClock) -> Self {
         Self {
             enabled,
             play_sound,
+            zone,
+            clock,
         }
     }
     
-    /// Check if an event should trigger a notification now
-    pub fn should_notify(&self, event: &CalendarEvent, default_minutes: u32) -> bool {
+    /// Reminders firing within a minute of now, with the event each is for.
+    /// Events without reminders get a display reminder `default_minutes`
+    /// before the start. Relative reminders fire for every occurrence;
+    /// absolute ones belong to the whole series and fire only with its
+    /// first occurrence in `events`.
+    pub fn due_reminders<'a>(
+        &self,
+        events: &'a [CalendarEvent],
+        default_minutes: u32,
+    ) -> Vec<(&'a CalendarEvent, ReminderConfig)> {
         if !self.enabled {
-            return false;
+            return Vec::new();
         }
         
-        // Get reminder minutes (from event or default)
-        let minutes_before = event.reminder
-            .as_ref()
-            .map(|r| r.minutes_before)
-            .unwrap_or(default_minutes);
-        
-        // Parse event date/time
-        let event_datetime = match self.parse_event_datetime(event) {
-            Some(dt) => dt,
-            None => return false, // Can't parse, skip
-        };
-        
-        // Calculate notification time
-        let notify_time = event_datetime - chrono::Duration::minutes(minutes_before as i64);
-        let now = Local::now().naive_local();
-        
-        // Check if we're within 1 minute of notification time
-        let diff = (notify_time - now).num_seconds().abs();
-        diff < 60
+        let now = self.clock.now();
+        let mut series_seen = HashSet::new();
+        let mut due = Vec::new();
+        for event in events {
+            let reminders = if event.reminders.is_empty() {
+                vec![ReminderConfig::before(default_minutes)]
+            } else {
+                event.reminders.clone()
+            };
+            
+            // Relative reminders need a start time; absolute ones do not
+            let start = self.parse_event_datetime(event);
+            let first_of_series = series_seen.insert(event.series_id.unwrap_or(event.id));
+            
+            for reminder in reminders {
+                if reminder.at.is_some() && !first_of_series {
+                    continue;
+                }
+                let fires_now = reminder.fire_times(start)
+                    .iter()
+                    .any(|at| (*at - now).num_seconds().abs() < 60);
+                if fires_now {
+                    due.push((event, reminder));
+                }
+            }
+        }
+        due
     }
     
-    /// Send OS notification for an event
-    pub fn send_notification(&self, event: &CalendarEvent) -> Result<(), String> {
+    /// Deliver one reminder over its channel. Email alarms are left to the
+    /// calendar the event is exported to.
+    pub fn send_reminder(&self, event: &CalendarEvent, reminder: &ReminderConfig) -> Result<(), String> {
+        match reminder.channel {
+            ReminderChannel::Display => self.send_notification(event, false),
+            ReminderChannel::Audio => self.send_notification(event, true),
+            ReminderChannel::Email => Ok(()),
+        }
+    }
+    
+    /// Send OS notification for an event, with sound if enabled
+    pub fn send_notification(&self, event: &CalendarEvent, sound: bool) -> Result<(), String> {
         if !self.enabled {
             return Ok(());
         }
         
-        let time_str = event.time.as_deref().unwrap_or("--:--");
+        let event = event.in_zone(self.zone, self.zone);
+        let time_str = event.time
+            .map(|t| t.format("%H:%M").to_string())
+            .unwrap_or_else(|| "--:--".to_string());
         let body = format!(
             "{} at {}\n{}",
             event.date,
@@ -57,45 +93,137 @@ impl NotificationService {
             event.notes.as_deref().unwrap_or("")
         );
         
-        Notification::new()
+        let mut notification = Notification::new();
+        notification
             .summary(&event.event)
             .body(&body)
             .icon("calendar")
-            .timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(10));
+        if sound && self.play_sound {
+            notification.sound_name("message-new-instant");
+        }
+        notification
             .show()
             .map_err(|e| format!("Notification failed: {}", e))?;
         
         Ok(())
     }
     
-    fn parse_event_datetime(&self, event: &CalendarEvent) -> Option<NaiveDateTime> {
-        let date = chrono::NaiveDate::parse_from_str(&event.date, "%Y-%m-%d").ok()?;
-        let time = event.time.as_ref()?;
-        let time_parsed = chrono::NaiveTime::parse_from_str(time, "%H:%M").ok()?;
-        Some(NaiveDateTime::new(date, time_parsed))
+    /// Start instant of a timed event, resolved in its own zone
+    fn parse_event_datetime(&self, event: &CalendarEvent) -> Option<DateTime<Utc>> {
+        event.time.as_ref()?;
+        event.start_utc(self.zone)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use calendar_core::{CalendarEvent, ReminderConfig};
+    use calendar_core::CalendarEvent;
+    use std::sync::Arc;
+    use calendar_core::time::FixedClock;
+    use calendar_core::testing::{date, time};
+
+    fn now() -> DateTime<Utc> {
+        "2026-03-01T10:00:00Z".parse().unwrap()
+    }
+    
+    /// Service in UTC whose clock is stopped at [`now`]
+    fn service(play_sound: bool) -> NotificationService {
+        NotificationService::new(true, play_sound, TimeZone::Utc, Arc::new(FixedClock(now())))
+    }
     
     #[test]
     fn test_should_notify_disabled() {
-        let service = NotificationService::new(false, false);
-        let event = CalendarEvent::new("Test".to_string(), "2026-01-20".to_string());
+        let service = NotificationService::new(false, false, TimeZone::Local, Arc::new(FixedClock(now())));
+        let event = CalendarEvent::new("Test".to_string(), date("2026-01-20"));
         
-        assert!(!service.should_notify(&event, 15));
+        assert!(service.due_reminders(std::slice::from_ref(&event), 15).is_empty());
     }
     
     #[test]
     fn test_parse_event_datetime() {
-        let service = NotificationService::new(true, true);
-        let mut event = CalendarEvent::new("Test".to_string(), "2026-01-20".to_string());
-        event.time = Some("14:00".to_string());
+        let service = NotificationService::new(true, true, TimeZone::Local, Arc::new(FixedClock(now())));
+        let mut event = CalendarEvent::new("Test".to_string(), date("2026-01-20"));
+        event.time = Some(time("14:00"));
         
         let dt = service.parse_event_datetime(&event);
         assert!(dt.is_some());
     }
+    
+    #[test]
+    fn test_event_zone_used_for_timing() {
+        let service = service(true);
+        let mut event = CalendarEvent::new("Test".to_string(), date("2026-07-01"));
+        event.time = Some(time("09:00"));
+        event.timezone = Some("Asia/Tokyo".to_string());
+        
+        let dt = service.parse_event_datetime(&event).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2026-07-01T00:00:00+00:00");
+    }
+    
+    #[test]
+    fn test_should_notify_at_reminder_time() {
+        let service = service(true);
+        let start = now() + chrono::Duration::minutes(15);
+        let mut event = CalendarEvent::new("Soon".to_string(), start.date_naive());
+        event.time = Some(time(&start.format("%H:%M").to_string()));
+        event.timezone = Some("UTC".to_string());
+        
+        // Reminder due within the minute
+        assert!(!service.due_reminders(std::slice::from_ref(&event), 15).is_empty());
+        assert!(service.due_reminders(std::slice::from_ref(&event), 60).is_empty());
+    }
+    
+    #[test]
+    fn test_event_reminders_replace_default() {
+        let service = service(true);
+        let start = now() + chrono::Duration::minutes(15);
+        let mut event = CalendarEvent::new("Soon".to_string(), start.date_naive());
+        event.time = Some(time(&start.format("%H:%M").to_string()));
+        event.timezone = Some("UTC".to_string());
+        event.reminders = vec![
+            ReminderConfig::before(60),
+            ReminderConfig::before(15).with_channel(ReminderChannel::Audio),
+        ];
+        
+        let due = service.due_reminders(std::slice::from_ref(&event), 60);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].1.channel, ReminderChannel::Audio);
+    }
+    
+    #[test]
+    fn test_absolute_reminder_ignores_start() {
+        let service = service(false);
+        // Untimed deadline next week, reminder now
+        let mut event = CalendarEvent::new("Deadline".to_string(), (now() + chrono::Duration::days(7)).date_naive());
+        event.reminders = vec![ReminderConfig::at(now())];
+        
+        assert!(!service.due_reminders(std::slice::from_ref(&event), 15).is_empty());
+        
+        event.reminders = vec![ReminderConfig::at(now() + chrono::Duration::hours(1))];
+        assert!(service.due_reminders(std::slice::from_ref(&event), 15).is_empty());
+    }
+    
+    #[test]
+    fn test_absolute_reminder_fires_once_per_series() {
+        use calendar_core::RecurrenceConfig;
+        
+        let service = service(false);
+        let mut series = CalendarEvent::new("Review".to_string(), (now() + chrono::Duration::days(2)).date_naive());
+        series.time = Some(time("10:00"));
+        series.recurring = Some(RecurrenceConfig::from_rrule("FREQ=WEEKLY").unwrap());
+        series.reminders = vec![ReminderConfig::at(now())];
+        let mut events: Vec<_> = (0..4)
+            .map(|week| series.instance(series.date + chrono::Duration::weeks(week)))
+            .collect();
+        
+        let mut deadline = CalendarEvent::new("Deadline".to_string(), series.date);
+        deadline.reminders = vec![ReminderConfig::at(now())];
+        events.push(deadline.clone());
+        
+        let due = service.due_reminders(&events, 15);
+        let fired: Vec<_> = due.iter().map(|(event, _)| event.id).collect();
+        assert_eq!(fired, vec![events[0].id, deadline.id]);
+    }
 }
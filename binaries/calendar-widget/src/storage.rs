@@ -1,24 +1,83 @@
 use std::path::PathBuf;
-use storage_engine::CalendarRepository;
-use calendar_core::AppResult;
+use std::sync::Arc;
+use calendar_core::{AppError, AppResult};
+use calendar_core::time::{Clock, SharedClock, TimeZone};
 
 pub use storage_engine::CalendarRepository;
 
-pub struct Repository(pub CalendarRepository);
+#[derive(Clone)]
+pub struct Repository(pub Arc<CalendarRepository>);
 
 impl Repository {
-    pub fn new(db_path: &PathBuf) -> AppResult<Self> {
-        Ok(Self(CalendarRepository::new(db_path)?))
+    pub fn new(db_path: &PathBuf, clock: SharedClock) -> AppResult<Self> {
+        Ok(Self(Arc::new(CalendarRepository::new(db_path)?.with_clock(clock))))
     }
 
-    pub fn get_today_events(&self) -> AppResult<Vec<calendar_core::CalendarEvent>> {
-        let today = chrono::Local::now()
-            .format("%Y-%m-%d")
-            .to_string();
-        self.0.get_by_date(&today)
+    /// Today's events in `zone` by `clock`, converted to that zone's
+    /// wall-clock time.
+    ///
+    /// Events written in other zones can fall on a neighbouring date, so the
+    /// surrounding days are loaded and filtered after conversion.
+    pub fn get_today_events(&self, zone: TimeZone, clock: &dyn Clock) -> AppResult<Vec<calendar_core::CalendarEvent>> {
+        let today = clock.today_in(zone);
+        let start = today - chrono::Duration::days(1);
+        let end = today + chrono::Duration::days(1);
+
+        let mut events: Vec<_> = self.0.get_by_date_range(start, end)?
+            .into_iter()
+            .map(|event| event.in_zone(zone, zone))
+            .filter(|event| event.overlaps_dates(today, today))
+            .collect();
+        events.sort_by_key(|event| event.time);
+        Ok(events)
     }
 
     pub fn save_event(&self, event: &calendar_core::CalendarEvent) -> AppResult<()> {
         self.0.save_event(event)
     }
+
+    pub fn save_task(&self, task: &calendar_core::Task) -> AppResult<()> {
+        self.0.save_task(task)
+    }
+
+    /// Complete the open task whose id starts with `prefix`, as shown by
+    /// `/tasks`
+    pub fn complete_task_by_prefix(&self, prefix: &str) -> AppResult<calendar_core::Task> {
+        let prefix = prefix.trim().to_lowercase();
+        if prefix.is_empty() {
+            return Err(AppError::Validation("Task id is required".to_string()));
+        }
+
+        let matches: Vec<_> = self.0.get_open_tasks()?
+            .into_iter()
+            .filter(|task| task.id.to_string().starts_with(&prefix))
+            .collect();
+        match matches.as_slice() {
+            [] => Err(AppError::NotFound),
+            [task] => self.0.complete_task(&task.id.to_string()),
+            _ => Err(AppError::Validation(format!("Ambiguous task id: {}", prefix))),
+        }
+    }
+
+    /// Restore the trashed event whose id starts with `prefix`, as shown by
+    /// `/trash`
+    pub fn restore_event_by_prefix(&self, prefix: &str) -> AppResult<calendar_core::CalendarEvent> {
+        let prefix = prefix.trim().to_lowercase();
+        if prefix.is_empty() {
+            return Err(AppError::Validation("Event id is required".to_string()));
+        }
+
+        let matches: Vec<_> = self.0.get_trash()?
+            .into_iter()
+            .filter(|trashed| trashed.event.id.to_string().starts_with(&prefix))
+            .collect();
+        match matches.as_slice() {
+            [] => Err(AppError::NotFound),
+            [trashed] => {
+                self.0.restore_event(&trashed.event.id.to_string())?;
+                Ok(trashed.event.clone())
+            }
+            _ => Err(AppError::Validation(format!("Ambiguous event id: {}", prefix))),
+        }
+    }
 }
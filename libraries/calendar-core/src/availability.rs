@@ -0,0 +1,401 @@
+//! Working hours and free/busy time.
+//!
+//! Times here are wall-clock times in the viewer's zone; events are
+//! converted into it before they are compared.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::holidays::HolidayCalendar;
+use crate::models::{CalendarEvent, EventStatus};
+use crate::time::TimeZone;
+use crate::validation::Validator;
+use crate::{AppError, AppResult};
+
+/// Busy time assumed for a timed event without an end
+pub const DEFAULT_BUSY_MINUTES: i64 = 60;
+
+/// Shortest free slot reported unless a query asks otherwise
+pub const DEFAULT_MIN_FREE_MINUTES: u32 = 30;
+
+/// A stretch of the day, `start` up to (not including) `end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeRange {
+    #[serde(with = "crate::time::serde_hhmm")]
+    pub start: NaiveTime,
+    #[serde(with = "crate::time::serde_hhmm")]
+    pub end: NaiveTime,
+}
+
+impl TimeRange {
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self { start, end }
+    }
+}
+
+/// Working time of one day; breaks are cut out of it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkingDay {
+    #[serde(with = "crate::time::serde_hhmm")]
+    pub start: NaiveTime,
+    #[serde(with = "crate::time::serde_hhmm")]
+    pub end: NaiveTime,
+    #[serde(default)]
+    pub breaks: Vec<TimeRange>,
+}
+
+impl WorkingDay {
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self { start, end, breaks: Vec::new() }
+    }
+
+    pub fn with_break(mut self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.breaks.push(TimeRange::new(start, end));
+        self
+    }
+
+    /// The working stretches of the day in order, breaks removed
+    pub fn periods(&self) -> Vec<TimeRange> {
+        let mut breaks = self.breaks.clone();
+        breaks.sort_by_key(|b| b.start);
+
+        let mut periods = Vec::new();
+        let mut cursor = self.start;
+        for b in breaks {
+            if b.start > cursor {
+                periods.push(TimeRange::new(cursor, b.start.min(self.end)));
+            }
+            cursor = cursor.max(b.end);
+        }
+        if cursor < self.end {
+            periods.push(TimeRange::new(cursor, self.end));
+        }
+        periods.retain(|p| p.start < p.end);
+        periods
+    }
+}
+
+/// Working hours per weekday; a day without hours is a day off
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkingHours {
+    #[serde(default)]
+    pub monday: Option<WorkingDay>,
+    #[serde(default)]
+    pub tuesday: Option<WorkingDay>,
+    #[serde(default)]
+    pub wednesday: Option<WorkingDay>,
+    #[serde(default)]
+    pub thursday: Option<WorkingDay>,
+    #[serde(default)]
+    pub friday: Option<WorkingDay>,
+    #[serde(default)]
+    pub saturday: Option<WorkingDay>,
+    #[serde(default)]
+    pub sunday: Option<WorkingDay>,
+}
+
+/// Monday to Friday, 09:00-17:00 with a lunch break at 12:00
+impl Default for WorkingHours {
+    fn default() -> Self {
+        let hm = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap_or_default();
+        let day = WorkingDay::new(hm(9), hm(17)).with_break(hm(12), hm(13));
+        Self {
+            monday: Some(day.clone()),
+            tuesday: Some(day.clone()),
+            wednesday: Some(day.clone()),
+            thursday: Some(day.clone()),
+            friday: Some(day),
+            saturday: None,
+            sunday: None,
+        }
+    }
+}
+
+impl WorkingHours {
+    pub fn day(&self, weekday: Weekday) -> Option<&WorkingDay> {
+        match weekday {
+            Weekday::Mon => self.monday.as_ref(),
+            Weekday::Tue => self.tuesday.as_ref(),
+            Weekday::Wed => self.wednesday.as_ref(),
+            Weekday::Thu => self.thursday.as_ref(),
+            Weekday::Fri => self.friday.as_ref(),
+            Weekday::Sat => self.saturday.as_ref(),
+            Weekday::Sun => self.sunday.as_ref(),
+        }
+    }
+
+    /// Working stretches on `date`, empty on a day off
+    pub fn periods_on(&self, date: NaiveDate) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+        self.day(date.weekday())
+            .map(|day| {
+                day.periods()
+                    .into_iter()
+                    .map(|p| (date.and_time(p.start), date.and_time(p.end)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Check all days, collecting every problem into
+    /// [`AppError::InvalidFields`]
+    pub fn validate(&self) -> AppResult<()> {
+        let errors = Validator::validate_working_hours(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::InvalidFields(errors))
+        }
+    }
+}
+
+/// Time taken by one or more overlapping events
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BusyBlock {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub event_ids: Vec<Uuid>,
+    pub titles: Vec<String>,
+}
+
+impl BusyBlock {
+    pub fn minutes(&self) -> i64 {
+        (self.end - self.start).num_minutes()
+    }
+}
+
+/// Time with nothing scheduled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FreeSlot {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+impl FreeSlot {
+    pub fn minutes(&self) -> i64 {
+        (self.end - self.start).num_minutes()
+    }
+}
+
+/// Result of a [`FreeBusyQuery`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FreeBusy {
+    pub busy: Vec<BusyBlock>,
+    pub free: Vec<FreeSlot>,
+}
+
+/// Which time between `start` and `end` is taken, and which free slots of
+/// at least `min_minutes` are left. With working hours, free time is only
+/// looked for within them, and not on the holidays of `holidays`; busy
+/// time is reported either way. Free slots keep `buffer_minutes` away from
+/// busy time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FreeBusyQuery {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    #[serde(default = "default_min_minutes")]
+    pub min_minutes: u32,
+    #[serde(default)]
+    pub working_hours: Option<WorkingHours>,
+    #[serde(default)]
+    pub buffer_minutes: u32,
+    #[serde(default)]
+    pub holidays: Option<HolidayCalendar>,
+}
+
+fn default_min_minutes() -> u32 {
+    DEFAULT_MIN_FREE_MINUTES
+}
+
+impl FreeBusyQuery {
+    pub fn new(start: NaiveDateTime, end: NaiveDateTime) -> Self {
+        Self {
+            start,
+            end,
+            min_minutes: DEFAULT_MIN_FREE_MINUTES,
+            working_hours: None,
+            buffer_minutes: 0,
+            holidays: None,
+        }
+    }
+
+    /// The whole of `date`
+    pub fn day(date: NaiveDate) -> Self {
+        let start = date.and_time(NaiveTime::MIN);
+        Self::new(start, start + Duration::days(1))
+    }
+
+    pub fn min_minutes(mut self, minutes: u32) -> Self {
+        self.min_minutes = minutes;
+        self
+    }
+
+    pub fn working_hours(mut self, hours: WorkingHours) -> Self {
+        self.working_hours = Some(hours);
+        self
+    }
+
+    pub fn buffer_minutes(mut self, minutes: u32) -> Self {
+        self.buffer_minutes = minutes;
+        self
+    }
+
+    pub fn holidays(mut self, holidays: HolidayCalendar) -> Self {
+        self.holidays = Some(holidays);
+        self
+    }
+
+    /// First and last dates touched, for loading the events to compute over
+    pub fn dates(&self) -> (NaiveDate, NaiveDate) {
+        (self.start.date(), (self.end - Duration::seconds(1)).date().max(self.start.date()))
+    }
+
+    /// Free/busy over `events` seen from `zone`. Cancelled, all-day and
+    /// untimed events do not take time.
+    pub fn compute(&self, events: &[CalendarEvent], zone: TimeZone) -> FreeBusy {
+        let busy = self.busy_blocks(events, zone);
+
+        let periods = match &self.working_hours {
+            Some(hours) => {
+                let (first, last) = self.dates();
+                let holidays: Vec<_> = self.holidays.as_ref()
+                    .map(|calendar| calendar.holidays_between(first, last).into_iter().map(|h| h.date).collect())
+                    .unwrap_or_default();
+                first.iter_days()
+                    .take_while(|date| *date <= last)
+                    .filter(|date| !holidays.contains(date))
+                    .flat_map(|date| hours.periods_on(date))
+                    .collect()
+            }
+            None => vec![(self.start, self.end)],
+        };
+
+        let min = Duration::minutes(self.min_minutes.max(1) as i64);
+        let buffer = Duration::minutes(self.buffer_minutes as i64);
+        let mut free = Vec::new();
+        for (start, end) in periods {
+            let (start, end) = (start.max(self.start), end.min(self.end));
+            let mut cursor = start;
+            for block in busy.iter().filter(|b| b.end + buffer > start && b.start - buffer < end) {
+                if block.start - buffer - cursor >= min {
+                    free.push(FreeSlot { start: cursor, end: block.start - buffer });
+                }
+                cursor = cursor.max(block.end + buffer);
+            }
+            if end - cursor >= min {
+                free.push(FreeSlot { start: cursor, end });
+            }
+        }
+
+        FreeBusy { busy, free }
+    }
+
+    /// Event times clipped to the query, overlapping ones merged
+    fn busy_blocks(&self, events: &[CalendarEvent], zone: TimeZone) -> Vec<BusyBlock> {
+        let mut spans: Vec<_> = events
+            .iter()
+            .filter(|event| event.status != EventStatus::Cancelled && !event.all_day && event.time.is_some())
+            .filter_map(|event| {
+                let event = event.in_zone(zone, zone);
+                let start = event.start_naive();
+                let end = event.end_naive().unwrap_or(start + Duration::minutes(DEFAULT_BUSY_MINUTES));
+                let (start, end) = (start.max(self.start), end.min(self.end));
+                (start < end).then_some((start, end, event))
+            })
+            .collect();
+        spans.sort_by_key(|(start, end, _)| (*start, *end));
+
+        let mut blocks: Vec<BusyBlock> = Vec::new();
+        for (start, end, event) in spans {
+            match blocks.last_mut() {
+                Some(block) if start < block.end => {
+                    block.end = block.end.max(end);
+                    block.event_ids.push(event.id);
+                    block.titles.push(event.event);
+                }
+                _ => blocks.push(BusyBlock {
+                    start,
+                    end,
+                    event_ids: vec![event.id],
+                    titles: vec![event.event],
+                }),
+            }
+        }
+        blocks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{at, event, time};
+
+    fn spans(free: &[FreeSlot]) -> Vec<String> {
+        free.iter()
+            .map(|s| format!("{}-{}", s.start.format("%H:%M"), s.end.format("%H:%M")))
+            .collect()
+    }
+
+    #[test]
+    fn test_working_day_periods() {
+        let day = WorkingDay::new(time("09:00"), time("17:00"))
+            .with_break(time("15:00"), time("15:15"))
+            .with_break(time("12:00"), time("13:00"));
+        let periods: Vec<_> = day.periods().iter().map(|p| (p.start, p.end)).collect();
+        assert_eq!(periods, vec![
+            (time("09:00"), time("12:00")),
+            (time("13:00"), time("15:00")),
+            (time("15:15"), time("17:00")),
+        ]);
+
+        // Thursday March 5 2026 is a working day, the Saturday is not
+        let hours = WorkingHours::default();
+        assert_eq!(hours.periods_on("2026-03-05".parse().unwrap()).len(), 2);
+        assert!(hours.periods_on("2026-03-07".parse().unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_free_busy_within_working_hours() {
+        let events = vec![
+            event("Standup", "2026-03-05 09:00", "2026-03-05 09:15"),
+            event("Review", "2026-03-05 14:00", "2026-03-05 15:00"),
+            event("Pairing", "2026-03-05 14:30", "2026-03-05 15:30"),
+            event("Dinner", "2026-03-05 19:00", "2026-03-05 21:00"),
+        ];
+        let query = FreeBusyQuery::day("2026-03-05".parse().unwrap())
+            .working_hours(WorkingHours::default());
+        let result = query.compute(&events, TimeZone::Utc);
+
+        assert_eq!(result.busy.len(), 3);
+        assert_eq!(result.busy[1].titles, vec!["Review", "Pairing"]);
+        assert_eq!(result.busy[1].minutes(), 90);
+        assert_eq!(spans(&result.free), vec!["09:15-12:00", "13:00-14:00", "15:30-17:00"]);
+    }
+
+    #[test]
+    fn test_min_duration_and_ignored_events() {
+        let mut cancelled = event("Cancelled", "2026-03-05 12:00", "2026-03-05 13:00");
+        cancelled.status = EventStatus::Cancelled;
+        let mut all_day = CalendarEvent::new("Offsite".to_string(), "2026-03-05".parse().unwrap());
+        all_day.all_day = true;
+        let events = vec![
+            event("Call", "2026-03-05 12:20", "2026-03-05 12:40"),
+            cancelled,
+            all_day,
+        ];
+
+        let query = FreeBusyQuery::new(at("2026-03-05 12:00"), at("2026-03-05 13:00")).min_minutes(20);
+        let result = query.compute(&events, TimeZone::Utc);
+        assert_eq!(result.busy.len(), 1);
+        assert_eq!(spans(&result.free), vec!["12:00-12:20", "12:40-13:00"]);
+
+        let result = query.clone().min_minutes(30).compute(&events, TimeZone::Utc);
+        assert!(result.free.is_empty());
+
+        let result = query.min_minutes(10).buffer_minutes(5).compute(&events, TimeZone::Utc);
+        assert_eq!(spans(&result.free), vec!["12:00-12:15", "12:45-13:00"]);
+    }
+}
@@ -0,0 +1,307 @@
+//! Conflicts between an event and what is already scheduled.
+//!
+//! Recurring events are checked occurrence by occurrence, and the events
+//! they are checked against are expected to be expanded already.
+
+use std::fmt;
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::availability::DEFAULT_BUSY_MINUTES;
+use crate::models::{CalendarEvent, EventStatus};
+use crate::time::TimeZone;
+use crate::validation::Validator;
+use crate::{AppError, AppResult};
+
+/// How far ahead occurrences of a recurring event are checked unless a
+/// check asks otherwise
+pub const DEFAULT_HORIZON_DAYS: u32 = 90;
+
+/// Why two events conflict
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConflictKind {
+    /// The events share some time
+    Overlap,
+    /// The events do not overlap but leave less than the buffer between them
+    Buffer,
+    /// One of the events takes the whole day
+    AllDay,
+}
+
+impl ConflictKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ConflictKind::Overlap => "overlap",
+            ConflictKind::Buffer => "buffer",
+            ConflictKind::AllDay => "allDay",
+        }
+    }
+}
+
+/// An event that gets in the way of one occurrence of the checked event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Conflict {
+    pub kind: ConflictKind,
+    /// The other event; occurrences of a series have their own ids
+    pub event_id: Uuid,
+    /// Series the other event is an occurrence of, if any
+    pub series_id: Option<Uuid>,
+    pub title: String,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    /// When the occurrence of the checked event that conflicts starts
+    pub occurrence_start: NaiveDateTime,
+    /// Time shared by the two events
+    pub overlap_minutes: i64,
+    /// Time left between the two events, for buffer conflicts
+    pub gap_minutes: i64,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let span = if self.start.date() == self.end.date() {
+            format!("{} {}–{}", self.start.format("%a %Y-%m-%d"), self.start.format("%H:%M"), self.end.format("%H:%M"))
+        } else {
+            format!("{} – {}", self.start.format("%a %Y-%m-%d %H:%M"), self.end.format("%a %Y-%m-%d %H:%M"))
+        };
+        match self.kind {
+            ConflictKind::Overlap => {
+                write!(f, "Overlaps \"{}\" ({}) by {} min", self.title, span, self.overlap_minutes)
+            }
+            ConflictKind::Buffer => {
+                let side = if self.end <= self.occurrence_start { "after" } else { "before" };
+                write!(f, "Only {} min {} \"{}\" ({})", self.gap_minutes, side, self.title, span)
+            }
+            ConflictKind::AllDay => {
+                write!(f, "Same day as all-day \"{}\" ({})", self.title, self.start.format("%a %Y-%m-%d"))
+            }
+        }
+    }
+}
+
+/// What counts as a conflict: overlapping time, and optionally less than
+/// `buffer_minutes` between events (e.g. for travel) or sharing a day with
+/// an all-day event. Cancelled and untimed events never conflict; a timed
+/// event without an end takes [`DEFAULT_BUSY_MINUTES`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConflictCheck {
+    #[serde(default)]
+    pub buffer_minutes: u32,
+    #[serde(default)]
+    pub include_all_day: bool,
+    /// Days after its start that occurrences of a recurring event are checked
+    #[serde(default = "default_horizon_days")]
+    pub horizon_days: u32,
+}
+
+fn default_horizon_days() -> u32 {
+    DEFAULT_HORIZON_DAYS
+}
+
+impl Default for ConflictCheck {
+    fn default() -> Self {
+        Self {
+            buffer_minutes: 0,
+            include_all_day: false,
+            horizon_days: DEFAULT_HORIZON_DAYS,
+        }
+    }
+}
+
+impl ConflictCheck {
+    pub fn buffer_minutes(mut self, minutes: u32) -> Self {
+        self.buffer_minutes = minutes;
+        self
+    }
+
+    pub fn include_all_day(mut self, include: bool) -> Self {
+        self.include_all_day = include;
+        self
+    }
+
+    pub fn horizon_days(mut self, days: u32) -> Self {
+        self.horizon_days = days;
+        self
+    }
+
+    /// Check the horizon is within
+    /// [`MAX_HORIZON_DAYS`](crate::validation::MAX_HORIZON_DAYS), collecting
+    /// the problem into [`AppError::InvalidFields`]
+    pub fn validate(&self) -> AppResult<()> {
+        let errors = Validator::validate_conflict_check(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::InvalidFields(errors))
+        }
+    }
+
+    /// The occurrences of `event` to check: the event itself, or the
+    /// occurrences of its series within the horizon
+    pub fn occurrences(&self, event: &CalendarEvent) -> Vec<CalendarEvent> {
+        match &event.recurring {
+            Some(recurring) if event.series_id.is_none() => {
+                let last = event.date
+                    .checked_add_signed(Duration::days(self.horizon_days as i64))
+                    .unwrap_or(NaiveDate::MAX);
+                recurring
+                    .occurrences_between(event.date, event.date, last)
+                    .map(|date| event.instance(date))
+                    .collect()
+            }
+            _ => vec![event.clone()],
+        }
+    }
+
+    /// First and last dates the occurrences of `event` touch, for loading
+    /// the events to check against
+    pub fn dates(&self, event: &CalendarEvent) -> (NaiveDate, NaiveDate) {
+        let last = self.occurrences(event)
+            .iter()
+            .map(CalendarEvent::last_date)
+            .max()
+            .unwrap_or(event.last_date());
+        (event.date, last)
+    }
+
+    /// Conflicts of `event` with `others` seen from `zone`, in order of the
+    /// occurrence they affect and then of the other event. Other
+    /// occurrences of the same series are not conflicts.
+    pub fn find(&self, event: &CalendarEvent, others: &[CalendarEvent], zone: TimeZone) -> Vec<Conflict> {
+        if event.status == EventStatus::Cancelled {
+            return Vec::new();
+        }
+        let series = event.series_id.unwrap_or(event.id);
+        let others: Vec<_> = others
+            .iter()
+            .filter(|other| other.id != event.id && other.series_id != Some(series) && other.id != series)
+            .filter_map(|other| Some((self.span(other, zone)?, other)))
+            .collect();
+
+        let mut conflicts = Vec::new();
+        for occurrence in self.occurrences(event) {
+            let Some(span) = self.span(&occurrence, zone) else { continue };
+            for (other_span, other) in &others {
+                if let Some(conflict) = self.compare(span, *other_span, other) {
+                    conflicts.push(conflict);
+                }
+            }
+        }
+        conflicts.sort_by_key(|c| (c.occurrence_start, c.start));
+        conflicts
+    }
+
+    /// Wall-clock span of an event that can conflict, and whether it is all-day
+    fn span(&self, event: &CalendarEvent, zone: TimeZone) -> Option<(NaiveDateTime, NaiveDateTime, bool)> {
+        if event.status == EventStatus::Cancelled || (!event.all_day && event.time.is_none()) {
+            return None;
+        }
+        let event = event.in_zone(zone, zone);
+        let start = event.start_naive();
+        let end = event.end_naive().unwrap_or(start + Duration::minutes(DEFAULT_BUSY_MINUTES));
+        Some((start, end, event.all_day))
+    }
+
+    fn compare(
+        &self,
+        (start, end, all_day): (NaiveDateTime, NaiveDateTime, bool),
+        (other_start, other_end, other_all_day): (NaiveDateTime, NaiveDateTime, bool),
+        other: &CalendarEvent,
+    ) -> Option<Conflict> {
+        let overlap = (end.min(other_end) - start.max(other_start)).num_minutes();
+        let gap = (start.max(other_start) - end.min(other_end)).num_minutes();
+        let kind = if all_day || other_all_day {
+            if !self.include_all_day || overlap <= 0 {
+                return None;
+            }
+            ConflictKind::AllDay
+        } else if overlap > 0 {
+            ConflictKind::Overlap
+        } else if gap < self.buffer_minutes as i64 {
+            ConflictKind::Buffer
+        } else {
+            return None;
+        };
+
+        Some(Conflict {
+            kind,
+            event_id: other.id,
+            series_id: other.series_id,
+            title: other.event.clone(),
+            start: other_start,
+            end: other_end,
+            occurrence_start: start,
+            overlap_minutes: overlap.max(0),
+            gap_minutes: gap.max(0),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RecurrenceConfig, RecurrenceFrequency};
+    use crate::testing::{at, event};
+
+    #[test]
+    fn test_overlap_and_buffer() {
+        let lunch = event("Lunch", "2026-03-02 12:00", "2026-03-02 13:00");
+        let others = vec![lunch.clone()];
+
+        let call = event("Call", "2026-03-02 12:30", "2026-03-02 13:30");
+        let conflicts = ConflictCheck::default().find(&call, &others, TimeZone::Utc);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].kind, ConflictKind::Overlap);
+        assert_eq!(conflicts[0].event_id, lunch.id);
+        assert_eq!(conflicts[0].overlap_minutes, 30);
+        assert_eq!(conflicts[0].to_string(), "Overlaps \"Lunch\" (Mon 2026-03-02 12:00–13:00) by 30 min");
+
+        // Back to back is fine, unless travel time is needed
+        let call = event("Call", "2026-03-02 13:05", "2026-03-02 14:00");
+        assert!(ConflictCheck::default().find(&call, &others, TimeZone::Utc).is_empty());
+        let conflicts = ConflictCheck::default().buffer_minutes(15).find(&call, &others, TimeZone::Utc);
+        assert_eq!(conflicts[0].kind, ConflictKind::Buffer);
+        assert_eq!(conflicts[0].gap_minutes, 5);
+        assert_eq!(conflicts[0].to_string(), "Only 5 min after \"Lunch\" (Mon 2026-03-02 12:00–13:00)");
+    }
+
+    #[test]
+    fn test_recurring_and_all_day() {
+        let mut standup = event("Standup", "2026-03-02 09:00", "2026-03-02 09:15");
+        standup.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Daily,
+            occurrences: Some(5),
+            ..Default::default()
+        });
+        let review = event("Review", "2026-03-04 09:00", "2026-03-04 10:00");
+        let offsite = CalendarEvent::builder("Offsite", at("2026-03-05 00:00").date())
+            .all_day()
+            .build()
+            .unwrap();
+        let others = vec![review.clone(), offsite];
+
+        // Only Wednesday's standup runs into the review
+        let conflicts = ConflictCheck::default().find(&standup, &others, TimeZone::Utc);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].occurrence_start, at("2026-03-04 09:00"));
+        assert_eq!(conflicts[0].overlap_minutes, 15);
+
+        let conflicts = ConflictCheck::default().include_all_day(true).find(&standup, &others, TimeZone::Utc);
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[1].kind, ConflictKind::AllDay);
+        assert_eq!(conflicts[1].to_string(), "Same day as all-day \"Offsite\" (Thu 2026-03-05)");
+
+        // Occurrences of the same series do not conflict with each other
+        let expanded: Vec<_> = ConflictCheck::default().occurrences(&standup);
+        assert_eq!(expanded.len(), 5);
+        assert!(ConflictCheck::default().find(&expanded[1], &expanded, TimeZone::Utc).is_empty());
+
+        // A horizon past the last representable date is rejected, not a panic
+        let unbounded = ConflictCheck::default().horizon_days(u32::MAX);
+        assert_eq!(unbounded.dates(&standup), (at("2026-03-02 00:00").date(), at("2026-03-06 00:00").date()));
+        assert!(matches!(unbounded.validate(), Err(AppError::InvalidFields(_))));
+    }
+}
@@ -1,4 +1,5 @@
 use thiserror::Error;
+use crate::validation::FieldError;
 
 #[derive(Debug, Error)]
 pub enum AppError {
@@ -8,6 +9,10 @@ pub enum AppError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    /// Every field-level problem found on an event
+    #[error("Validation error: {}", join_field_errors(.0))]
+    InvalidFields(Vec<FieldError>),
+
     #[error("AI extraction error: {0}")]
     Ai(String),
 
@@ -23,6 +28,20 @@ pub enum AppError {
 
 pub type AppResult<T> = std::result::Result<T, AppError>;
 
+fn join_field_errors(errors: &[FieldError]) -> String {
+    errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+}
+
+impl AppError {
+    /// Field-level problems carried by this error, if any
+    pub fn field_errors(&self) -> &[FieldError] {
+        match self {
+            AppError::InvalidFields(errors) => errors,
+            _ => &[],
+        }
+    }
+}
+
 impl From<std::io::Error> for AppError {
     fn from(e: std::io::Error) -> Self {
         AppError::Io(e.to_string())
@@ -0,0 +1,429 @@
+//! Public holidays and business days.
+//!
+//! A [`HolidayCalendar`] is a list of yearly rules, either one of the
+//! built-in country calendars or loaded from a user's JSON file. Holidays
+//! are never stored; they are shown as read-only all-day overlay events and
+//! skipped when counting business days.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::models::CalendarEvent;
+use crate::validation::Validator;
+use crate::{AppError, AppResult};
+
+/// Country codes with a built-in calendar
+pub const COUNTRIES: [&str; 4] = ["DE", "FR", "GB", "US"];
+
+/// Namespace for the ids of overlay events, so a holiday keeps its id
+/// between loads
+const OVERLAY_NAMESPACE: Uuid = Uuid::from_u128(0x5f1c_2b7e_8d44_4c1a_9e0f_6a3b_d2c8_7e15);
+
+/// What happens when a fixed-date holiday falls on a weekend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Observance {
+    /// Observed on the day itself
+    #[default]
+    Actual,
+    /// Saturday moves to Friday, Sunday to Monday
+    NearestWeekday,
+    /// Moves to the following Monday
+    FollowingMonday,
+}
+
+/// When a holiday falls in a given year
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "camelCase")]
+pub enum HolidayRule {
+    /// The same date every year, e.g. December 25
+    #[serde(rename_all = "camelCase")]
+    Fixed {
+        month: u32,
+        day: u32,
+        #[serde(default)]
+        observed: Observance,
+    },
+    /// The `n`th weekday of a month, 1 to 5, or -1 for the last one
+    #[serde(rename_all = "camelCase")]
+    NthWeekday { month: u32, weekday: Weekday, n: i8 },
+    /// Days after Easter Sunday (Western); negative for days before it
+    #[serde(rename_all = "camelCase")]
+    Easter { offset: i64 },
+}
+
+/// A named yearly holiday
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Holiday {
+    pub name: String,
+    #[serde(flatten)]
+    pub rule: HolidayRule,
+}
+
+impl Holiday {
+    pub fn new(name: impl Into<String>, rule: HolidayRule) -> Self {
+        Self { name: name.into(), rule }
+    }
+
+    /// The holiday's own date in `year`, before any weekend observance
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        match self.rule {
+            HolidayRule::Fixed { month, day, .. } => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayRule::NthWeekday { month, weekday, n } => crate::time::nth_weekday(year, month, weekday, n),
+            HolidayRule::Easter { offset } => easter_sunday(year)?.checked_add_signed(Duration::days(offset)),
+        }
+    }
+
+    fn observance(&self) -> Observance {
+        match self.rule {
+            HolidayRule::Fixed { observed, .. } => observed,
+            _ => Observance::Actual,
+        }
+    }
+}
+
+/// A holiday on a particular date
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolidayDate {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+/// Public holidays and the days of the week nobody works
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolidayCalendar {
+    /// Country code or a name for a user's calendar
+    pub name: String,
+    #[serde(default)]
+    pub holidays: Vec<Holiday>,
+    #[serde(default = "default_weekend")]
+    pub weekend: Vec<Weekday>,
+}
+
+fn default_weekend() -> Vec<Weekday> {
+    vec![Weekday::Sat, Weekday::Sun]
+}
+
+/// No holidays, weekends off
+impl Default for HolidayCalendar {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            holidays: Vec::new(),
+            weekend: default_weekend(),
+        }
+    }
+}
+
+impl HolidayCalendar {
+    /// Built-in calendar of national holidays for a country code in
+    /// [`COUNTRIES`] (`UK` is accepted for `GB`)
+    pub fn country(code: &str) -> AppResult<Self> {
+        use HolidayRule::{Easter, NthWeekday};
+        use Observance::{Actual, FollowingMonday, NearestWeekday};
+        let fixed = |month, day, observed| HolidayRule::Fixed { month, day, observed };
+        let nth = |month, weekday, n| NthWeekday { month, weekday, n };
+
+        let code = code.trim().to_uppercase();
+        let holidays: Vec<(&str, HolidayRule)> = match code.as_str() {
+            "US" => vec![
+                ("New Year's Day", fixed(1, 1, NearestWeekday)),
+                ("Martin Luther King Jr. Day", nth(1, Weekday::Mon, 3)),
+                ("Washington's Birthday", nth(2, Weekday::Mon, 3)),
+                ("Memorial Day", nth(5, Weekday::Mon, -1)),
+                ("Juneteenth", fixed(6, 19, NearestWeekday)),
+                ("Independence Day", fixed(7, 4, NearestWeekday)),
+                ("Labor Day", nth(9, Weekday::Mon, 1)),
+                ("Columbus Day", nth(10, Weekday::Mon, 2)),
+                ("Veterans Day", fixed(11, 11, NearestWeekday)),
+                ("Thanksgiving", nth(11, Weekday::Thu, 4)),
+                ("Christmas Day", fixed(12, 25, NearestWeekday)),
+            ],
+            "GB" | "UK" => vec![
+                ("New Year's Day", fixed(1, 1, FollowingMonday)),
+                ("Good Friday", Easter { offset: -2 }),
+                ("Easter Monday", Easter { offset: 1 }),
+                ("Early May bank holiday", nth(5, Weekday::Mon, 1)),
+                ("Spring bank holiday", nth(5, Weekday::Mon, -1)),
+                ("Summer bank holiday", nth(8, Weekday::Mon, -1)),
+                ("Christmas Day", fixed(12, 25, FollowingMonday)),
+                ("Boxing Day", fixed(12, 26, FollowingMonday)),
+            ],
+            "DE" => vec![
+                ("Neujahr", fixed(1, 1, Actual)),
+                ("Karfreitag", Easter { offset: -2 }),
+                ("Ostermontag", Easter { offset: 1 }),
+                ("Tag der Arbeit", fixed(5, 1, Actual)),
+                ("Christi Himmelfahrt", Easter { offset: 39 }),
+                ("Pfingstmontag", Easter { offset: 50 }),
+                ("Tag der Deutschen Einheit", fixed(10, 3, Actual)),
+                ("1. Weihnachtstag", fixed(12, 25, Actual)),
+                ("2. Weihnachtstag", fixed(12, 26, Actual)),
+            ],
+            "FR" => vec![
+                ("Jour de l'an", fixed(1, 1, Actual)),
+                ("Lundi de Pâques", Easter { offset: 1 }),
+                ("Fête du Travail", fixed(5, 1, Actual)),
+                ("Victoire 1945", fixed(5, 8, Actual)),
+                ("Ascension", Easter { offset: 39 }),
+                ("Lundi de Pentecôte", Easter { offset: 50 }),
+                ("Fête nationale", fixed(7, 14, Actual)),
+                ("Assomption", fixed(8, 15, Actual)),
+                ("Toussaint", fixed(11, 1, Actual)),
+                ("Armistice", fixed(11, 11, Actual)),
+                ("Noël", fixed(12, 25, Actual)),
+            ],
+            _ => return Err(AppError::Validation(format!("No holiday calendar for {}", code))),
+        };
+
+        Ok(Self {
+            name: if code == "UK" { "GB".to_string() } else { code },
+            holidays: holidays.into_iter().map(|(name, rule)| Holiday::new(name, rule)).collect(),
+            weekend: default_weekend(),
+        })
+    }
+
+    /// A user's calendar in the JSON form of this type
+    pub fn from_json(json: &str) -> AppResult<Self> {
+        let calendar: Self = serde_json::from_str(json)
+            .map_err(|e| AppError::Validation(format!("Invalid holiday calendar: {}", e)))?;
+        calendar.validate()?;
+        Ok(calendar)
+    }
+
+    /// Read a user's calendar from a JSON file
+    pub fn load(path: &Path) -> AppResult<Self> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| AppError::Io(format!("Failed to read {}: {}", path.display(), e)))?;
+        Self::from_json(&json)
+    }
+
+    pub fn validate(&self) -> AppResult<()> {
+        let errors = Validator::validate_holiday_calendar(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::InvalidFields(errors))
+        }
+    }
+
+    pub fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend.contains(&date.weekday())
+    }
+
+    /// Holidays observed between `start` and `end` (inclusive), in date
+    /// order. A weekend holiday moved by its observance skips days already
+    /// taken by another holiday, so Christmas and Boxing Day on a weekend
+    /// become Monday and Tuesday.
+    pub fn holidays_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<HolidayDate> {
+        // Observance can move a holiday into the neighbouring year
+        (start.year() - 1..=end.year() + 1)
+            .flat_map(|year| self.observed_in(year))
+            .filter(|(date, _)| *date >= start && *date <= end)
+            .map(|(date, name)| HolidayDate { date, name })
+            .collect()
+    }
+
+    /// Name of the holiday observed on `date`
+    pub fn holiday_on(&self, date: NaiveDate) -> Option<String> {
+        self.holidays_between(date, date).into_iter().next().map(|holiday| holiday.name)
+    }
+
+    /// Next date on or after `from` that the holiday called `name` is
+    /// observed (case-insensitive)
+    pub fn next_named(&self, name: &str, from: NaiveDate) -> Option<NaiveDate> {
+        (from.year()..=from.year() + 1)
+            .flat_map(|year| self.observed_in(year))
+            .find(|(date, holiday)| *date >= from && holiday.eq_ignore_ascii_case(name))
+            .map(|(date, _)| date)
+    }
+
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date) && self.holiday_on(date).is_none()
+    }
+
+    /// The date `days` business days after `date` (before it when
+    /// negative); `None` if the calendar leaves no business days
+    pub fn add_business_days(&self, date: NaiveDate, days: i64) -> Option<NaiveDate> {
+        if self.weekend.len() >= 7 {
+            return None;
+        }
+        let step = Duration::days(days.signum());
+        let mut date = date;
+        for _ in 0..days.abs() {
+            date = date.checked_add_signed(step)?;
+            while !self.is_business_day(date) {
+                date = date.checked_add_signed(step)?;
+            }
+        }
+        Some(date)
+    }
+
+    /// Business days from `start` up to but not including `end`
+    pub fn business_days_between(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        let holidays: Vec<_> = self.holidays_between(start, end).into_iter().map(|h| h.date).collect();
+        start.iter_days()
+            .take_while(|date| *date < end)
+            .filter(|date| !self.is_weekend(*date) && !holidays.contains(date))
+            .count() as i64
+    }
+
+    /// Holidays between `start` and `end` as read-only all-day events, to
+    /// show over a user's own events
+    pub fn overlay_events(&self, start: NaiveDate, end: NaiveDate) -> Vec<CalendarEvent> {
+        self.holidays_between(start, end)
+            .into_iter()
+            .filter_map(|holiday| {
+                let key = format!("{}:{}:{}", self.name, holiday.date, holiday.name);
+                let mut event = CalendarEvent::builder(holiday.name, holiday.date)
+                    .all_day()
+                    .tag("holiday")
+                    .metadata_entry("source", "holidays")
+                    .metadata_entry("calendar", self.name.clone())
+                    .metadata_entry("readOnly", true)
+                    .build()
+                    .ok()?;
+                event.id = Uuid::new_v5(&OVERLAY_NAMESPACE, key.as_bytes());
+                Some(event)
+            })
+            .collect()
+    }
+
+    /// Observed dates of every holiday in `year`, in date order. Holidays
+    /// on their own date are placed first so moved ones can avoid them.
+    fn observed_in(&self, year: i32) -> Vec<(NaiveDate, String)> {
+        let mut observed: BTreeMap<NaiveDate, String> = BTreeMap::new();
+        let mut moved = Vec::new();
+        for holiday in &self.holidays {
+            let Some(date) = holiday.date_in(year) else { continue };
+            match holiday.observance() {
+                Observance::NearestWeekday | Observance::FollowingMonday if self.is_weekend(date) => {
+                    moved.push((date, holiday));
+                }
+                _ => {
+                    observed.entry(date).or_insert_with(|| holiday.name.clone());
+                }
+            }
+        }
+
+        for (date, holiday) in moved {
+            let mut date = match (holiday.observance(), date.weekday()) {
+                (Observance::NearestWeekday, Weekday::Sat) => date - Duration::days(1),
+                (Observance::NearestWeekday, _) => date + Duration::days(1),
+                _ => date + Duration::days(7 - date.weekday().num_days_from_monday() as i64),
+            };
+            while self.is_weekend(date) || observed.contains_key(&date) {
+                date += Duration::days(1);
+            }
+            observed.insert(date, holiday.name.clone());
+        }
+
+        observed.into_iter().collect()
+    }
+}
+
+/// Easter Sunday in the Gregorian calendar (anonymous Gregorian algorithm)
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::date;
+
+    fn dates(calendar: &HolidayCalendar, year: i32) -> Vec<String> {
+        let start = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(year, 12, 31).unwrap();
+        calendar.holidays_between(start, end)
+            .iter()
+            .map(|h| format!("{} {}", h.date.format("%m-%d"), h.name))
+            .collect()
+    }
+
+    #[test]
+    fn test_rules() {
+        assert_eq!(easter_sunday(2026), Some(date("2026-04-05")));
+        assert_eq!(easter_sunday(2027), Some(date("2027-03-28")));
+
+        let us = HolidayCalendar::country("us").unwrap();
+        assert_eq!(us.next_named("thanksgiving", date("2026-01-01")), Some(date("2026-11-26")));
+        assert_eq!(us.next_named("memorial day", date("2026-01-01")), Some(date("2026-05-25")));
+        // July 4 2026 is a Saturday, observed on the Friday
+        assert_eq!(us.holiday_on(date("2026-07-03")).as_deref(), Some("Independence Day"));
+        // ... and New Year's Day 2028 on the last day of 2027
+        assert_eq!(us.holiday_on(date("2027-12-31")).as_deref(), Some("New Year's Day"));
+
+        let de = HolidayCalendar::country("DE").unwrap();
+        assert_eq!(de.holiday_on(date("2026-05-14")).as_deref(), Some("Christi Himmelfahrt"));
+        assert!(HolidayCalendar::country("XX").is_err());
+    }
+
+    #[test]
+    fn test_substitute_days_do_not_collide() {
+        let gb = HolidayCalendar::country("UK").unwrap();
+        let christmas: Vec<_> = dates(&gb, 2021).into_iter().filter(|d| d.starts_with("12-")).collect();
+        assert_eq!(christmas, vec!["12-27 Christmas Day", "12-28 Boxing Day"]);
+        let christmas: Vec<_> = dates(&gb, 2022).into_iter().filter(|d| d.starts_with("12-")).collect();
+        assert_eq!(christmas, vec!["12-26 Boxing Day", "12-27 Christmas Day"]);
+    }
+
+    #[test]
+    fn test_business_days() {
+        let us = HolidayCalendar::country("US").unwrap();
+        // Wednesday before Thanksgiving: Thursday and the weekend are skipped
+        assert_eq!(us.add_business_days(date("2026-11-25"), 1), Some(date("2026-11-27")));
+        assert_eq!(us.add_business_days(date("2026-11-30"), -2), Some(date("2026-11-25")));
+        assert_eq!(us.business_days_between(date("2026-11-23"), date("2026-11-30")), 4);
+        assert!(!us.is_business_day(date("2026-11-26")));
+
+        let every_day = vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun];
+        let never = HolidayCalendar { weekend: every_day, ..Default::default() };
+        assert_eq!(never.add_business_days(date("2026-11-25"), 1), None);
+    }
+
+    #[test]
+    fn test_user_file_and_overlay() {
+        let calendar = HolidayCalendar::from_json(r#"{
+            "name": "Office",
+            "holidays": [
+                {"name": "Founders Day", "rule": "fixed", "month": 3, "day": 14},
+                {"name": "Summer Friday", "rule": "nthWeekday", "month": 7, "weekday": "Fri", "n": -1},
+                {"name": "Spring Break", "rule": "easter", "offset": 8}
+            ],
+            "weekend": ["Fri", "Sat"]
+        }"#).unwrap();
+        assert_eq!(dates(&calendar, 2026), vec!["03-14 Founders Day", "04-13 Spring Break", "07-31 Summer Friday"]);
+        assert!(calendar.is_weekend(date("2026-03-13")));
+
+        let events = calendar.overlay_events(date("2026-03-01"), date("2026-03-31"));
+        assert_eq!(events.len(), 1);
+        assert!(events[0].all_day);
+        assert_eq!(events[0].metadata["readOnly"], true);
+        assert_eq!(events[0].id, calendar.overlay_events(date("2026-03-14"), date("2026-03-14"))[0].id);
+
+        let error = HolidayCalendar::from_json(r#"{"name": "Bad", "holidays": [{"name": "", "rule": "fixed", "month": 2, "day": 30}]}"#)
+            .unwrap_err();
+        let fields: Vec<_> = error.field_errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["holidays[0].name", "holidays[0].day"]);
+    }
+}
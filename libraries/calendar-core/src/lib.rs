@@ -2,7 +2,28 @@ pub mod models;
 pub mod time;
 pub mod errors;
 pub mod validation;
+pub mod patch;
+pub mod availability;
+pub mod scheduling;
+pub mod conflicts;
+pub mod holidays;
+pub mod search;
+pub mod query;
+pub mod trash;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
-pub use models::{CalendarEvent, Priority, Category, EventStatus, Visibility};
+pub use models::{CalendarEvent, CalendarEventBuilder, Priority, Category, EventStatus, Visibility};
+pub use models::{RecurrenceConfig, RecurrenceFrequency, OrdinalWeekday, OccurrenceId, EditScope};
+pub use models::{ReminderConfig, ReminderChannel, Location, LocationType, Coordinates};
+pub use models::{Attendee, AttendeeRole, ParticipationStatus, Task};
+pub use models::{CategoryDefinition, CategoryRegistry};
+pub use availability::{FreeBusy, FreeBusyQuery, WorkingDay, WorkingHours};
+pub use scheduling::{SlotProposal, SlotRequest};
+pub use conflicts::{Conflict, ConflictCheck, ConflictKind};
+pub use holidays::{Holiday, HolidayCalendar, HolidayRule};
+pub use search::{parse_query, QueryError, SearchHit, SnippetPart};
+pub use query::{EventPage, EventQuery, EventSort};
+pub use trash::{RetentionPolicy, TrashedEvent};
 pub use errors::{AppError, AppResult};
-pub use validation::Validator;
+pub use validation::{Validator, FieldError};
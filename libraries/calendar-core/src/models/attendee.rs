@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use crate::AppError;
+
+/// Someone invited to an event (RFC 5545 `ATTENDEE`, or `ORGANIZER` for
+/// the organizer role)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attendee {
+    #[serde(default)]
+    pub name: Option<String>,
+    pub email: String,
+    #[serde(default)]
+    pub role: AttendeeRole,
+    #[serde(default)]
+    pub status: ParticipationStatus,
+}
+
+impl Attendee {
+    pub fn new(email: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            email: email.into(),
+            role: AttendeeRole::default(),
+            status: ParticipationStatus::default(),
+        }
+    }
+
+    pub fn named(name: impl Into<String>, email: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::new(email)
+        }
+    }
+
+    pub fn with_role(mut self, role: AttendeeRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    pub fn with_status(mut self, status: ParticipationStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Whether `email` refers to this attendee (case-insensitive)
+    pub fn has_email(&self, email: &str) -> bool {
+        self.email.trim().eq_ignore_ascii_case(email.trim())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AttendeeRole {
+    /// Runs the meeting; exported as `ORGANIZER`
+    Organizer,
+    /// `ROLE=CHAIR`
+    Chair,
+    /// `ROLE=REQ-PARTICIPANT`
+    #[default]
+    Required,
+    /// `ROLE=OPT-PARTICIPANT`
+    Optional,
+    /// `ROLE=NON-PARTICIPANT`, e.g. copied for information
+    NonParticipant,
+}
+
+impl AttendeeRole {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AttendeeRole::Organizer => "organizer",
+            AttendeeRole::Chair => "chair",
+            AttendeeRole::Required => "required",
+            AttendeeRole::Optional => "optional",
+            AttendeeRole::NonParticipant => "nonParticipant",
+        }
+    }
+
+    /// RFC 5545 `ROLE` parameter value
+    pub fn ical_role(&self) -> &'static str {
+        match self {
+            AttendeeRole::Organizer | AttendeeRole::Chair => "CHAIR",
+            AttendeeRole::Required => "REQ-PARTICIPANT",
+            AttendeeRole::Optional => "OPT-PARTICIPANT",
+            AttendeeRole::NonParticipant => "NON-PARTICIPANT",
+        }
+    }
+}
+
+impl std::str::FromStr for AttendeeRole {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "organizer" => Ok(AttendeeRole::Organizer),
+            "chair" => Ok(AttendeeRole::Chair),
+            "required" | "req-participant" => Ok(AttendeeRole::Required),
+            "optional" | "opt-participant" => Ok(AttendeeRole::Optional),
+            "nonparticipant" | "non-participant" => Ok(AttendeeRole::NonParticipant),
+            other => Err(AppError::Validation(format!("Unknown attendee role: {}", other))),
+        }
+    }
+}
+
+/// RSVP state (RFC 5545 `PARTSTAT`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ParticipationStatus {
+    #[default]
+    NeedsAction,
+    Accepted,
+    Declined,
+    Tentative,
+    Delegated,
+}
+
+impl ParticipationStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ParticipationStatus::NeedsAction => "needsAction",
+            ParticipationStatus::Accepted => "accepted",
+            ParticipationStatus::Declined => "declined",
+            ParticipationStatus::Tentative => "tentative",
+            ParticipationStatus::Delegated => "delegated",
+        }
+    }
+
+    /// RFC 5545 `PARTSTAT` parameter value
+    pub fn ical_partstat(&self) -> &'static str {
+        match self {
+            ParticipationStatus::NeedsAction => "NEEDS-ACTION",
+            ParticipationStatus::Accepted => "ACCEPTED",
+            ParticipationStatus::Declined => "DECLINED",
+            ParticipationStatus::Tentative => "TENTATIVE",
+            ParticipationStatus::Delegated => "DELEGATED",
+        }
+    }
+}
+
+impl std::str::FromStr for ParticipationStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "needsaction" | "needs-action" => Ok(ParticipationStatus::NeedsAction),
+            "accepted" => Ok(ParticipationStatus::Accepted),
+            "declined" => Ok(ParticipationStatus::Declined),
+            "tentative" => Ok(ParticipationStatus::Tentative),
+            "delegated" => Ok(ParticipationStatus::Delegated),
+            other => Err(AppError::Validation(format!("Unknown participation status: {}", other))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wire_format_and_defaults() {
+        let attendee: Attendee = serde_json::from_value(serde_json::json!({
+            "email": "ana@example.com",
+            "status": "accepted"
+        }))
+        .unwrap();
+
+        assert_eq!(attendee.role, AttendeeRole::Required);
+        assert_eq!(attendee.status, ParticipationStatus::Accepted);
+        assert!(attendee.has_email(" ANA@example.com"));
+
+        let json = serde_json::to_value(Attendee::new("bo@example.com").with_role(AttendeeRole::NonParticipant)).unwrap();
+        assert_eq!(json["role"], "nonParticipant");
+        assert_eq!(json["status"], "needsAction");
+    }
+
+    #[test]
+    fn test_parse_ical_names() {
+        assert_eq!("NEEDS-ACTION".parse::<ParticipationStatus>().unwrap(), ParticipationStatus::NeedsAction);
+        assert_eq!("OPT-PARTICIPANT".parse::<AttendeeRole>().unwrap(), AttendeeRole::Optional);
+        assert!("maybe".parse::<ParticipationStatus>().is_err());
+    }
+}
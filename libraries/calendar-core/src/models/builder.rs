@@ -0,0 +1,223 @@
+use chrono::{NaiveDate, NaiveTime};
+use crate::time::TimeZone;
+use crate::AppResult;
+use super::{
+    Attendee, CalendarEvent, Category, EventStatus, Location, Priority, RecurrenceConfig,
+    ReminderConfig, Visibility,
+};
+
+/// Fluent constructor for [`CalendarEvent`]; `build()` validates the result.
+///
+/// ```
+/// use calendar_core::{CalendarEvent, Priority};
+/// use chrono::{NaiveDate, NaiveTime};
+///
+/// let event = CalendarEvent::builder("Standup", NaiveDate::from_ymd_opt(2026, 3, 2).unwrap())
+///     .time_range(
+///         NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+///         NaiveTime::from_hms_opt(9, 15, 0).unwrap(),
+///     )
+///     .priority(Priority::High)
+///     .tag("team")
+///     .build()
+///     .unwrap();
+/// assert_eq!(event.tags, vec!["team"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CalendarEventBuilder {
+    event: CalendarEvent,
+}
+
+impl CalendarEventBuilder {
+    pub fn new(title: impl Into<String>, date: NaiveDate) -> Self {
+        Self {
+            event: CalendarEvent::new(title.into(), date),
+        }
+    }
+
+    /// Start time; the event stays open-ended until an end time is set
+    pub fn time(mut self, time: NaiveTime) -> Self {
+        self.event.time = Some(time);
+        self.event.all_day = false;
+        self
+    }
+
+    pub fn end_time(mut self, end_time: NaiveTime) -> Self {
+        self.event.end_time = Some(end_time);
+        self
+    }
+
+    /// Start and end on the same day; use [`Self::end_date`] for events
+    /// that run past midnight
+    pub fn time_range(self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.time(start).end_time(end)
+    }
+
+    /// Last day of a multi-day event (inclusive)
+    pub fn end_date(mut self, end_date: NaiveDate) -> Self {
+        self.event.end_date = Some(end_date);
+        self
+    }
+
+    /// Cover whole days, dropping any times already set
+    pub fn all_day(mut self) -> Self {
+        self.event.all_day = true;
+        self.event.time = None;
+        self.event.end_time = None;
+        self
+    }
+
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.event.notes = Some(notes.into());
+        self
+    }
+
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.event.priority = priority;
+        self
+    }
+
+    pub fn category(mut self, category: Category) -> Self {
+        self.event.category = category;
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.event.color = Some(color.into());
+        self
+    }
+
+    pub fn status(mut self, status: EventStatus) -> Self {
+        self.event.status = status;
+        self
+    }
+
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.event.visibility = visibility;
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.event.tags.push(tag.into());
+        self
+    }
+
+    pub fn tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.event.tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn recurrence(mut self, recurrence: RecurrenceConfig) -> Self {
+        self.event.recurring = Some(recurrence);
+        self
+    }
+
+    /// Add a reminder; call again for more
+    pub fn reminder(mut self, reminder: ReminderConfig) -> Self {
+        self.event.reminders.push(reminder);
+        self
+    }
+
+    pub fn location(mut self, location: Location) -> Self {
+        self.event.location = Some(location);
+        self
+    }
+
+    pub fn attendee(mut self, attendee: Attendee) -> Self {
+        self.event.attendees.push(attendee);
+        self
+    }
+
+    /// Zone the date and times are written in; `Local` leaves it unset so
+    /// the viewer's default applies
+    pub fn timezone(mut self, zone: TimeZone) -> Self {
+        self.event.timezone = zone.tzid().map(str::to_string);
+        self
+    }
+
+    /// Replace the metadata object; non-objects are ignored
+    pub fn metadata(mut self, metadata: serde_json::Value) -> Self {
+        if metadata.is_object() {
+            self.event.metadata = metadata;
+        }
+        self
+    }
+
+    /// Set a single metadata key
+    pub fn metadata_entry(mut self, key: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.event.metadata[key] = value.into();
+        self
+    }
+
+    /// Validate and return the event
+    pub fn build(self) -> AppResult<CalendarEvent> {
+        self.event.validate()?;
+        Ok(self.event)
+    }
+}
+
+/// Start from an existing event, e.g. one received from the GUI, so it goes
+/// through the same validation as newly built ones
+impl From<CalendarEvent> for CalendarEventBuilder {
+    fn from(event: CalendarEvent) -> Self {
+        Self { event }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RecurrenceFrequency;
+    use crate::testing::{date, time};
+
+    #[test]
+    fn test_builds_typed_event() {
+        let event = CalendarEventBuilder::new("Night shift", date("2026-03-02"))
+            .time_range(time("22:00"), time("06:00"))
+            .end_date(date("2026-03-03"))
+            .category(Category::Work)
+            .tags(["ops", "oncall"])
+            .recurrence(RecurrenceConfig {
+                frequency: RecurrenceFrequency::Weekly,
+                ..Default::default()
+            })
+            .timezone(TimeZone::parse("Europe/Berlin").unwrap())
+            .metadata_entry("source", "Test")
+            .build()
+            .unwrap();
+
+        assert_eq!(event.time, Some(time("22:00")));
+        assert_eq!(event.end_date, Some(date("2026-03-03")));
+        assert_eq!(event.category, Category::Work);
+        assert_eq!(event.tags, vec!["ops", "oncall"]);
+        assert_eq!(event.timezone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(event.metadata["source"], "Test");
+    }
+
+    #[test]
+    fn test_build_validates() {
+        let err = CalendarEventBuilder::new("", date("2026-03-02"))
+            .time_range(time("10:00"), time("09:00"))
+            .build()
+            .unwrap_err();
+
+        let fields: Vec<_> = err.field_errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["event", "endTime"]);
+    }
+
+    #[test]
+    fn test_all_day_clears_times() {
+        let event = CalendarEventBuilder::new("Offsite", date("2026-03-02"))
+            .time(time("09:00"))
+            .all_day()
+            .build()
+            .unwrap();
+
+        assert!(event.all_day);
+        assert_eq!(event.time, None);
+    }
+}
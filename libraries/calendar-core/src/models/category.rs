@@ -0,0 +1,309 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::validation::Validator;
+use crate::{AppError, AppResult};
+
+/// What an event or task is about. The built-in variants are always
+/// available; anything else is a user-defined category, addressed by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Category {
+    Work,
+    Personal,
+    Health,
+    Social,
+    Finance,
+    Education,
+    #[default]
+    Other,
+    /// User-defined category; the name is lowercase (see
+    /// [`Validator::validate_category_name`])
+    Custom(String),
+}
+
+impl Category {
+    pub const BUILT_IN: [Category; 7] = [
+        Category::Work,
+        Category::Personal,
+        Category::Health,
+        Category::Social,
+        Category::Finance,
+        Category::Education,
+        Category::Other,
+    ];
+
+    /// Default color; user-defined categories get their color from the
+    /// [`CategoryRegistry`]
+    pub fn color(&self) -> &'static str {
+        match self {
+            Category::Work => "#3B82F6",
+            Category::Personal => "#10B981",
+            Category::Health => "#EF4444",
+            Category::Social => "#8B5CF6",
+            Category::Finance => "#F59E0B",
+            Category::Education => "#6366F1",
+            Category::Other | Category::Custom(_) => "#6B7280",
+        }
+    }
+
+    pub fn display_name(&self) -> &str {
+        match self {
+            Category::Work => "Work",
+            Category::Personal => "Personal",
+            Category::Health => "Health",
+            Category::Social => "Social",
+            Category::Finance => "Finance",
+            Category::Education => "Education",
+            Category::Other => "Other",
+            Category::Custom(name) => name,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Category::Work => "work",
+            Category::Personal => "personal",
+            Category::Health => "health",
+            Category::Social => "social",
+            Category::Finance => "finance",
+            Category::Education => "education",
+            Category::Other => "other",
+            Category::Custom(name) => name,
+        }
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Category::Custom(_))
+    }
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Built-in names map to their variants; any other valid name is a
+/// user-defined category
+impl std::str::FromStr for Category {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase();
+        if let Some(built_in) = Category::BUILT_IN.into_iter().find(|c| c.as_str() == name) {
+            return Ok(built_in);
+        }
+        Validator::validate_category_name(&name).map_err(AppError::Validation)?;
+        Ok(Category::Custom(name))
+    }
+}
+
+impl Serialize for Category {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Category {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// How a category looks and which words in free text point to it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryDefinition {
+    pub category: Category,
+    pub display_name: String,
+    pub color: String,
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Whole words (or phrases) that make the parser pick this category
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+impl CategoryDefinition {
+    pub fn new(category: Category, display_name: impl Into<String>, color: impl Into<String>) -> Self {
+        Self {
+            category,
+            display_name: display_name.into(),
+            color: color.into(),
+            icon: None,
+            keywords: Vec::new(),
+        }
+    }
+
+    /// Default definition of a built-in category
+    pub fn built_in(category: Category) -> Self {
+        let (icon, keywords): (&str, &[&str]) = match category {
+            Category::Work => ("💼", &["meeting", "work", "call", "sync", "standup"]),
+            Category::Personal => ("🏠", &[]),
+            Category::Health => ("❤️", &["doctor", "dentist", "health", "appointment", "gym"]),
+            Category::Social => ("👥", &["lunch", "dinner", "coffee", "meet", "party", "drinks"]),
+            Category::Finance => ("💰", &["bank", "tax", "taxes", "invoice", "budget", "rent"]),
+            Category::Education => ("📚", &["class", "lecture", "exam", "course", "study"]),
+            Category::Other | Category::Custom(_) => ("📌", &[]),
+        };
+        Self {
+            display_name: category.display_name().to_string(),
+            color: category.color().to_string(),
+            icon: Some(icon.to_string()),
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            category,
+        }
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn with_keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Check all fields, collecting every problem into
+    /// [`AppError::InvalidFields`]
+    pub fn validate(&self) -> AppResult<()> {
+        let errors = Validator::validate_category(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::InvalidFields(errors))
+        }
+    }
+
+    /// How many of the keywords occur in `words` as whole words
+    fn matches(&self, words: &[String]) -> usize {
+        self.keywords
+            .iter()
+            .map(|keyword| split_words(keyword))
+            .filter(|phrase| !phrase.is_empty() && words.windows(phrase.len()).any(|w| w == phrase.as_slice()))
+            .count()
+    }
+}
+
+/// The categories in use: the built-ins, overridden or extended by
+/// user-defined ones
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryRegistry {
+    definitions: Vec<CategoryDefinition>,
+}
+
+impl Default for CategoryRegistry {
+    fn default() -> Self {
+        Self {
+            definitions: Category::BUILT_IN.into_iter().map(CategoryDefinition::built_in).collect(),
+        }
+    }
+}
+
+impl CategoryRegistry {
+    /// Built-ins plus `definitions`. A definition for a built-in replaces
+    /// its default; user-defined categories are listed first, in order.
+    pub fn with_definitions(definitions: impl IntoIterator<Item = CategoryDefinition>) -> Self {
+        let mut registry = Self::default();
+        let mut custom = Vec::new();
+        for definition in definitions {
+            match registry.definitions.iter_mut().find(|d| d.category == definition.category) {
+                Some(existing) => *existing = definition,
+                None if custom.iter().any(|d: &CategoryDefinition| d.category == definition.category) => {}
+                None => custom.push(definition),
+            }
+        }
+        custom.append(&mut registry.definitions);
+        registry.definitions = custom;
+        registry
+    }
+
+    pub fn definitions(&self) -> &[CategoryDefinition] {
+        &self.definitions
+    }
+
+    pub fn get(&self, category: &Category) -> Option<&CategoryDefinition> {
+        self.definitions.iter().find(|d| &d.category == category)
+    }
+
+    /// Configured color, or the default for unknown categories
+    pub fn color<'a>(&'a self, category: &'a Category) -> &'a str {
+        self.get(category).map(|d| d.color.as_str()).unwrap_or_else(|| category.color())
+    }
+
+    /// Category whose keywords best match `text`: most keywords matched
+    /// wins, ties go to the category listed first. `None` when nothing
+    /// matches.
+    pub fn detect(&self, text: &str) -> Option<Category> {
+        let words = split_words(text);
+        let mut best: Option<(&CategoryDefinition, usize)> = None;
+        for definition in &self.definitions {
+            let score = definition.matches(&words);
+            if score > 0 && best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((definition, score));
+            }
+        }
+        best.map(|(definition, _)| definition.category.clone())
+    }
+}
+
+/// Lowercase alphanumeric words of `text`
+fn split_words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_wire_format() {
+        assert_eq!(" Work ".parse::<Category>().unwrap(), Category::Work);
+        assert_eq!("Side-Project".parse::<Category>().unwrap(), Category::Custom("side-project".to_string()));
+        assert!("".parse::<Category>().is_err());
+        assert!("two words".parse::<Category>().is_err());
+
+        let json = serde_json::to_value(Category::Custom("garden".to_string())).unwrap();
+        assert_eq!(json, "garden");
+        let parsed: Category = serde_json::from_value(serde_json::json!("health")).unwrap();
+        assert_eq!(parsed, Category::Health);
+    }
+
+    #[test]
+    fn test_detect_by_whole_words() {
+        let registry = CategoryRegistry::default();
+
+        assert_eq!(registry.detect("Team meeting tomorrow"), Some(Category::Work));
+        assert_eq!(registry.detect("Coffee with Sarah"), Some(Category::Social));
+        assert_eq!(registry.detect("Doctor appointment"), Some(Category::Health));
+        // "meet" does not match inside "meeting", nor "tax" inside "taxi"
+        assert_eq!(registry.detect("Book a taxi"), None);
+    }
+
+    #[test]
+    fn test_user_definitions_override_and_extend() {
+        let garden = Category::Custom("garden".to_string());
+        let registry = CategoryRegistry::with_definitions([
+            CategoryDefinition::new(garden.clone(), "Garden", "#22C55E").with_keywords(["plant", "lawn", "call"]),
+            CategoryDefinition::built_in(Category::Work).with_keywords(["standup", "call"]),
+        ]);
+
+        assert_eq!(registry.definitions()[0].category, garden);
+        assert_eq!(registry.definitions().len(), Category::BUILT_IN.len() + 1);
+        assert_eq!(registry.color(&garden), "#22C55E");
+        assert_eq!(registry.color(&Category::Custom("unknown".to_string())), Category::Other.color());
+
+        assert_eq!(registry.detect("Mow the lawn"), Some(garden.clone()));
+        // Custom categories win ties; the work override dropped "meeting"
+        assert_eq!(registry.detect("Call the gardener"), Some(garden));
+        assert_eq!(registry.detect("Standup call"), Some(Category::Work));
+        assert_eq!(registry.detect("Team meeting"), None);
+    }
+}
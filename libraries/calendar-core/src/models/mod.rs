@@ -1,15 +1,34 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use std::collections::HashSet;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use crate::time::TimeZone;
+
+mod attendee;
+mod builder;
+mod category;
+mod occurrence;
+mod recurrence;
+mod reminder;
+mod task;
+
+pub use attendee::{Attendee, AttendeeRole, ParticipationStatus};
+pub use builder::CalendarEventBuilder;
+pub use category::{Category, CategoryDefinition, CategoryRegistry};
+pub use occurrence::{OccurrenceId, EditScope};
+pub use recurrence::{RecurrenceConfig, RecurrenceFrequency, OrdinalWeekday};
+pub use reminder::{ReminderConfig, ReminderChannel};
+pub use task::Task;
 
 pub mod prelude {
-    pub use super::{CalendarEvent, Priority, Category, EventStatus, Visibility};
-    pub use super::{RecurrenceConfig, RecurrenceFrequency};
-    pub use super::{ReminderConfig, Location, LocationType};
-    pub use super::Coordinates;
+    pub use super::{CalendarEvent, CalendarEventBuilder, Priority, Category, EventStatus, Visibility};
+    pub use super::{RecurrenceConfig, RecurrenceFrequency, OrdinalWeekday, OccurrenceId, EditScope};
+    pub use super::{ReminderConfig, ReminderChannel, Location, LocationType};
+    pub use super::{Attendee, AttendeeRole, ParticipationStatus};
+    pub use super::{Coordinates, Task};
+    pub use super::{CategoryDefinition, CategoryRegistry};
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CalendarEvent {
     pub id: Uuid,
@@ -17,27 +36,48 @@ pub struct CalendarEvent {
     pub created_at: chrono::DateTime<chrono::Utc>,
     #[serde(rename = "updatedAt")]
     pub updated_at: chrono::DateTime<chrono::Utc>,
-    pub date: String,
-    pub time: Option<String>,
-    #[serde(rename = "endTime")]
-    pub end_time: Option<String>,
+    pub date: NaiveDate,
+    #[serde(with = "crate::time::serde_hhmm::option", default)]
+    pub time: Option<NaiveTime>,
+    #[serde(rename = "endTime", with = "crate::time::serde_hhmm::option", default)]
+    pub end_time: Option<NaiveTime>,
+    /// Last day of a multi-day event (inclusive); `None` for single-day events
+    #[serde(rename = "endDate", default)]
+    pub end_date: Option<NaiveDate>,
+    /// All-day events ignore `time`/`end_time` and cover whole days
+    #[serde(rename = "allDay", default)]
+    pub all_day: bool,
     pub event: String,
     pub notes: Option<String>,
     pub priority: Priority,
     pub recurring: Option<RecurrenceConfig>,
-    pub reminder: Option<ReminderConfig>,
+    /// Series an expanded occurrence belongs to; `None` on stored events
+    #[serde(rename = "seriesId", default)]
+    pub series_id: Option<Uuid>,
+    /// Date an expanded occurrence was originally scheduled on (`RECURRENCE-ID`)
+    #[serde(rename = "recurrenceId", default)]
+    pub recurrence_id: Option<NaiveDate>,
+    /// Reminders; a single legacy `reminder` object is also accepted
+    #[serde(default, alias = "reminder", deserialize_with = "reminder::deserialize_reminders")]
+    pub reminders: Vec<ReminderConfig>,
     pub location: Option<Location>,
+    /// People invited, including the organizer
+    #[serde(default)]
+    pub attendees: Vec<Attendee>,
     pub category: Category,
     pub color: Option<String>,
     pub tags: Vec<String>,
     pub status: EventStatus,
     pub visibility: Visibility,
+    /// IANA zone the date and time are written in; `None` uses the default zone
+    #[serde(default)]
+    pub timezone: Option<String>,
     #[serde(default)]
     pub metadata: serde_json::Value,
 }
 
 impl CalendarEvent {
-    pub fn new(event: String, date: String) -> Self {
+    pub fn new(event: String, date: NaiveDate) -> Self {
         let now = chrono::Utc::now();
         Self {
             id: Uuid::new_v4(),
@@ -46,33 +86,166 @@ impl CalendarEvent {
             date,
             time: None,
             end_time: None,
+            end_date: None,
+            all_day: false,
             event,
             notes: None,
             priority: Priority::Medium,
             recurring: None,
-            reminder: None,
+            series_id: None,
+            recurrence_id: None,
+            reminders: Vec::new(),
             location: None,
+            attendees: Vec::new(),
             category: Category::Other,
             color: None,
             tags: Vec::new(),
             status: EventStatus::Confirmed,
             visibility: Visibility::Private,
+            timezone: None,
             metadata: serde_json::json!({}),
         }
     }
 
+    /// The attendee with the organizer role, if any
+    pub fn organizer(&self) -> Option<&Attendee> {
+        self.attendees.iter().find(|a| a.role == AttendeeRole::Organizer)
+    }
+
+    /// Record `email`'s RSVP; fails if they are not invited
+    pub fn set_attendee_status(&mut self, email: &str, status: ParticipationStatus) -> crate::AppResult<()> {
+        let attendee = self.attendees
+            .iter_mut()
+            .find(|a| a.has_email(email))
+            .ok_or_else(|| crate::AppError::Validation(format!("Not an attendee: {}", email)))?;
+        attendee.status = status;
+        Ok(())
+    }
+
+    /// Start building an event with the given title on `date`
+    pub fn builder(event: impl Into<String>, date: NaiveDate) -> CalendarEventBuilder {
+        CalendarEventBuilder::new(event, date)
+    }
+
+    /// Check every field, failing with all problems found
     pub fn validate(&self) -> crate::AppResult<()> {
-        if self.event.trim().is_empty() {
-            return Err(crate::AppError::Validation("Event title cannot be empty".to_string()));
+        let errors = crate::Validator::validate_event(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::AppError::InvalidFields(errors))
         }
-        Ok(())
     }
 
     pub fn effective_color(&self) -> &str {
         self.color.as_deref().unwrap_or_else(|| self.category.color())
     }
 
-    /// Create CalendarEvent from parsed event data (from SimpleParser or AI)
+    /// Last day the event covers (inclusive)
+    pub fn last_date(&self) -> NaiveDate {
+        self.end_date.unwrap_or(self.date)
+    }
+
+    /// Number of days after the start date that the event ends on
+    pub fn span_days(&self) -> i64 {
+        (self.last_date() - self.date).num_days().max(0)
+    }
+
+    /// Whether any day of the event falls within `[start, end]`
+    pub fn overlaps_dates(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        self.date <= end && self.last_date() >= start
+    }
+
+    /// Move the event to start on `date`, keeping its length
+    pub fn shift_to(&mut self, date: NaiveDate) {
+        let span = self.span_days();
+        self.date = date;
+        if self.end_date.is_some() {
+            self.end_date = Some(date + chrono::Duration::days(span));
+        }
+    }
+
+    /// Identity of this event as an occurrence of a series, if it is one
+    pub fn occurrence_id(&self) -> Option<OccurrenceId> {
+        Some(OccurrenceId::new(self.series_id?, self.recurrence_id?))
+    }
+
+    /// Expand this series into the occurrence originally scheduled on
+    /// `original_date`, with a stable id derived from the series and date
+    pub fn instance(&self, original_date: NaiveDate) -> Self {
+        let occurrence = OccurrenceId::new(self.id, original_date);
+        let mut instance = self.clone();
+        instance.shift_to(original_date);
+        instance.id = occurrence.instance_uuid();
+        instance.series_id = Some(occurrence.series_id);
+        instance.recurrence_id = Some(occurrence.original_date);
+        instance
+    }
+
+    /// Zone this event is written in, falling back to `default`
+    pub fn zone(&self, default: TimeZone) -> TimeZone {
+        self.timezone
+            .as_deref()
+            .and_then(|tz| TimeZone::parse(tz).ok())
+            .unwrap_or(default)
+    }
+
+    /// Wall-clock start; all-day and untimed events start at midnight
+    pub fn start_naive(&self) -> NaiveDateTime {
+        let time = match self.time {
+            Some(time) if !self.all_day => time,
+            _ => NaiveTime::MIN,
+        };
+        self.date.and_time(time)
+    }
+
+    /// Wall-clock end (exclusive). All-day events end at midnight after
+    /// their last day; timed events need an `end_time`.
+    pub fn end_naive(&self) -> Option<NaiveDateTime> {
+        if self.all_day {
+            return Some(self.last_date().succ_opt()?.and_time(NaiveTime::MIN));
+        }
+        Some(self.last_date().and_time(self.end_time?))
+    }
+
+    /// Start instant; untimed events start at midnight in their zone
+    pub fn start_utc(&self, default: TimeZone) -> Option<DateTime<Utc>> {
+        self.zone(default).localize(self.start_naive())
+    }
+
+    /// End instant, if the event has an end
+    pub fn end_utc(&self, default: TimeZone) -> Option<DateTime<Utc>> {
+        self.zone(default).localize(self.end_naive()?)
+    }
+
+    /// Copy of this event with its date and times rewritten for display in `target`
+    pub fn in_zone(&self, target: TimeZone, default: TimeZone) -> Self {
+        let mut converted = self.clone();
+        // All-day events cover calendar days wherever they are viewed
+        if self.all_day || self.time.is_none() || self.zone(default) == target {
+            return converted;
+        }
+        if let Some(start) = self.start_utc(default) {
+            let local = target.from_utc(start);
+            converted.date = local.date();
+            converted.time = Some(local.time());
+        }
+        if let Some(end) = self.end_utc(default) {
+            let local = target.from_utc(end);
+            converted.end_time = Some(local.time());
+            converted.end_date = (local.date() != converted.date).then_some(local.date());
+        }
+        converted.timezone = target.tzid().map(str::to_string);
+        converted
+    }
+
+    /// Create CalendarEvent from parsed event data with string fields.
+    ///
+    /// Fails if the date is not a real `YYYY-MM-DD` calendar date, a time
+    /// is not `HH:MM`, or the event does not validate. Unknown priorities and
+    /// categories fall back to the defaults. New code should use
+    /// [`CalendarEvent::builder`].
+    #[allow(clippy::too_many_arguments)]
     pub fn from_parsed(
         event: String,
         date: String,
@@ -84,42 +257,37 @@ impl CalendarEvent {
         tags: Vec<String>,
         metadata: serde_json::Value,
         recurring: Option<RecurrenceConfig>,
-    ) -> Self {
-        let now = chrono::Utc::now();
-        
-        // Apply inferred defaults
-        let priority_enum = priority.parse().unwrap_or(Priority::Medium);
-        let category_enum = category.parse().unwrap_or(Category::Other);
-        
-        // Ensure metadata has source
-        let mut final_metadata = metadata;
-        if !final_metadata.is_object() {
-            final_metadata = serde_json::json!({});
+    ) -> crate::AppResult<Self> {
+        let date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+            .map_err(|_| crate::AppError::Validation(format!("Invalid date: {}", date)))?;
+        let parse_time = |time: &str| {
+            crate::time::parse_hhmm(time)
+                .ok_or_else(|| crate::AppError::Validation(format!("Invalid time: {}", time)))
+        };
+
+        let mut builder = Self::builder(event, date)
+            .priority(priority.parse().unwrap_or(Priority::Medium))
+            .category(category.parse().unwrap_or(Category::Other))
+            .tags(tags)
+            .metadata(metadata);
+        if let Some(time) = time {
+            builder = builder.time(parse_time(&time)?);
         }
-        if !final_metadata.get("source").is_some() {
-            final_metadata["source"] = serde_json::json!("Parsed");
+        if let Some(end_time) = end_time {
+            builder = builder.end_time(parse_time(&end_time)?);
         }
-        
-        Self {
-            id: Uuid::new_v4(),
-            created_at: now,
-            updated_at: now,
-            date,
-            time,
-            end_time,
-            event,
-            notes,
-            priority: priority_enum,
-            category: category_enum,
-            color: None,
-            tags,
-            status: EventStatus::Confirmed,
-            visibility: Visibility::Private,
-            recurring,
-            reminder: None,
-            location: None,
-            metadata: final_metadata,
+        if let Some(notes) = notes {
+            builder = builder.notes(notes);
+        }
+        if let Some(recurring) = recurring {
+            builder = builder.recurrence(recurring);
+        }
+
+        let mut event = builder.build()?;
+        if event.metadata.get("source").is_none() {
+            event.metadata["source"] = serde_json::json!("Parsed");
         }
+        Ok(event)
     }
 }
 
@@ -165,60 +333,28 @@ impl Priority {
             Priority::Urgent => "urgent",
         }
     }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum Category {
-    Work,
-    Personal,
-    Health,
-    Social,
-    Finance,
-    Education,
-    Other,
-}
-
-impl Default for Category {
-    fn default() -> Self {
-        Category::Other
-    }
-}
 
-impl Category {
-    pub fn color(&self) -> &'static str {
+    /// RFC 5545 `PRIORITY` value (1 is highest)
+    pub fn ical_priority(&self) -> u8 {
         match self {
-            Category::Work => "#3B82F6",
-            Category::Personal => "#10B981",
-            Category::Health => "#EF4444",
-            Category::Social => "#8B5CF6",
-            Category::Finance => "#F59E0B",
-            Category::Education => "#6366F1",
-            Category::Other => "#6B7280",
+            Priority::Urgent => 1,
+            Priority::High => 3,
+            Priority::Medium => 5,
+            Priority::Low => 9,
         }
     }
+}
 
-    pub fn display_name(&self) -> &'static str {
-        match self {
-            Category::Work => "Work",
-            Category::Personal => "Personal",
-            Category::Health => "Health",
-            Category::Social => "Social",
-            Category::Finance => "Finance",
-            Category::Education => "Education",
-            Category::Other => "Other",
-        }
-    }
+impl std::str::FromStr for Priority {
+    type Err = crate::AppError;
 
-    pub fn as_str(&self) -> &str {
-        match self {
-            Category::Work => "work",
-            Category::Personal => "personal",
-            Category::Health => "health",
-            Category::Social => "social",
-            Category::Finance => "finance",
-            Category::Education => "education",
-            Category::Other => "other",
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "low" => Ok(Priority::Low),
+            "medium" => Ok(Priority::Medium),
+            "high" => Ok(Priority::High),
+            "urgent" => Ok(Priority::Urgent),
+            other => Err(crate::AppError::Validation(format!("Unknown priority: {}", other))),
         }
     }
 }
@@ -249,6 +385,20 @@ impl EventStatus {
     }
 }
 
+impl std::str::FromStr for EventStatus {
+    type Err = crate::AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "tentative" => Ok(EventStatus::Tentative),
+            "confirmed" => Ok(EventStatus::Confirmed),
+            "cancelled" => Ok(EventStatus::Cancelled),
+            "completed" => Ok(EventStatus::Completed),
+            other => Err(crate::AppError::Validation(format!("Unknown status: {}", other))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Visibility {
@@ -271,182 +421,19 @@ impl Visibility {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct RecurrenceConfig {
-    pub frequency: RecurrenceFrequency,
-    #[serde(default = "default_interval")]
-    pub interval: u32,
-    #[serde(default)]
-    pub days_of_week: Vec<u8>,
-    #[serde(rename = "endDate")]
-    pub end_date: Option<String>,
-    pub occurrences: Option<u32>,
-    #[serde(rename = "exceptDates", default)]
-    pub except_dates: Vec<String>,
-}
-
-impl Default for RecurrenceConfig {
-    fn default() -> Self {
-        Self {
-            frequency: RecurrenceFrequency::None,
-            interval: 1,
-            days_of_week: Vec::new(),
-            end_date: None,
-            occurrences: None,
-            except_dates: Vec::new(),
-        }
-    }
-}
-
-impl RecurrenceConfig {
-    /// Generate occurrence dates for this recurrence pattern
-    pub fn generate_occurrences(
-        &self,
-        start_date: &str,
-        limit: Option<u32>,
-    ) -> Vec<String> {
-        if self.frequency == RecurrenceFrequency::None {
-            return vec![start_date.to_string()];
-        }
-
-        let mut occurrences = Vec::new();
-        let Some(mut current) = chrono::NaiveDate::parse_from_str(start_date, "%Y-%m-%d").ok() else {
-            return vec![start_date.to_string()];
-        };
-        
-        let max_occurrences = limit.or(self.occurrences).unwrap_or(365);
-        let mut count = 0;
-
-        while count < max_occurrences {
-            // Check if we've hit end_date
-            if let Some(ref end_date_str) = self.end_date {
-                if let Ok(end_date) = chrono::NaiveDate::parse_from_str(end_date_str, "%Y-%m-%d") {
-                    if current > end_date {
-                        break;
-                    }
-                }
-            }
-
-            // Check if this date is excluded
-            let date_str = current.format("%Y-%m-%d").to_string();
-            if !self.except_dates.contains(&date_str) {
-                occurrences.push(date_str.clone());
-                count += 1;
-            }
-
-            // Advance to next occurrence
-            current = match self.frequency {
-                RecurrenceFrequency::Daily => {
-                    current.checked_add_signed(chrono::Duration::days(self.interval as i64))?
-                }
-                RecurrenceFrequency::Weekly => {
-                    if self.days_of_week.is_empty() {
-                        match current.checked_add_signed(chrono::Duration::weeks(self.interval as i64)) {
-                            Some(d) => d,
-                            None => break,
-                        }
-                    } else {
-                        // Find next matching weekday
-                        let mut next = current;
-                        for _ in 0..(7 * self.interval as i64) {
-                            next = match next.succ_opt() {
-                                Some(d) => d,
-                                None => break,
-                            };
-                            let weekday_num = next.weekday().num_days_from_sunday() as u8;
-                            if self.days_of_week.contains(&weekday_num) {
-                                break;
-                            }
-                        }
-                        next
-                    }
-                }
-                RecurrenceFrequency::Biweekly => {
-                    match current.checked_add_signed(chrono::Duration::weeks(2 * self.interval as i64)) {
-                        Some(d) => d,
-                        None => break,
-                    }
-                }
-                RecurrenceFrequency::Monthly => {
-                    // Add one month
-                    let mut year = current.year();
-                    let mut month = current.month();
-                    month += self.interval;
-                    while month > 12 {
-                        month -= 12;
-                        year += 1;
-                    }
-                    chrono::NaiveDate::from_ymd_opt(year, month, current.day())
-                        .unwrap_or_else(|| current + chrono::Duration::days(30 * self.interval as i64))
-                }
-                RecurrenceFrequency::Yearly => {
-                    chrono::NaiveDate::from_ymd_opt(
-                        current.year() + self.interval as i32,
-                        current.month(),
-                        current.day()
-                    ).unwrap_or_else(|| current + chrono::Duration::days(365 * self.interval as i64))
-                }
-                _ => break,
-            };
-        }
-
-        occurrences
-    }
-}
-
-fn default_interval() -> u32 {
-    1
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum RecurrenceFrequency {
-    None,
-    Daily,
-    Weekly,
-    Biweekly,
-    Monthly,
-    Yearly,
-    Custom,
-}
-
-impl RecurrenceFrequency {
-    pub fn as_str(&self) -> &str {
-        match self {
-            RecurrenceFrequency::None => "none",
-            RecurrenceFrequency::Daily => "daily",
-            RecurrenceFrequency::Weekly => "weekly",
-            RecurrenceFrequency::Biweekly => "biweekly",
-            RecurrenceFrequency::Monthly => "monthly",
-            RecurrenceFrequency::Yearly => "yearly",
-            RecurrenceFrequency::Custom => "custom",
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ReminderConfig {
-    #[serde(rename = "minutesBefore")]
-    pub minutes_before: u32,
-    #[serde(rename = "repeatMinutes")]
-    pub repeat_minutes: Option<u32>,
-    #[serde(rename = "maxReminders")]
-    pub max_reminders: u32,
-}
+impl std::str::FromStr for Visibility {
+    type Err = crate::AppError;
 
-impl Default for ReminderConfig {
-    fn default() -> Self {
-        Self {
-            minutes_before: 15,
-            repeat_minutes: None,
-            max_reminders: 3,
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "public" => Ok(Visibility::Public),
+            "private" => Ok(Visibility::Private),
+            other => Err(crate::AppError::Validation(format!("Unknown visibility: {}", other))),
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Location {
     pub location_type: LocationType,
@@ -470,6 +457,18 @@ impl LocationType {
     }
 }
 
+impl std::str::FromStr for LocationType {
+    type Err = crate::AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "physical" => Ok(LocationType::Physical),
+            "virtual" => Ok(LocationType::Virtual),
+            other => Err(crate::AppError::Validation(format!("Unknown location type: {}", other))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Coordinates {
@@ -480,16 +479,17 @@ pub struct Coordinates {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::{date, time};
     
     #[test]
     fn test_validate_empty_title() {
-        let event = CalendarEvent::new("".to_string(), "2026-01-20".to_string());
+        let event = CalendarEvent::new("".to_string(), date("2026-01-20"));
         assert!(event.validate().is_err());
     }
     
     #[test]
     fn test_validate_valid_event() {
-        let event = CalendarEvent::new("Meeting".to_string(), "2026-01-20".to_string());
+        let event = CalendarEvent::new("Meeting".to_string(), date("2026-01-20"));
         assert!(event.validate().is_ok());
     }
     
@@ -506,7 +506,7 @@ mod tests {
             vec!["meeting".to_string()],
             serde_json::json!({"project": "DemoProject"}),
             None,
-        );
+        ).unwrap();
         
         assert_eq!(event.event, "Team meeting");
         assert_eq!(event.priority, Priority::High);
@@ -523,6 +523,7 @@ mod tests {
             end_date: None,
             occurrences: Some(5),
             except_dates: vec![],
+            ..Default::default()
         };
         
         let event = CalendarEvent::from_parsed(
@@ -536,7 +537,7 @@ mod tests {
             vec!["standup".to_string()],
             serde_json::json!({}),
             Some(recurring),
-        );
+        ).unwrap();
         
         assert!(event.recurring.is_some());
         assert_eq!(event.recurring.unwrap().frequency, RecurrenceFrequency::Daily);
@@ -551,12 +552,13 @@ mod tests {
             end_date: None,
             occurrences: Some(5),
             except_dates: vec![],
+            ..Default::default()
         };
         
-        let occurrences = config.generate_occurrences("2026-01-20", Some(5));
+        let occurrences = config.generate_occurrences(date("2026-01-20"), Some(5));
         assert_eq!(occurrences.len(), 5);
-        assert_eq!(occurrences[0], "2026-01-20");
-        assert_eq!(occurrences[1], "2026-01-21");
+        assert_eq!(occurrences[0], date("2026-01-20"));
+        assert_eq!(occurrences[1], date("2026-01-21"));
     }
     
     #[test]
@@ -568,9 +570,10 @@ mod tests {
             end_date: None,
             occurrences: Some(3),
             except_dates: vec![],
+            ..Default::default()
         };
         
-        let occurrences = config.generate_occurrences("2026-01-20", Some(3));
+        let occurrences = config.generate_occurrences(date("2026-01-20"), Some(3));
         assert_eq!(occurrences.len(), 3);
     }
     
@@ -589,4 +592,78 @@ mod tests {
         assert_eq!("health".parse::<Category>().unwrap(), Category::Health);
         assert_eq!("social".parse::<Category>().unwrap(), Category::Social);
     }
+
+    #[test]
+    fn test_in_zone_conversion() {
+        let mut event = CalendarEvent::new("Standup".to_string(), date("2026-03-09"));
+        event.time = Some(time("09:00"));
+        event.end_time = Some(time("09:15"));
+        event.timezone = Some("America/New_York".to_string());
+        assert!(event.validate().is_ok());
+
+        let berlin = TimeZone::parse("Europe/Berlin").unwrap();
+        let shown = event.in_zone(berlin, TimeZone::Utc);
+        // New York is already on EDT while Berlin is still on CET
+        assert_eq!(shown.time, Some(time("14:00")));
+        assert_eq!(shown.end_time, Some(time("14:15")));
+        assert_eq!(shown.timezone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(shown.start_utc(TimeZone::Utc), event.start_utc(TimeZone::Utc));
+
+        event.timezone = Some("Nowhere/Special".to_string());
+        assert!(event.validate().is_err());
+    }
+
+    #[test]
+    fn test_multi_day_span() {
+        let mut event = CalendarEvent::new("Conference".to_string(), date("2026-05-04"));
+        event.all_day = true;
+        event.end_date = Some(date("2026-05-06"));
+        assert!(event.validate().is_ok());
+        assert_eq!(event.span_days(), 2);
+        assert!(event.overlaps_dates(date("2026-05-06"), date("2026-05-10")));
+        assert!(!event.overlaps_dates(date("2026-05-07"), date("2026-05-10")));
+        assert_eq!(event.end_naive().unwrap().to_string(), "2026-05-07 00:00:00");
+
+        event.shift_to(date("2026-06-01"));
+        assert_eq!(event.end_date, Some(date("2026-06-03")));
+
+        event.end_date = Some(date("2026-05-31"));
+        assert!(event.validate().is_err());
+    }
+
+    #[test]
+    fn test_overnight_event_in_zone() {
+        let mut event = CalendarEvent::new("Night shift".to_string(), date("2026-05-04"));
+        event.time = Some(time("22:00"));
+        event.end_time = Some(time("06:00"));
+        event.end_date = Some(date("2026-05-05"));
+        event.timezone = Some("UTC".to_string());
+
+        let shown = event.in_zone(TimeZone::parse("Asia/Tokyo").unwrap(), TimeZone::Utc);
+        assert_eq!(shown.date, date("2026-05-05"));
+        assert_eq!(shown.time, Some(time("07:00")));
+        assert_eq!(shown.end_time, Some(time("15:00")));
+        assert_eq!(shown.end_date, None);
+    }
+
+    #[test]
+    fn test_wire_format_keeps_strings() {
+        let mut event = CalendarEvent::new("Review".to_string(), date("2026-01-20"));
+        event.time = Some(time("14:00"));
+        
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["date"], "2026-01-20");
+        assert_eq!(json["time"], "14:00");
+        assert_eq!(json["endTime"], serde_json::Value::Null);
+        
+        let parsed: CalendarEvent = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(parsed, event);
+        
+        let mut invalid = json;
+        invalid["date"] = serde_json::json!("2026-99-99");
+        assert!(serde_json::from_value::<CalendarEvent>(invalid.clone()).is_err());
+        invalid["date"] = serde_json::json!("2026-01-20");
+        invalid["time"] = serde_json::json!("25:00");
+        assert!(serde_json::from_value::<CalendarEvent>(invalid).is_err());
+    }
 }
@@ -0,0 +1,85 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::{AppError, AppResult};
+
+/// Stable identity of one occurrence of a recurring series: the series id
+/// plus the date the occurrence was originally scheduled on (RFC 5545
+/// `RECURRENCE-ID`).
+///
+/// The string form is `<series-id>:<YYYY-MM-DD>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OccurrenceId {
+    pub series_id: Uuid,
+    pub original_date: NaiveDate,
+}
+
+impl OccurrenceId {
+    pub fn new(series_id: Uuid, original_date: NaiveDate) -> Self {
+        Self {
+            series_id,
+            original_date,
+        }
+    }
+
+    /// Deterministic id given to the expanded instance; the same occurrence
+    /// always gets the same id
+    pub fn instance_uuid(&self) -> Uuid {
+        Uuid::new_v5(&self.series_id, self.original_date.format("%Y-%m-%d").to_string().as_bytes())
+    }
+}
+
+/// Which part of a recurring series an edit or delete applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EditScope {
+    /// Only the selected occurrence
+    This,
+    /// The selected occurrence and every later one; splits the series
+    ThisAndFollowing,
+    /// The whole series
+    #[default]
+    All,
+}
+
+impl std::fmt::Display for OccurrenceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.series_id, self.original_date.format("%Y-%m-%d"))
+    }
+}
+
+impl std::str::FromStr for OccurrenceId {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> AppResult<Self> {
+        let invalid = || AppError::Validation(format!("Invalid occurrence id: {}", s));
+        let (series, date) = s.split_once(':').ok_or_else(invalid)?;
+        let series_id = series.parse().map_err(|_| invalid())?;
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| invalid())?;
+        Ok(Self::new(series_id, date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_and_stable_uuid() {
+        let series = Uuid::new_v4();
+        let date = |s: &str| s.parse::<NaiveDate>().unwrap();
+        let id = OccurrenceId::new(series, date("2026-02-03"));
+        let parsed: OccurrenceId = id.to_string().parse().unwrap();
+
+        assert_eq!(parsed, id);
+        assert_eq!(parsed.instance_uuid(), id.instance_uuid());
+        assert_ne!(id.instance_uuid(), OccurrenceId::new(series, date("2026-02-10")).instance_uuid());
+    }
+
+    #[test]
+    fn test_invalid_ids() {
+        assert!("not-an-id".parse::<OccurrenceId>().is_err());
+        assert!(format!("{}:tomorrow", Uuid::new_v4()).parse::<OccurrenceId>().is_err());
+    }
+}
@@ -0,0 +1,881 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use crate::{AppError, AppResult};
+
+/// Cap applied when neither a limit nor a COUNT bounds the expansion
+const DEFAULT_OCCURRENCE_LIMIT: u32 = 365;
+
+/// Upper bound on periods walked, so rules that can never match terminate
+const MAX_PERIODS: u32 = 10_000;
+
+/// RFC 5545 two-letter weekday codes, indexed from Sunday
+const WEEKDAY_CODES: [&str; 7] = ["SU", "MO", "TU", "WE", "TH", "FR", "SA"];
+
+/// Recurrence rule for an event.
+///
+/// Weekdays are numbered 0 = Sunday through 6 = Saturday throughout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurrenceConfig {
+    pub frequency: RecurrenceFrequency,
+    #[serde(default = "default_interval")]
+    pub interval: u32,
+    /// Plain `BYDAY` weekdays, e.g. every Monday and Wednesday
+    #[serde(default)]
+    pub days_of_week: Vec<u8>,
+    /// `BYDAY` weekdays with an ordinal, e.g. `2TU` or `-1FR`
+    #[serde(default)]
+    pub ordinal_weekdays: Vec<OrdinalWeekday>,
+    /// `BYMONTHDAY`; negative values count back from the end of the month
+    #[serde(default)]
+    pub by_month_day: Vec<i8>,
+    /// `BYMONTH`, 1 = January
+    #[serde(default)]
+    pub by_month: Vec<u8>,
+    /// `BYSETPOS`; negative values count back from the end of each period
+    #[serde(default)]
+    pub by_set_pos: Vec<i16>,
+    /// `WKST`; `None` means Monday, the RFC 5545 default
+    #[serde(default)]
+    pub week_start: Option<u8>,
+    /// `UNTIL`, inclusive
+    #[serde(rename = "endDate")]
+    pub end_date: Option<NaiveDate>,
+    pub occurrences: Option<u32>,
+    #[serde(rename = "exceptDates", default)]
+    pub except_dates: Vec<NaiveDate>,
+}
+
+impl Default for RecurrenceConfig {
+    fn default() -> Self {
+        Self {
+            frequency: RecurrenceFrequency::None,
+            interval: 1,
+            days_of_week: Vec::new(),
+            ordinal_weekdays: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: None,
+            end_date: None,
+            occurrences: None,
+            except_dates: Vec::new(),
+        }
+    }
+}
+
+impl RecurrenceConfig {
+    /// Generate occurrence dates for this recurrence pattern.
+    ///
+    /// The start date is always the first instance. `occurrences` (COUNT)
+    /// counts instances before `except_dates` are removed, as in RFC 5545,
+    /// while `limit` caps the number of dates returned.
+    pub fn generate_occurrences(
+        &self,
+        start: NaiveDate,
+        limit: Option<u32>,
+    ) -> Vec<NaiveDate> {
+        if !self.is_repeating() {
+            return vec![start];
+        }
+
+        let max_results = limit.unwrap_or(DEFAULT_OCCURRENCE_LIMIT) as usize;
+
+        let mut occurrences = Vec::new();
+
+        for (generated, date) in self.instances(start, self.end_date).enumerate() {
+            if self.occurrences.is_some_and(|count| generated as u32 >= count) {
+                break;
+            }
+
+            if self.is_excepted(date) {
+                continue;
+            }
+
+            occurrences.push(date);
+            if occurrences.len() >= max_results {
+                break;
+            }
+        }
+
+        occurrences
+    }
+
+    /// Lazily yield the occurrence dates that fall within `[from, to]`.
+    ///
+    /// Unlike [`generate_occurrences`](Self::generate_occurrences) this is
+    /// not capped and does not walk from the series start: expansion seeks
+    /// straight to the period containing `from`, so unbounded series that
+    /// began long ago stay cheap. Rules with a COUNT are still walked from
+    /// the start, since every earlier instance counts towards it.
+    pub fn occurrences_between(
+        &self,
+        start: NaiveDate,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> impl Iterator<Item = NaiveDate> + '_ {
+        let last = self.end_date.map_or(to, |until| until.min(to));
+        let first_period = match self.occurrences {
+            Some(_) => 0,
+            None => self.period_containing(start, from),
+        };
+
+        self.instances_from(start, Some(last), first_period)
+            .take(self.occurrences.map_or(usize::MAX, |count| count as usize))
+            .skip_while(move |date| *date < from)
+            .filter(move |date| !self.is_excepted(*date))
+    }
+
+    /// Parse an RFC 5545 RRULE value, with or without the `RRULE:` prefix
+    pub fn from_rrule(rule: &str) -> AppResult<Self> {
+        let rule = rule.trim();
+        let body = match rule.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &rule[6..],
+            _ => rule,
+        };
+
+        let mut config = Self::default();
+        let mut frequency = None;
+
+        for part in body.split(';').filter(|p| !p.trim().is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid_rrule(format!("malformed part '{}'", part)))?;
+            let key = key.trim().to_ascii_uppercase();
+            let value = value.trim();
+
+            match key.as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => RecurrenceFrequency::Daily,
+                        "WEEKLY" => RecurrenceFrequency::Weekly,
+                        "MONTHLY" => RecurrenceFrequency::Monthly,
+                        "YEARLY" => RecurrenceFrequency::Yearly,
+                        other => return Err(invalid_rrule(format!("unsupported FREQ '{}'", other))),
+                    });
+                }
+                "INTERVAL" => {
+                    config.interval = parse_number(&key, value)?;
+                    if config.interval == 0 {
+                        return Err(invalid_rrule("INTERVAL must be at least 1".to_string()));
+                    }
+                }
+                "COUNT" => config.occurrences = Some(parse_number(&key, value)?),
+                "UNTIL" => config.end_date = Some(parse_until(value)?),
+                "BYDAY" => {
+                    for item in value.split(',') {
+                        let (ordinal, weekday) = parse_by_day(item)?;
+                        match ordinal {
+                            Some(ordinal) => config.ordinal_weekdays.push(OrdinalWeekday { ordinal, weekday }),
+                            None => config.days_of_week.push(weekday),
+                        }
+                    }
+                }
+                "BYMONTHDAY" => config.by_month_day = parse_list(&key, value, 1, 31)?,
+                "BYMONTH" => {
+                    config.by_month = parse_list::<i8>(&key, value, 1, 12)?
+                        .into_iter()
+                        .map(|m| {
+                            if m < 0 {
+                                Err(invalid_rrule(format!("BYMONTH value {} out of range", m)))
+                            } else {
+                                Ok(m as u8)
+                            }
+                        })
+                        .collect::<AppResult<_>>()?;
+                }
+                "BYSETPOS" => config.by_set_pos = parse_list(&key, value, 1, 366)?,
+                "WKST" => config.week_start = Some(parse_weekday_code(value)?),
+                other => return Err(invalid_rrule(format!("unsupported rule part '{}'", other))),
+            }
+        }
+
+        config.frequency = frequency.ok_or_else(|| invalid_rrule("missing FREQ".to_string()))?;
+
+        if config.occurrences.is_some() && config.end_date.is_some() {
+            return Err(invalid_rrule("COUNT and UNTIL cannot both be set".to_string()));
+        }
+
+        Ok(config)
+    }
+
+    /// Serialize as an RFC 5545 RRULE value (without the `RRULE:` prefix).
+    ///
+    /// Returns `None` for non-repeating and custom frequencies. Exception
+    /// dates are not part of the rule and must be written as EXDATE.
+    pub fn to_rrule(&self) -> Option<String> {
+        let interval = self.interval.max(1);
+        let (freq, interval) = match self.frequency {
+            RecurrenceFrequency::Daily => ("DAILY", interval),
+            RecurrenceFrequency::Weekly => ("WEEKLY", interval),
+            RecurrenceFrequency::Biweekly => ("WEEKLY", interval * 2),
+            RecurrenceFrequency::Monthly => ("MONTHLY", interval),
+            RecurrenceFrequency::Yearly => ("YEARLY", interval),
+            RecurrenceFrequency::None | RecurrenceFrequency::Custom => return None,
+        };
+
+        let mut parts = vec![format!("FREQ={}", freq)];
+
+        if interval > 1 {
+            parts.push(format!("INTERVAL={}", interval));
+        }
+
+        if let Some(count) = self.occurrences {
+            parts.push(format!("COUNT={}", count));
+        } else if let Some(until) = self.end_date {
+            parts.push(format!("UNTIL={}", until.format("%Y%m%d")));
+        }
+
+        if !self.by_month.is_empty() {
+            parts.push(format!("BYMONTH={}", join(&self.by_month)));
+        }
+
+        if !self.by_month_day.is_empty() {
+            parts.push(format!("BYMONTHDAY={}", join(&self.by_month_day)));
+        }
+
+        let by_day: Vec<String> = self.days_of_week.iter()
+            .filter_map(|&d| weekday_code(d).map(str::to_string))
+            .chain(self.ordinal_weekdays.iter().filter_map(|o| {
+                weekday_code(o.weekday).map(|code| format!("{}{}", o.ordinal, code))
+            }))
+            .collect();
+        if !by_day.is_empty() {
+            parts.push(format!("BYDAY={}", by_day.join(",")));
+        }
+
+        if !self.by_set_pos.is_empty() {
+            parts.push(format!("BYSETPOS={}", join(&self.by_set_pos)));
+        }
+
+        if let Some(code) = self.week_start.and_then(weekday_code) {
+            parts.push(format!("WKST={}", code));
+        }
+
+        Some(parts.join(";"))
+    }
+
+    /// Split the series at `split_date` for a "this and following" edit.
+    ///
+    /// Returns the rule for the existing series, now ending before
+    /// `split_date`, and the rule for the new series that continues from it.
+    /// A COUNT is shared between the two so the total number of instances
+    /// is unchanged.
+    pub fn split_at(&self, start: NaiveDate, split: NaiveDate) -> (Self, Self) {
+        let mut head = self.clone();
+        let mut tail = self.clone();
+
+        head.except_dates.retain(|date| *date < split);
+        tail.except_dates.retain(|date| *date >= split);
+
+        match self.occurrences {
+            Some(count) => {
+                let before = self.instances(start, None)
+                    .take(count as usize)
+                    .take_while(|date| *date < split)
+                    .count() as u32;
+                head.occurrences = Some(before);
+                tail.occurrences = Some(count - before);
+            }
+            None => {
+                head.end_date = split.pred_opt();
+            }
+        }
+
+        (head, tail)
+    }
+
+    fn is_excepted(&self, date: NaiveDate) -> bool {
+        self.except_dates.contains(&date)
+    }
+
+    fn is_repeating(&self) -> bool {
+        !matches!(self.frequency, RecurrenceFrequency::None | RecurrenceFrequency::Custom)
+    }
+
+    /// Instances of the rule in ascending order, starting with `start`
+    fn instances(
+        &self,
+        start: NaiveDate,
+        until: Option<NaiveDate>,
+    ) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.instances_from(start, until, 0)
+    }
+
+    /// Instances of the rule in ascending order, beginning with the period
+    /// at index `first_period`. `start` itself is only yielded from period 0.
+    fn instances_from(
+        &self,
+        start: NaiveDate,
+        until: Option<NaiveDate>,
+        first_period: u32,
+    ) -> impl Iterator<Item = NaiveDate> + '_ {
+        let mut period = first_period;
+        let last_period = first_period.saturating_add(MAX_PERIODS);
+        let mut pending = VecDeque::new();
+        if first_period == 0 {
+            pending.push_back(start);
+        }
+
+        std::iter::from_fn(move || loop {
+            if let Some(date) = pending.pop_front() {
+                if until.is_some_and(|u| date > u) {
+                    return None;
+                }
+                return Some(date);
+            }
+
+            if period >= last_period {
+                return None;
+            }
+            let anchor = self.period_anchor(start, period)?;
+            if until.is_some_and(|u| anchor > u) {
+                return None;
+            }
+            period += 1;
+
+            pending.extend(
+                self.expand_period(start, anchor)
+                    .into_iter()
+                    .filter(|date| *date > start),
+            );
+        })
+    }
+
+    /// Index of the period that contains `date`, so expansion can seek there
+    fn period_containing(&self, start: NaiveDate, date: NaiveDate) -> u32 {
+        if date <= start {
+            return 0;
+        }
+
+        let elapsed = match self.frequency {
+            RecurrenceFrequency::Daily => (date - start).num_days(),
+            RecurrenceFrequency::Weekly | RecurrenceFrequency::Biweekly => {
+                let first = self.period_anchor(start, 0).unwrap_or(start);
+                (date - first).num_days() / 7
+            }
+            RecurrenceFrequency::Monthly => {
+                (date.year() as i64 - start.year() as i64) * 12
+                    + date.month0() as i64
+                    - start.month0() as i64
+            }
+            RecurrenceFrequency::Yearly => date.year() as i64 - start.year() as i64,
+            RecurrenceFrequency::None | RecurrenceFrequency::Custom => 0,
+        };
+
+        u32::try_from(elapsed / self.step() as i64).unwrap_or(u32::MAX)
+    }
+
+    fn step(&self) -> u32 {
+        match self.frequency {
+            RecurrenceFrequency::Biweekly => 2 * self.interval.max(1),
+            _ => self.interval.max(1),
+        }
+    }
+
+    /// First day of the `period`-th period of the rule
+    fn period_anchor(&self, start: NaiveDate, period: u32) -> Option<NaiveDate> {
+        let offset = period.checked_mul(self.step())?;
+
+        match self.frequency {
+            RecurrenceFrequency::Daily => {
+                start.checked_add_signed(Duration::days(offset as i64))
+            }
+            RecurrenceFrequency::Weekly | RecurrenceFrequency::Biweekly => {
+                let week_start = self.week_start.unwrap_or(1) as u32;
+                let back = (weekday_num(start) as u32 + 7 - week_start) % 7;
+                start
+                    .checked_sub_signed(Duration::days(back as i64))?
+                    .checked_add_signed(Duration::weeks(offset as i64))
+            }
+            RecurrenceFrequency::Monthly => {
+                let months = start.month0() + offset;
+                let year = start.year().checked_add((months / 12) as i32)?;
+                NaiveDate::from_ymd_opt(year, months % 12 + 1, 1)
+            }
+            RecurrenceFrequency::Yearly => {
+                NaiveDate::from_ymd_opt(start.year().checked_add(offset as i32)?, 1, 1)
+            }
+            RecurrenceFrequency::None | RecurrenceFrequency::Custom => None,
+        }
+    }
+
+    /// Candidate dates in the period beginning at `anchor`, after BYSETPOS
+    fn expand_period(&self, start: NaiveDate, anchor: NaiveDate) -> Vec<NaiveDate> {
+        let mut dates: Vec<NaiveDate> = match self.frequency {
+            RecurrenceFrequency::Daily => {
+                let weekdays = self.plain_weekdays();
+                Some(anchor)
+                    .filter(|d| self.month_allowed(d.month()))
+                    .filter(|d| weekdays.is_empty() || weekdays.contains(&weekday_num(*d)))
+                    .filter(|d| {
+                        self.by_month_day.is_empty()
+                            || self.by_month_day.iter()
+                                .filter_map(|&md| resolve_month_day(d.year(), d.month(), md))
+                                .any(|md| md == *d)
+                    })
+                    .into_iter()
+                    .collect()
+            }
+            RecurrenceFrequency::Weekly | RecurrenceFrequency::Biweekly => {
+                let mut weekdays = self.plain_weekdays();
+                if weekdays.is_empty() {
+                    weekdays.push(weekday_num(start));
+                }
+                (0..7)
+                    .filter_map(|i| anchor.checked_add_signed(Duration::days(i)))
+                    .filter(|d| weekdays.contains(&weekday_num(*d)))
+                    .filter(|d| self.month_allowed(d.month()))
+                    .collect()
+            }
+            RecurrenceFrequency::Monthly => {
+                if self.month_allowed(anchor.month()) {
+                    self.month_candidates(anchor.year(), anchor.month(), start.day())
+                } else {
+                    Vec::new()
+                }
+            }
+            RecurrenceFrequency::Yearly => self.year_candidates(anchor.year(), start),
+            RecurrenceFrequency::None | RecurrenceFrequency::Custom => Vec::new(),
+        };
+
+        dates.sort();
+        dates.dedup();
+        self.apply_set_pos(dates)
+    }
+
+    fn month_candidates(&self, year: i32, month: u32, default_day: u32) -> Vec<NaiveDate> {
+        if !self.by_month_day.is_empty() {
+            self.by_month_day.iter()
+                .filter_map(|&day| resolve_month_day(year, month, day))
+                .filter(|d| self.matches_weekday_in_month(*d))
+                .collect()
+        } else if self.has_weekday_rule() {
+            (1..=days_in_month(year, month))
+                .filter_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+                .filter(|d| self.matches_weekday_in_month(*d))
+                .collect()
+        } else {
+            NaiveDate::from_ymd_opt(year, month, default_day).into_iter().collect()
+        }
+    }
+
+    fn year_candidates(&self, year: i32, start: NaiveDate) -> Vec<NaiveDate> {
+        if !self.by_month.is_empty() {
+            self.by_month.iter()
+                .flat_map(|&month| self.month_candidates(year, month as u32, start.day()))
+                .collect()
+        } else if !self.by_month_day.is_empty() {
+            (1..=12)
+                .flat_map(|month| self.month_candidates(year, month, start.day()))
+                .collect()
+        } else if self.has_weekday_rule() {
+            let Some(first) = NaiveDate::from_ymd_opt(year, 1, 1) else {
+                return Vec::new();
+            };
+            first.iter_days()
+                .take_while(|d| d.year() == year)
+                .filter(|d| self.matches_weekday_in_year(*d))
+                .collect()
+        } else {
+            NaiveDate::from_ymd_opt(year, start.month(), start.day()).into_iter().collect()
+        }
+    }
+
+    fn apply_set_pos(&self, dates: Vec<NaiveDate>) -> Vec<NaiveDate> {
+        if self.by_set_pos.is_empty() {
+            return dates;
+        }
+
+        let len = dates.len() as i64;
+        let mut selected: Vec<NaiveDate> = self.by_set_pos.iter()
+            .filter_map(|&pos| {
+                let index = if pos > 0 { pos as i64 - 1 } else { len + pos as i64 };
+                (0..len).contains(&index).then(|| dates[index as usize])
+            })
+            .collect();
+        selected.sort();
+        selected.dedup();
+        selected
+    }
+
+    fn has_weekday_rule(&self) -> bool {
+        !self.days_of_week.is_empty() || !self.ordinal_weekdays.is_empty()
+    }
+
+    /// All BYDAY weekdays with ordinals dropped, for DAILY and WEEKLY rules
+    fn plain_weekdays(&self) -> Vec<u8> {
+        self.days_of_week.iter()
+            .copied()
+            .chain(self.ordinal_weekdays.iter().map(|o| o.weekday))
+            .collect()
+    }
+
+    fn month_allowed(&self, month: u32) -> bool {
+        self.by_month.is_empty() || self.by_month.contains(&(month as u8))
+    }
+
+    fn matches_weekday_in_month(&self, date: NaiveDate) -> bool {
+        let last = days_in_month(date.year(), date.month());
+        self.matches_weekday(date, date.day0(), last - date.day())
+    }
+
+    fn matches_weekday_in_year(&self, date: NaiveDate) -> bool {
+        let last = if date.leap_year() { 366 } else { 365 };
+        self.matches_weekday(date, date.ordinal0(), last - date.ordinal())
+    }
+
+    /// `before`/`after` are the number of days in the scope before and after `date`
+    fn matches_weekday(&self, date: NaiveDate, before: u32, after: u32) -> bool {
+        if !self.has_weekday_rule() {
+            return true;
+        }
+
+        let weekday = weekday_num(date);
+        let from_start = (before / 7 + 1) as i8;
+        let from_end = -((after / 7 + 1) as i8);
+
+        self.days_of_week.contains(&weekday)
+            || self.ordinal_weekdays.iter().any(|o| {
+                o.weekday == weekday && (o.ordinal == from_start || o.ordinal == from_end)
+            })
+    }
+}
+
+fn default_interval() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecurrenceFrequency {
+    None,
+    Daily,
+    Weekly,
+    Biweekly,
+    Monthly,
+    Yearly,
+    Custom,
+}
+
+impl RecurrenceFrequency {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RecurrenceFrequency::None => "none",
+            RecurrenceFrequency::Daily => "daily",
+            RecurrenceFrequency::Weekly => "weekly",
+            RecurrenceFrequency::Biweekly => "biweekly",
+            RecurrenceFrequency::Monthly => "monthly",
+            RecurrenceFrequency::Yearly => "yearly",
+            RecurrenceFrequency::Custom => "custom",
+        }
+    }
+}
+
+impl std::str::FromStr for RecurrenceFrequency {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "none" => Ok(RecurrenceFrequency::None),
+            "daily" => Ok(RecurrenceFrequency::Daily),
+            "weekly" => Ok(RecurrenceFrequency::Weekly),
+            "biweekly" => Ok(RecurrenceFrequency::Biweekly),
+            "monthly" => Ok(RecurrenceFrequency::Monthly),
+            "yearly" => Ok(RecurrenceFrequency::Yearly),
+            "custom" => Ok(RecurrenceFrequency::Custom),
+            other => Err(AppError::Validation(format!("Unknown frequency: {}", other))),
+        }
+    }
+}
+
+/// A weekday with a position in its month or year, e.g. "second Tuesday" (2, 2)
+/// or "last Friday" (-1, 5)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdinalWeekday {
+    pub ordinal: i8,
+    pub weekday: u8,
+}
+
+fn weekday_num(date: NaiveDate) -> u8 {
+    date.weekday().num_days_from_sunday() as u8
+}
+
+fn weekday_code(weekday: u8) -> Option<&'static str> {
+    WEEKDAY_CODES.get(weekday as usize).copied()
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|d| d.pred_opt())
+        .map(|d| d.day())
+        .unwrap_or(28)
+}
+
+/// Resolve a BYMONTHDAY value; days that don't exist in the month are skipped
+fn resolve_month_day(year: i32, month: u32, day: i8) -> Option<NaiveDate> {
+    let last = days_in_month(year, month) as i32;
+    let day = if day < 0 { last + day as i32 + 1 } else { day as i32 };
+    if day < 1 || day > last {
+        return None;
+    }
+    NaiveDate::from_ymd_opt(year, month, day as u32)
+}
+
+fn invalid_rrule(message: String) -> AppError {
+    AppError::Validation(format!("Invalid RRULE: {}", message))
+}
+
+fn parse_number<T: std::str::FromStr>(key: &str, value: &str) -> AppResult<T> {
+    value.parse()
+        .map_err(|_| invalid_rrule(format!("{} value '{}' is not a number", key, value)))
+}
+
+/// Parse a comma-separated list of signed values with `min <= |v| <= max`
+fn parse_list<T>(key: &str, value: &str, min: i16, max: i16) -> AppResult<Vec<T>>
+where
+    T: std::str::FromStr + Copy + Into<i16>,
+{
+    value.split(',')
+        .map(|item| {
+            let number: T = parse_number(key, item.trim())?;
+            let magnitude = number.into().abs();
+            if magnitude < min || magnitude > max {
+                return Err(invalid_rrule(format!("{} value {} out of range", key, item.trim())));
+            }
+            Ok(number)
+        })
+        .collect()
+}
+
+fn parse_until(value: &str) -> AppResult<NaiveDate> {
+    value.get(..8)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+        .ok_or_else(|| invalid_rrule(format!("invalid UNTIL '{}'", value)))
+}
+
+fn parse_weekday_code(code: &str) -> AppResult<u8> {
+    WEEKDAY_CODES.iter()
+        .position(|c| c.eq_ignore_ascii_case(code.trim()))
+        .map(|i| i as u8)
+        .ok_or_else(|| invalid_rrule(format!("unknown weekday '{}'", code)))
+}
+
+fn parse_by_day(item: &str) -> AppResult<(Option<i8>, u8)> {
+    let item = item.trim();
+    if item.len() < 2 || !item.is_char_boundary(item.len() - 2) {
+        return Err(invalid_rrule(format!("invalid BYDAY '{}'", item)));
+    }
+
+    let (ordinal, code) = item.split_at(item.len() - 2);
+    let weekday = parse_weekday_code(code)?;
+
+    if ordinal.is_empty() {
+        return Ok((None, weekday));
+    }
+
+    let ordinal: i8 = ordinal.trim_start_matches('+').parse()
+        .map_err(|_| invalid_rrule(format!("invalid BYDAY '{}'", item)))?;
+    if ordinal == 0 || ordinal.abs() > 53 {
+        return Err(invalid_rrule(format!("BYDAY ordinal out of range in '{}'", item)));
+    }
+
+    Ok((Some(ordinal), weekday))
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::date;
+
+    fn expand(rule: &str, start: &str, limit: u32) -> Vec<String> {
+        let rule = RecurrenceConfig::from_rrule(rule).unwrap();
+        strings(rule.generate_occurrences(date(start), Some(limit)))
+    }
+
+    fn strings(dates: Vec<NaiveDate>) -> Vec<String> {
+        dates.into_iter().map(|d| d.to_string()).collect()
+    }
+
+    fn window(rule: &RecurrenceConfig, start: &str, from: &str, to: &str) -> Vec<String> {
+        rule.occurrences_between(date(start), date(from), date(to))
+            .map(|d| d.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_occurrences_between_seeks_into_window() {
+        let rule = RecurrenceConfig::from_rrule("FREQ=DAILY").unwrap();
+        let dates = window(&rule, "2024-01-01", "2026-03-30", "2026-04-02");
+        assert_eq!(dates, vec!["2026-03-30", "2026-03-31", "2026-04-01", "2026-04-02"]);
+
+        let rule = RecurrenceConfig::from_rrule("FREQ=MONTHLY;INTERVAL=5;BYDAY=-1FR").unwrap();
+        let dates = window(&rule, "2020-01-31", "2026-01-01", "2026-12-31");
+        assert_eq!(dates, vec!["2026-04-24", "2026-09-25"]);
+
+        let rule = RecurrenceConfig::from_rrule("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH").unwrap();
+        let dates = window(&rule, "2025-01-02", "2026-01-01", "2026-01-15");
+        assert_eq!(dates, vec!["2026-01-01", "2026-01-12", "2026-01-15"]);
+
+        // Seeking agrees with walking from the start
+        let walked: Vec<_> = strings(rule.generate_occurrences(date("2025-01-02"), Some(200)))
+            .into_iter()
+            .filter(|d| d.as_str() >= "2026-01-01" && d.as_str() <= "2026-01-15")
+            .collect();
+        assert_eq!(dates, walked);
+    }
+
+    #[test]
+    fn test_occurrences_between_respects_bounds() {
+        let mut rule = RecurrenceConfig::from_rrule("FREQ=WEEKLY;COUNT=10").unwrap();
+        rule.except_dates = vec![date("2026-02-24")];
+        let dates = window(&rule, "2026-01-06", "2026-02-20", "2026-12-31");
+        assert_eq!(dates, vec!["2026-03-03", "2026-03-10"]);
+
+        let rule = RecurrenceConfig::from_rrule("FREQ=YEARLY;UNTIL=20280101").unwrap();
+        let dates = window(&rule, "2000-02-29", "2020-01-01", "2040-01-01");
+        assert_eq!(dates, vec!["2020-02-29", "2024-02-29"]);
+
+        // The start date is an instance, but only inside the window
+        let rule = RecurrenceConfig { frequency: RecurrenceFrequency::None, ..Default::default() };
+        assert_eq!(window(&rule, "2026-01-06", "2026-01-01", "2026-01-31").len(), 1);
+        assert_eq!(window(&rule, "2026-01-06", "2026-02-01", "2026-02-28").len(), 0);
+    }
+
+    #[test]
+    fn test_split_at() {
+        let mut rule = RecurrenceConfig::from_rrule("FREQ=WEEKLY;COUNT=6").unwrap();
+        rule.except_dates = vec![date("2026-01-13"), date("2026-01-27")];
+
+        let (head, tail) = rule.split_at(date("2026-01-06"), date("2026-01-20"));
+        assert_eq!(head.occurrences, Some(2));
+        assert_eq!(tail.occurrences, Some(4));
+        assert_eq!(head.except_dates, vec![date("2026-01-13")]);
+        assert_eq!(tail.except_dates, vec![date("2026-01-27")]);
+        assert_eq!(strings(tail.generate_occurrences(date("2026-01-20"), None)), vec!["2026-01-20", "2026-02-03", "2026-02-10"]);
+
+        let rule = RecurrenceConfig::from_rrule("FREQ=DAILY").unwrap();
+        let (head, tail) = rule.split_at(date("2026-01-01"), date("2026-01-10"));
+        assert_eq!(head.end_date, Some(date("2026-01-09")));
+        assert_eq!(tail.end_date, None);
+    }
+
+    #[test]
+    fn test_second_tuesday_of_month() {
+        let dates = expand("FREQ=MONTHLY;BYDAY=TU;BYSETPOS=2", "2026-01-13", 3);
+        assert_eq!(dates, vec!["2026-01-13", "2026-02-10", "2026-03-10"]);
+
+        let dates = expand("FREQ=MONTHLY;BYDAY=2TU", "2026-01-13", 3);
+        assert_eq!(dates, vec!["2026-01-13", "2026-02-10", "2026-03-10"]);
+    }
+
+    #[test]
+    fn test_last_friday_of_month() {
+        let dates = expand("RRULE:FREQ=MONTHLY;BYDAY=-1FR", "2026-01-30", 3);
+        assert_eq!(dates, vec!["2026-01-30", "2026-02-27", "2026-03-27"]);
+    }
+
+    #[test]
+    fn test_last_weekday_of_month_via_setpos() {
+        let dates = expand("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1", "2026-01-30", 3);
+        assert_eq!(dates, vec!["2026-01-30", "2026-02-27", "2026-03-31"]);
+    }
+
+    #[test]
+    fn test_negative_month_day() {
+        let dates = expand("FREQ=MONTHLY;BYMONTHDAY=-1", "2026-01-31", 3);
+        assert_eq!(dates, vec!["2026-01-31", "2026-02-28", "2026-03-31"]);
+    }
+
+    #[test]
+    fn test_monthly_skips_short_months() {
+        let dates = expand("FREQ=MONTHLY", "2026-01-31", 3);
+        assert_eq!(dates, vec!["2026-01-31", "2026-03-31", "2026-05-31"]);
+    }
+
+    #[test]
+    fn test_yearly_by_month() {
+        let dates = expand("FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU", "2026-03-29", 2);
+        assert_eq!(dates, vec!["2026-03-29", "2027-03-28"]);
+    }
+
+    #[test]
+    fn test_week_start_changes_expansion() {
+        // RFC 5545 section 3.3.10 example
+        let monday = expand("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO", "1997-08-05", 10);
+        assert_eq!(monday, vec!["1997-08-05", "1997-08-10", "1997-08-19", "1997-08-24"]);
+
+        let sunday = expand("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU", "1997-08-05", 10);
+        assert_eq!(sunday, vec!["1997-08-05", "1997-08-17", "1997-08-19", "1997-08-31"]);
+    }
+
+    #[test]
+    fn test_count_includes_excluded_dates() {
+        let mut config = RecurrenceConfig::from_rrule("FREQ=DAILY;COUNT=3").unwrap();
+        config.except_dates = vec![date("2026-01-21")];
+
+        let dates = strings(config.generate_occurrences(date("2026-01-20"), None));
+        assert_eq!(dates, vec!["2026-01-20", "2026-01-22"]);
+    }
+
+    #[test]
+    fn test_until_is_inclusive() {
+        let dates = expand("FREQ=WEEKLY;UNTIL=20260203T235959Z", "2026-01-20", 10);
+        assert_eq!(dates, vec!["2026-01-20", "2026-01-27", "2026-02-03"]);
+    }
+
+    #[test]
+    fn test_rrule_roundtrip() {
+        let rules = [
+            "FREQ=MONTHLY;BYDAY=TU;BYSETPOS=2",
+            "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=-1FR",
+            "FREQ=YEARLY;UNTIL=20301231;BYMONTH=1,7;BYMONTHDAY=1,-1",
+            "FREQ=WEEKLY;BYDAY=MO,WE,FR;WKST=SU",
+        ];
+
+        for rule in rules {
+            let config = RecurrenceConfig::from_rrule(rule).unwrap();
+            assert_eq!(config.to_rrule().as_deref(), Some(rule));
+        }
+    }
+
+    #[test]
+    fn test_dates_keep_wire_format() {
+        let mut rule = RecurrenceConfig::from_rrule("FREQ=WEEKLY;UNTIL=20261231").unwrap();
+        rule.except_dates = vec![date("2026-02-10")];
+
+        let json = serde_json::to_value(&rule).unwrap();
+        assert_eq!(json["endDate"], "2026-12-31");
+        assert_eq!(json["exceptDates"], serde_json::json!(["2026-02-10"]));
+        assert_eq!(serde_json::from_value::<RecurrenceConfig>(json).unwrap(), rule);
+
+        let invalid = serde_json::json!({ "frequency": "weekly", "endDate": "2026-02-30" });
+        assert!(serde_json::from_value::<RecurrenceConfig>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_biweekly_serializes_as_weekly_interval() {
+        let config = RecurrenceConfig {
+            frequency: RecurrenceFrequency::Biweekly,
+            ..Default::default()
+        };
+        assert_eq!(config.to_rrule().as_deref(), Some("FREQ=WEEKLY;INTERVAL=2"));
+    }
+
+    #[test]
+    fn test_invalid_rrules_rejected() {
+        assert!(RecurrenceConfig::from_rrule("INTERVAL=2").is_err());
+        assert!(RecurrenceConfig::from_rrule("FREQ=HOURLY").is_err());
+        assert!(RecurrenceConfig::from_rrule("FREQ=DAILY;COUNT=3;UNTIL=20260101").is_err());
+        assert!(RecurrenceConfig::from_rrule("FREQ=MONTHLY;BYMONTHDAY=32").is_err());
+        assert!(RecurrenceConfig::from_rrule("FREQ=MONTHLY;BYDAY=0TU").is_err());
+        assert!(RecurrenceConfig::from_rrule("FREQ=WEEKLY;BYHOUR=9").is_err());
+    }
+}
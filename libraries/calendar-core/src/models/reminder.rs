@@ -0,0 +1,195 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::AppError;
+
+/// One reminder on an event (RFC 5545 `VALARM`).
+///
+/// Fires `minutes_before` the start, or at `at` when an absolute time is
+/// given, then every `repeat_minutes` until `max_reminders` have fired.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderConfig {
+    #[serde(rename = "minutesBefore")]
+    pub minutes_before: u32,
+    #[serde(rename = "repeatMinutes")]
+    pub repeat_minutes: Option<u32>,
+    #[serde(rename = "maxReminders")]
+    pub max_reminders: u32,
+    /// Absolute trigger time; overrides `minutes_before` when set
+    #[serde(default)]
+    pub at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub channel: ReminderChannel,
+}
+
+impl Default for ReminderConfig {
+    fn default() -> Self {
+        Self {
+            minutes_before: 15,
+            repeat_minutes: None,
+            max_reminders: 3,
+            at: None,
+            channel: ReminderChannel::default(),
+        }
+    }
+}
+
+impl ReminderConfig {
+    /// Reminder `minutes` before the event starts
+    pub fn before(minutes: u32) -> Self {
+        Self {
+            minutes_before: minutes,
+            ..Self::default()
+        }
+    }
+
+    /// Reminder at a fixed instant, independent of the event start
+    pub fn at(at: DateTime<Utc>) -> Self {
+        Self {
+            minutes_before: 0,
+            at: Some(at),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_channel(mut self, channel: ReminderChannel) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// First time the reminder fires; relative reminders need the event start
+    pub fn trigger_time(&self, event_start: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+        match self.at {
+            Some(at) => Some(at),
+            None => Some(event_start? - Duration::minutes(self.minutes_before as i64)),
+        }
+    }
+
+    /// Every time the reminder fires, including repeats
+    pub fn fire_times(&self, event_start: Option<DateTime<Utc>>) -> Vec<DateTime<Utc>> {
+        let Some(first) = self.trigger_time(event_start) else {
+            return Vec::new();
+        };
+        match self.repeat_minutes {
+            Some(repeat) if repeat > 0 => (0..self.max_reminders.max(1))
+                .map(|i| first + Duration::minutes(i as i64 * repeat as i64))
+                .collect(),
+            _ => vec![first],
+        }
+    }
+}
+
+/// How a reminder is delivered (RFC 5545 `ACTION`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReminderChannel {
+    /// Desktop notification
+    #[default]
+    Display,
+    /// Notification with sound
+    Audio,
+    /// Email to the attendees; delivered by whoever imports the exported alarm
+    Email,
+}
+
+impl ReminderChannel {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReminderChannel::Display => "display",
+            ReminderChannel::Audio => "audio",
+            ReminderChannel::Email => "email",
+        }
+    }
+
+    /// RFC 5545 `ACTION` value
+    pub fn ical_action(&self) -> &'static str {
+        match self {
+            ReminderChannel::Display => "DISPLAY",
+            ReminderChannel::Audio => "AUDIO",
+            ReminderChannel::Email => "EMAIL",
+        }
+    }
+}
+
+impl std::str::FromStr for ReminderChannel {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "display" | "notification" => Ok(ReminderChannel::Display),
+            "audio" | "sound" => Ok(ReminderChannel::Audio),
+            "email" => Ok(ReminderChannel::Email),
+            other => Err(AppError::Validation(format!("Unknown reminder channel: {}", other))),
+        }
+    }
+}
+
+/// Accept a reminder list, a single reminder object (the old `reminder`
+/// field) or `null`
+pub(crate) fn deserialize_reminders<'de, D>(deserializer: D) -> Result<Vec<ReminderConfig>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        Many(Vec<ReminderConfig>),
+        One(ReminderConfig),
+    }
+
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        Some(OneOrMany::Many(reminders)) => reminders,
+        Some(OneOrMany::One(reminder)) => vec![reminder],
+        None => Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instant(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_fire_times() {
+        let start = Some(instant("2026-03-02T09:00:00Z"));
+
+        assert_eq!(ReminderConfig::before(30).fire_times(start), vec![instant("2026-03-02T08:30:00Z")]);
+
+        let repeating = ReminderConfig {
+            repeat_minutes: Some(5),
+            max_reminders: 3,
+            ..ReminderConfig::before(15)
+        };
+        assert_eq!(repeating.fire_times(start), vec![
+            instant("2026-03-02T08:45:00Z"),
+            instant("2026-03-02T08:50:00Z"),
+            instant("2026-03-02T08:55:00Z"),
+        ]);
+
+        // Absolute reminders do not need the start
+        let friday = ReminderConfig::at(instant("2026-02-27T09:00:00Z"));
+        assert_eq!(friday.fire_times(None), vec![instant("2026-02-27T09:00:00Z")]);
+        assert!(ReminderConfig::before(10).fire_times(None).is_empty());
+    }
+
+    #[test]
+    fn test_legacy_single_reminder_wire_format() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            #[serde(default, alias = "reminder", deserialize_with = "deserialize_reminders")]
+            reminders: Vec<ReminderConfig>,
+        }
+
+        let legacy: Wrapper = serde_json::from_value(serde_json::json!({
+            "reminder": {"minutesBefore": 10, "repeatMinutes": null, "maxReminders": 1}
+        }))
+        .unwrap();
+        assert_eq!(legacy.reminders, vec![ReminderConfig { max_reminders: 1, ..ReminderConfig::before(10) }]);
+
+        let empty: Wrapper = serde_json::from_value(serde_json::json!({"reminder": null})).unwrap();
+        assert!(empty.reminders.is_empty());
+    }
+}
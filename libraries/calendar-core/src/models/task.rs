@@ -0,0 +1,161 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::validation::Validator;
+use crate::{AppError, AppResult};
+use super::{Category, Priority};
+
+/// A to-do with an optional due date (RFC 5545 `VTODO`).
+///
+/// Subtasks are tasks whose `parent_id` points at another task.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Task {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub title: String,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub due_date: Option<NaiveDate>,
+    /// Time of day the task is due; needs `due_date`
+    #[serde(with = "crate::time::serde_hhmm::option", default)]
+    pub due_time: Option<NaiveTime>,
+    #[serde(default)]
+    pub priority: Priority,
+    #[serde(default)]
+    pub category: Category,
+    /// 0-100; 100 once completed
+    #[serde(default)]
+    pub percent_complete: u8,
+    /// When the task was marked done; `None` while open
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Task this one is a subtask of
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl Task {
+    pub fn new(title: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            title: title.into(),
+            notes: None,
+            due_date: None,
+            due_time: None,
+            priority: Priority::default(),
+            category: Category::default(),
+            percent_complete: 0,
+            completed_at: None,
+            parent_id: None,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Subtask of `parent`, inheriting its priority and category
+    pub fn subtask_of(parent: &Task, title: impl Into<String>) -> Self {
+        Self {
+            parent_id: Some(parent.id),
+            priority: parent.priority,
+            category: parent.category.clone(),
+            ..Self::new(title)
+        }
+    }
+
+    pub fn due(mut self, date: NaiveDate) -> Self {
+        self.due_date = Some(date);
+        self
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed_at.is_some()
+    }
+
+    /// Open and due before `today`
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        !self.is_completed() && self.due_date.is_some_and(|due| due < today)
+    }
+
+    /// Mark done at `at`; completing an already completed task keeps the
+    /// original completion time
+    pub fn complete(&mut self, at: DateTime<Utc>) {
+        if self.completed_at.is_none() {
+            self.completed_at = Some(at);
+        }
+        self.percent_complete = 100;
+        self.updated_at = at;
+    }
+
+    pub fn reopen(&mut self) {
+        self.completed_at = None;
+        if self.percent_complete == 100 {
+            self.percent_complete = 0;
+        }
+        self.updated_at = Utc::now();
+    }
+
+    /// Check all fields, collecting every problem into
+    /// [`AppError::InvalidFields`]
+    pub fn validate(&self) -> AppResult<()> {
+        let errors = Validator::validate_task(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::InvalidFields(errors))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::date;
+
+    #[test]
+    fn test_complete_and_reopen() {
+        let mut task = Task::new("File taxes").due(date("2026-04-15"));
+        assert!(task.is_overdue(date("2026-04-16")));
+        assert!(!task.is_overdue(date("2026-04-15")));
+
+        let done_at = Utc::now();
+        task.complete(done_at);
+        task.complete(done_at + chrono::Duration::hours(1));
+        assert_eq!(task.completed_at, Some(done_at));
+        assert_eq!(task.percent_complete, 100);
+        assert!(!task.is_overdue(date("2026-04-16")));
+
+        task.reopen();
+        assert!(!task.is_completed());
+        assert_eq!(task.percent_complete, 0);
+    }
+
+    #[test]
+    fn test_wire_format() {
+        let parent = Task::new("Move house");
+        let task = Task::subtask_of(&parent, "Book van");
+        let json = serde_json::to_value(&task).unwrap();
+
+        assert_eq!(json["parentId"], parent.id.to_string());
+        assert_eq!(json["percentComplete"], 0);
+        assert_eq!(json["dueTime"], serde_json::Value::Null);
+
+        let parsed: Task = serde_json::from_value(serde_json::json!({
+            "id": task.id,
+            "createdAt": task.created_at,
+            "updatedAt": task.updated_at,
+            "title": "Book van",
+            "dueDate": "2026-05-01",
+            "dueTime": "17:00"
+        }))
+        .unwrap();
+        assert_eq!(parsed.due_time, crate::time::parse_hhmm("17:00"));
+        assert_eq!(parsed.priority, Priority::Medium);
+    }
+}
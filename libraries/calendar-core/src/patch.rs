@@ -0,0 +1,159 @@
+//! JSON Merge Patch (RFC 7396) for events, so clients can send just the
+//! fields they changed.
+
+use serde_json::Value;
+use crate::{AppError, AppResult, CalendarEvent};
+use crate::time::TimeZone;
+
+/// Apply `patch` to `target` as described in RFC 7396: objects merge
+/// recursively, `null` removes a member and any other value replaces it
+pub fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(serde_json::Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.as_str()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+/// Fields owned by the store; a patch may repeat their current value but
+/// not change them
+const READ_ONLY_FIELDS: [&str; 2] = ["id", "createdAt"];
+
+/// Fields read as wall-clock values in the zone the patch was written in
+const WALL_CLOCK_FIELDS: [&str; 4] = ["date", "time", "endTime", "endDate"];
+
+impl CalendarEvent {
+    /// Copy of this event with a merge patch applied and the result
+    /// validated. `updated_at` is left for the caller to stamp from its
+    /// clock.
+    ///
+    /// The patch uses the event's wire format (camelCase keys, `HH:MM`
+    /// times). Setting a required field to `null` is an error.
+    pub fn apply_patch(&self, patch: &Value) -> AppResult<Self> {
+        if !patch.is_object() {
+            return Err(AppError::Validation("Patch must be a JSON object".to_string()));
+        }
+
+        let mut document = serde_json::to_value(self)?;
+        for field in READ_ONLY_FIELDS {
+            if patch.get(field).is_some_and(|value| Some(value) != document.get(field)) {
+                return Err(AppError::Validation(format!("Field cannot be patched: {}", field)));
+            }
+        }
+
+        merge_patch(&mut document, patch);
+        let patched: CalendarEvent = serde_json::from_value(document)
+            .map_err(|e| AppError::Validation(format!("Invalid patch: {}", e)))?;
+        patched.validate()?;
+        Ok(patched)
+    }
+
+    /// [`apply_patch`](Self::apply_patch) for a patch written against the
+    /// event as shown in `viewer`: its dates and times are read in that
+    /// zone and stored back in the event's own, unless the patch sets the
+    /// zone itself. Floating events count as being in `viewer`.
+    pub fn apply_patch_in_zone(&self, patch: &Value, viewer: TimeZone) -> AppResult<Self> {
+        let moves = WALL_CLOCK_FIELDS.iter().any(|field| patch.get(field).is_some());
+        if !moves || patch.get("timezone").is_some() {
+            return self.apply_patch(patch);
+        }
+        let own = self.zone(viewer);
+        let patched = self.in_zone(viewer, viewer).apply_patch(patch)?;
+        Ok(patched.in_zone(own, viewer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use crate::testing::{date, time};
+
+    #[test]
+    fn test_rfc7396_examples() {
+        let mut target = json!({"a": "b", "c": {"d": "e", "f": "g"}});
+        merge_patch(&mut target, &json!({"a": "z", "c": {"f": null}}));
+        assert_eq!(target, json!({"a": "z", "c": {"d": "e"}}));
+
+        let mut target = json!({"a": [{"b": "c"}]});
+        merge_patch(&mut target, &json!({"a": [1]}));
+        assert_eq!(target, json!({"a": [1]}));
+
+        let mut target = json!(["a", "b"]);
+        merge_patch(&mut target, &json!({"a": "b", "c": null}));
+        assert_eq!(target, json!({"a": "b"}));
+    }
+
+    #[test]
+    fn test_patch_changes_only_given_fields() {
+        let mut event = CalendarEvent::new("Review".to_string(), date("2026-03-02"));
+        event.notes = Some("Bring slides".to_string());
+        event.tags = vec!["team".to_string()];
+
+        let patched = event
+            .apply_patch(&json!({"time": "10:30", "notes": null, "metadata": {"room": "4B"}}))
+            .unwrap();
+
+        assert_eq!(patched.id, event.id);
+        assert_eq!(patched.event, "Review");
+        assert_eq!(patched.tags, vec!["team"]);
+        assert_eq!(patched.time, crate::time::parse_hhmm("10:30"));
+        assert_eq!(patched.notes, None);
+        assert_eq!(patched.metadata["room"], "4B");
+        assert_eq!(patched.updated_at, event.updated_at);
+    }
+
+    #[test]
+    fn test_patch_rejections() {
+        let event = CalendarEvent::new("Review".to_string(), date("2026-03-02"));
+
+        assert!(event.apply_patch(&json!({"id": uuid::Uuid::new_v4()})).is_err());
+        assert!(event.apply_patch(&json!({"id": event.id})).is_ok());
+        assert!(event.apply_patch(&json!({"event": null})).is_err());
+        assert!(event.apply_patch(&json!({"date": "2026-02-30"})).is_err());
+        assert!(event.apply_patch(&json!({"event": ""})).is_err());
+        assert!(event.apply_patch(&json!(["not", "an", "object"])).is_err());
+    }
+
+    #[test]
+    fn test_patch_from_another_zone() {
+        let tokyo = TimeZone::parse("Asia/Tokyo").unwrap();
+        let new_york = TimeZone::parse("America/New_York").unwrap();
+        let mut event = CalendarEvent::new("Call".to_string(), date("2026-07-02"));
+        event.time = Some(time("09:00"));
+        event.end_time = Some(time("10:00"));
+        event.timezone = Some("Asia/Tokyo".to_string());
+
+        // Shown in New York the evening before; pushed back half an hour there
+        let shown = event.in_zone(new_york, new_york);
+        assert_eq!((shown.date, shown.time), (date("2026-07-01"), Some(time("20:00"))));
+        let patched = event
+            .apply_patch_in_zone(&json!({"time": "20:30", "endTime": "21:30"}), new_york)
+            .unwrap();
+        assert_eq!(patched.timezone.as_deref(), Some("Asia/Tokyo"));
+        assert_eq!((patched.date, patched.time, patched.end_time), (date("2026-07-02"), Some(time("09:30")), Some(time("10:30"))));
+
+        // A whole edited copy goes back the same way
+        assert_eq!(shown.in_zone(tokyo, new_york).start_utc(tokyo), event.start_utc(tokyo));
+        assert_eq!(shown.in_zone(tokyo, new_york).timezone.as_deref(), Some("Asia/Tokyo"));
+
+        // Patches that leave the times alone, or set the zone, apply as-is
+        let renamed = event.apply_patch_in_zone(&json!({"event": "Sync"}), new_york).unwrap();
+        assert_eq!((renamed.date, renamed.time), (event.date, event.time));
+        let rezoned = event
+            .apply_patch_in_zone(&json!({"time": "08:00", "timezone": "UTC"}), new_york)
+            .unwrap();
+        assert_eq!((rezoned.time, rezoned.timezone.as_deref()), (Some(time("08:00")), Some("UTC")));
+    }
+}
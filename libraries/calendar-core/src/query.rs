@@ -0,0 +1,223 @@
+//! Filtering, sorting and paging stored events.
+//!
+//! An [`EventQuery`] describes which events to return; storage translates
+//! it to its own query language. Queries match stored events, so a
+//! recurring series is returned once rather than per occurrence. It matches
+//! a date range when the series starts before the range ends and has not
+//! ended before it starts.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::models::{CalendarEvent, Category, EventStatus, Priority, Visibility};
+use crate::validation::Validator;
+use crate::{AppError, AppResult};
+
+/// Events per page unless a query asks otherwise
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// Largest page a query may ask for
+pub const MAX_PAGE_SIZE: usize = 1000;
+
+/// Order of the events returned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EventSort {
+    /// Earliest first; untimed events before timed ones on the same day
+    #[default]
+    Start,
+    /// Latest first
+    StartDesc,
+    /// Most urgent first, then earliest
+    Priority,
+    /// Most recently changed first
+    Updated,
+    /// Alphabetical by title, ignoring case
+    Title,
+}
+
+impl EventSort {
+    pub fn as_str(&self) -> &str {
+        match self {
+            EventSort::Start => "start",
+            EventSort::StartDesc => "startDesc",
+            EventSort::Priority => "priority",
+            EventSort::Updated => "updated",
+            EventSort::Title => "title",
+        }
+    }
+}
+
+/// Which events to return. Every filter that is set must match; a list
+/// filter matches any of its values, except `tags`, which must all be
+/// present. Events matching any `exclude_` value are left out. Unset
+/// filters match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventQuery {
+    /// First day of the range, inclusive
+    pub start: Option<NaiveDate>,
+    /// Last day of the range, inclusive
+    pub end: Option<NaiveDate>,
+    pub categories: Vec<Category>,
+    pub priorities: Vec<Priority>,
+    pub statuses: Vec<EventStatus>,
+    /// Matched ignoring case
+    pub tags: Vec<String>,
+    pub visibilities: Vec<Visibility>,
+    /// Full-text search over title, notes, tags and location
+    pub text: Option<String>,
+    pub has_reminder: Option<bool>,
+    pub has_location: Option<bool>,
+    pub exclude_categories: Vec<Category>,
+    pub exclude_priorities: Vec<Priority>,
+    pub exclude_statuses: Vec<EventStatus>,
+    pub exclude_tags: Vec<String>,
+    pub exclude_visibilities: Vec<Visibility>,
+    /// Full-text searches whose matches are left out, each on its own
+    pub exclude_text: Vec<String>,
+    pub sort: EventSort,
+    /// Page size; [`DEFAULT_PAGE_SIZE`] when not set
+    pub limit: Option<usize>,
+    /// Events to skip; cannot be combined with `cursor`
+    pub offset: usize,
+    /// `next_cursor` of the previous page, for stable paging while events
+    /// change
+    pub cursor: Option<String>,
+}
+
+/// One page of query results
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventPage {
+    pub events: Vec<CalendarEvent>,
+    /// Events matching the filters across all pages
+    pub total: u64,
+    /// Pass as `cursor` to get the next page; `None` on the last one
+    pub next_cursor: Option<String>,
+}
+
+impl EventQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn between(mut self, start: NaiveDate, end: NaiveDate) -> Self {
+        self.start = Some(start);
+        self.end = Some(end);
+        self
+    }
+
+    pub fn from(mut self, start: NaiveDate) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn until(mut self, end: NaiveDate) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    pub fn category(mut self, category: Category) -> Self {
+        self.categories.push(category);
+        self
+    }
+
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priorities.push(priority);
+        self
+    }
+
+    pub fn status(mut self, status: EventStatus) -> Self {
+        self.statuses.push(status);
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibilities.push(visibility);
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn has_reminder(mut self, has_reminder: bool) -> Self {
+        self.has_reminder = Some(has_reminder);
+        self
+    }
+
+    pub fn has_location(mut self, has_location: bool) -> Self {
+        self.has_location = Some(has_location);
+        self
+    }
+
+    pub fn exclude_category(mut self, category: Category) -> Self {
+        self.exclude_categories.push(category);
+        self
+    }
+
+    pub fn exclude_priority(mut self, priority: Priority) -> Self {
+        self.exclude_priorities.push(priority);
+        self
+    }
+
+    pub fn exclude_status(mut self, status: EventStatus) -> Self {
+        self.exclude_statuses.push(status);
+        self
+    }
+
+    pub fn exclude_tag(mut self, tag: impl Into<String>) -> Self {
+        self.exclude_tags.push(tag.into());
+        self
+    }
+
+    pub fn exclude_visibility(mut self, visibility: Visibility) -> Self {
+        self.exclude_visibilities.push(visibility);
+        self
+    }
+
+    pub fn exclude_text(mut self, text: impl Into<String>) -> Self {
+        self.exclude_text.push(text.into());
+        self
+    }
+
+    pub fn sort(mut self, sort: EventSort) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Page size to use
+    pub fn page_size(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+
+    pub fn validate(&self) -> AppResult<()> {
+        let errors = Validator::validate_event_query(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::InvalidFields(errors))
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! Proposing times for new events.
+//!
+//! Candidates are taken from the free time left by a [`FreeBusyQuery`] and
+//! ranked by how well they fit the request: inside the preferred part of
+//! the day, soon enough for the priority, and snug against existing events
+//! so free time is not cut into unusable pieces.
+
+use chrono::{Duration, NaiveDateTime, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+use crate::availability::{FreeBusyQuery, FreeSlot, TimeRange, WorkingHours};
+use crate::holidays::HolidayCalendar;
+use crate::models::{CalendarEvent, Priority};
+use crate::time::TimeZone;
+
+/// Proposals offered unless a request asks otherwise
+pub const DEFAULT_PROPOSALS: usize = 3;
+
+/// Candidate starts are this far apart, besides the edges of free slots
+const STEP_MINUTES: i64 = 15;
+
+/// Free time shorter than this left next to a proposal counts as wasted
+const USEFUL_GAP_MINUTES: i64 = 30;
+
+/// A new event of `duration_minutes` to fit somewhere between `start` and
+/// `end`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotRequest {
+    pub duration_minutes: u32,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    /// Part of the day to aim for, e.g. mornings
+    #[serde(default)]
+    pub preferred: Option<TimeRange>,
+    /// Urgent requests favour the earliest slots; low priority ones only
+    /// care about fit
+    #[serde(default)]
+    pub priority: Priority,
+    /// Time kept free around existing events
+    #[serde(default)]
+    pub buffer_minutes: u32,
+    #[serde(default)]
+    pub working_hours: Option<WorkingHours>,
+    /// Days off besides weekends without working hours
+    #[serde(default)]
+    pub holidays: Option<HolidayCalendar>,
+}
+
+/// A proposed time; higher scores fit better
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotProposal {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub score: i64,
+}
+
+impl SlotRequest {
+    pub fn new(duration_minutes: u32, start: NaiveDateTime, end: NaiveDateTime) -> Self {
+        Self {
+            duration_minutes,
+            start,
+            end,
+            preferred: None,
+            priority: Priority::default(),
+            buffer_minutes: 0,
+            working_hours: None,
+            holidays: None,
+        }
+    }
+
+    pub fn preferred(mut self, from: NaiveTime, to: NaiveTime) -> Self {
+        self.preferred = Some(TimeRange::new(from, to));
+        self
+    }
+
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn buffer_minutes(mut self, minutes: u32) -> Self {
+        self.buffer_minutes = minutes;
+        self
+    }
+
+    pub fn working_hours(mut self, hours: WorkingHours) -> Self {
+        self.working_hours = Some(hours);
+        self
+    }
+
+    pub fn holidays(mut self, holidays: HolidayCalendar) -> Self {
+        self.holidays = Some(holidays);
+        self
+    }
+
+    /// The free/busy query whose free slots can hold this request
+    pub fn free_busy_query(&self) -> FreeBusyQuery {
+        let mut query = FreeBusyQuery::new(self.start, self.end)
+            .min_minutes(self.duration_minutes)
+            .buffer_minutes(self.buffer_minutes);
+        query.working_hours = self.working_hours.clone();
+        query.holidays = self.holidays.clone();
+        query
+    }
+
+    /// Up to `limit` proposals around `events` seen from `zone`, best first;
+    /// at most one per free slot so the choices are spread out
+    pub fn propose(&self, events: &[CalendarEvent], zone: TimeZone, limit: usize) -> Vec<SlotProposal> {
+        let free = self.free_busy_query().compute(events, zone).free;
+        self.rank(&free, limit)
+    }
+
+    /// Rank candidate times within `free`, best first
+    pub fn rank(&self, free: &[FreeSlot], limit: usize) -> Vec<SlotProposal> {
+        let duration = Duration::minutes(self.duration_minutes.max(1) as i64);
+
+        let mut best_per_slot: Vec<SlotProposal> = free
+            .iter()
+            .filter_map(|slot| {
+                candidate_starts(slot, duration)
+                    .map(|start| SlotProposal {
+                        start,
+                        end: start + duration,
+                        score: self.score(slot, start, start + duration),
+                    })
+                    .max_by_key(|p| (p.score, std::cmp::Reverse(p.start)))
+            })
+            .collect();
+
+        best_per_slot.sort_by_key(|p| (std::cmp::Reverse(p.score), p.start));
+        best_per_slot.truncate(limit);
+        best_per_slot
+    }
+
+    fn score(&self, slot: &FreeSlot, start: NaiveDateTime, end: NaiveDateTime) -> i64 {
+        let mut score = 1000;
+
+        // Preferred part of the day: full marks inside, less the further out
+        if let Some(preferred) = &self.preferred {
+            let minutes = |t: NaiveTime| t.num_seconds_from_midnight() as i64 / 60;
+            let end_minutes = if end.date() > start.date() { 24 * 60 } else { minutes(end.time()) };
+            let outside = (minutes(preferred.start) - minutes(start.time())).max(0)
+                + (end_minutes - minutes(preferred.end)).max(0);
+            score += 300 - outside.min(300);
+        }
+
+        // The more urgent, the more each day of waiting costs
+        let per_day = match self.priority {
+            Priority::Urgent => 200,
+            Priority::High => 100,
+            Priority::Medium => 40,
+            Priority::Low => 0,
+        };
+        score -= per_day * (start - self.start).num_minutes() / (24 * 60);
+
+        // Snug fits keep the rest of the free time in one piece
+        let before = (start - slot.start).num_minutes();
+        let after = (slot.end - end).num_minutes();
+        for gap in [before, after] {
+            if gap == 0 {
+                score += 75;
+            } else if gap < USEFUL_GAP_MINUTES {
+                score -= 50;
+            }
+        }
+
+        score
+    }
+}
+
+/// Starts within `slot` on the quarter hour, plus the ones that fill
+/// either edge exactly
+fn candidate_starts(slot: &FreeSlot, duration: Duration) -> impl Iterator<Item = NaiveDateTime> {
+    let (first, last) = (slot.start, slot.end - duration);
+    let step = Duration::minutes(STEP_MINUTES);
+    let first_aligned = {
+        let past = slot.start.time().num_seconds_from_midnight() as i64 % (STEP_MINUTES * 60);
+        if past == 0 { slot.start } else { slot.start + Duration::seconds(STEP_MINUTES * 60 - past) }
+    };
+
+    let aligned = std::iter::successors(Some(first_aligned), move |t| Some(*t + step))
+        .take_while(move |t| *t <= last);
+    [first, last]
+        .into_iter()
+        .filter(move |t| *t >= first && *t <= last)
+        .chain(aligned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{at, event, time};
+
+    fn starts(proposals: &[SlotProposal]) -> Vec<String> {
+        proposals.iter().map(|p| p.start.format("%a %H:%M").to_string()).collect()
+    }
+
+    /// Monday March 2 to Friday March 6 2026, with meetings on Monday
+    fn week() -> (SlotRequest, Vec<CalendarEvent>) {
+        let request = SlotRequest::new(60, at("2026-03-02 00:00"), at("2026-03-07 00:00"))
+            .working_hours(WorkingHours::default());
+        let events = vec![
+            event("Standup", "2026-03-02 09:00", "2026-03-02 09:30"),
+            event("Planning", "2026-03-02 13:00", "2026-03-02 16:00"),
+        ];
+        (request, events)
+    }
+
+    #[test]
+    fn test_urgent_requests_come_first_and_snug() {
+        let (request, events) = week();
+        let proposals = request.priority(Priority::Urgent).propose(&events, TimeZone::Utc, 3);
+
+        // The hour left after planning fits exactly, then right after standup
+        assert_eq!(starts(&proposals), vec!["Mon 16:00", "Mon 09:30", "Tue 09:00"]);
+        assert!(proposals.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[test]
+    fn test_preferred_time_and_buffers() {
+        let (request, events) = week();
+        let proposals = request
+            .priority(Priority::Low)
+            .preferred(time("14:00"), time("17:00"))
+            .buffer_minutes(15)
+            .propose(&events, TimeZone::Utc, 2);
+
+        // Monday afternoon is too short once the buffer is kept
+        assert_eq!(proposals.len(), 2);
+        assert!(proposals.iter().all(|p| p.start.time() >= time("14:00")));
+        assert!(proposals.iter().all(|p| p.start.date() != at("2026-03-02 00:00").date()));
+    }
+
+    #[test]
+    fn test_holidays_are_skipped() {
+        let (request, events) = week();
+        let mut office = HolidayCalendar::default();
+        office.holidays.push(crate::Holiday::new(
+            "Founders Day",
+            crate::HolidayRule::Fixed { month: 3, day: 3, observed: Default::default() },
+        ));
+        let proposals = request.priority(Priority::Urgent).holidays(office).propose(&events, TimeZone::Utc, 3);
+        assert_eq!(starts(&proposals), vec!["Mon 16:00", "Mon 09:30", "Wed 09:00"]);
+    }
+
+    #[test]
+    fn test_no_room() {
+        let (request, events) = week();
+        let request = SlotRequest { duration_minutes: 5 * 60, ..request };
+        assert!(request.propose(&events, TimeZone::Utc, 3).is_empty());
+    }
+}
@@ -0,0 +1,419 @@
+//! Searching events: the query language users type and the results.
+//!
+//! A search is a list of words and `field:value` filters, e.g.
+//! `cat:work pri:>=high tag:review after:2026-01-01 -status:cancelled standup`.
+//! Words and `"quoted phrases"` are searched as text; `prefix*` matches the
+//! start of a word. A leading `-` leaves out what a term matches.
+//!
+//! | Filter | Values |
+//! |---|---|
+//! | `cat:` / `category:` | category names, comma separated |
+//! | `pri:` / `priority:` | priority names, optionally `>=`, `<=`, `>` or `<` one |
+//! | `status:` | `tentative`, `confirmed`, `cancelled`, `completed` |
+//! | `tag:` | a tag; several `tag:` filters must all match |
+//! | `vis:` / `visibility:` | `public`, `private` |
+//! | `has:` | `reminder`, `location` |
+//! | `after:` / `before:` / `on:` | a date, e.g. `2026-01-01`, `tomorrow` or `"next friday"`; `after:` includes the day, `before:` does not |
+//! | `sort:` | `start`, `startDesc`, `priority`, `updated`, `title` |
+
+use std::fmt;
+use std::ops::Range;
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use crate::models::{CalendarEvent, Category, EventStatus, Priority, Visibility};
+use crate::query::{EventQuery, EventSort};
+use crate::time::parse_natural_date;
+use crate::AppError;
+
+/// Results returned unless a search asks otherwise
+pub const DEFAULT_SEARCH_LIMIT: usize = 50;
+
+/// An event matching a search, best matches first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    pub event: CalendarEvent,
+    /// Relevance; higher is better and only comparable within one search
+    pub score: f64,
+    /// Excerpt of the best matching field, split so matched terms can be
+    /// highlighted however the frontend likes
+    pub snippet: Vec<SnippetPart>,
+}
+
+/// A run of snippet text, either matched by the query or not
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnippetPart {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+impl SearchHit {
+    /// The snippet with matched terms wrapped in `open` and `close`
+    pub fn highlighted(&self, open: &str, close: &str) -> String {
+        self.snippet
+            .iter()
+            .map(|part| {
+                if part.highlighted {
+                    format!("{}{}{}", open, part.text, close)
+                } else {
+                    part.text.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+/// A search that cannot be understood, pointing at the text responsible
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryError {
+    pub message: String,
+    /// Byte range of the offending text in the search
+    pub start: usize,
+    pub end: usize,
+    /// Character position of `start`, counting from 1
+    pub column: usize,
+}
+
+impl QueryError {
+    fn new(message: impl Into<String>, range: Range<usize>, input: &str) -> Self {
+        Self {
+            message: message.into(),
+            start: range.start,
+            end: range.end,
+            column: input[..range.start].chars().count() + 1,
+        }
+    }
+
+    /// The search with the offending text underlined, for terminals
+    pub fn pointer(&self, input: &str) -> String {
+        let width = input[self.start..self.end].chars().count().max(1);
+        format!("{}\n{}{}", input, " ".repeat(self.column - 1), "^".repeat(width))
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (column {})", self.message, self.column)
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+impl From<QueryError> for AppError {
+    fn from(error: QueryError) -> Self {
+        AppError::Validation(error.to_string())
+    }
+}
+
+const FILTERS: &str = "cat:, pri:, status:, tag:, vis:, has:, after:, before:, on: or sort:";
+
+/// Parse what a user typed into a search; relative dates count from `today`
+pub fn parse_query(input: &str, today: NaiveDate) -> Result<EventQuery, QueryError> {
+    let mut query = EventQuery::new();
+    let mut words = Vec::new();
+
+    for token in tokens(input)? {
+        let (negated, body, body_start) = match token.text.strip_prefix('-') {
+            Some(rest) if !rest.is_empty() => (true, rest, token.start + 1),
+            _ => (false, token.text, token.start),
+        };
+        let token_range = token.start..token.start + token.text.len();
+
+        // `key:value`, where the key is plain letters; anything else is text
+        let Some(colon) = body.find(':').filter(|&i| i > 0 && body[..i].chars().all(|c| c.is_ascii_alphabetic())) else {
+            if negated {
+                query.exclude_text.push(body.to_string());
+            } else {
+                words.push(body);
+            }
+            continue;
+        };
+        let key = body[..colon].to_lowercase();
+        let key_range = body_start..body_start + colon + 1;
+        let (value, value_start) = unquote(&body[colon + 1..], body_start + colon + 1);
+        if value.trim().is_empty() {
+            return Err(QueryError::new(format!("Missing value after \"{}:\"", key), token_range, input));
+        }
+        let values = || split_values(value, value_start);
+        let invalid = |message: String, range: Range<usize>| QueryError::new(message, range, input);
+
+        match key.as_str() {
+            "cat" | "category" => {
+                for (name, range) in values() {
+                    let category: Category = name
+                        .parse()
+                        .map_err(|_| invalid(format!("\"{}\" is not a valid category name", name), range))?;
+                    if negated {
+                        query.exclude_categories.push(category);
+                    } else {
+                        query.categories.push(category);
+                    }
+                }
+            }
+            "pri" | "priority" => {
+                for (text, range) in values() {
+                    let priorities = priorities_matching(text).ok_or_else(|| {
+                        invalid(format!("Unknown priority \"{}\"; expected low, medium, high or urgent", text), range.clone())
+                    })?;
+                    if priorities.is_empty() {
+                        return Err(invalid(format!("No priority matches \"{}\"", text), range));
+                    }
+                    if negated {
+                        query.exclude_priorities.extend(priorities);
+                    } else {
+                        query.priorities.extend(priorities);
+                    }
+                }
+            }
+            "status" => {
+                for (text, range) in values() {
+                    let status: EventStatus = text.parse().map_err(|_| {
+                        invalid(
+                            format!("Unknown status \"{}\"; expected tentative, confirmed, cancelled or completed", text),
+                            range,
+                        )
+                    })?;
+                    if negated {
+                        query.exclude_statuses.push(status);
+                    } else {
+                        query.statuses.push(status);
+                    }
+                }
+            }
+            "vis" | "visibility" => {
+                for (text, range) in values() {
+                    let visibility: Visibility = text.parse().map_err(|_| {
+                        invalid(format!("Unknown visibility \"{}\"; expected public or private", text), range)
+                    })?;
+                    if negated {
+                        query.exclude_visibilities.push(visibility);
+                    } else {
+                        query.visibilities.push(visibility);
+                    }
+                }
+            }
+            "tag" => {
+                if negated {
+                    query.exclude_tags.push(value.trim().to_string());
+                } else {
+                    query.tags.push(value.trim().to_string());
+                }
+            }
+            "has" => {
+                for (text, range) in values() {
+                    match text.to_lowercase().as_str() {
+                        "reminder" | "reminders" => query.has_reminder = Some(!negated),
+                        "location" => query.has_location = Some(!negated),
+                        _ => {
+                            return Err(invalid(
+                                format!("Unknown \"has:\" value \"{}\"; expected reminder or location", text),
+                                range,
+                            ))
+                        }
+                    }
+                }
+            }
+            "after" | "before" | "on" | "sort" if negated => {
+                return Err(invalid(format!("\"{}:\" cannot be negated", key), token_range));
+            }
+            "after" | "before" | "on" => {
+                let range = value_start..value_start + value.len();
+                let date = parse_date(value, today)
+                    .ok_or_else(|| invalid(format!("Cannot read \"{}\" as a date", value), range))?;
+                if key != "before" {
+                    query.start = Some(query.start.map_or(date, |start| start.max(date)));
+                }
+                if key != "after" {
+                    let last = if key == "before" { date - Duration::days(1) } else { date };
+                    query.end = Some(query.end.map_or(last, |end| end.min(last)));
+                }
+            }
+            "sort" => {
+                let range = value_start..value_start + value.len();
+                query.sort = [EventSort::Start, EventSort::StartDesc, EventSort::Priority, EventSort::Updated, EventSort::Title]
+                    .into_iter()
+                    .find(|sort| sort.as_str().eq_ignore_ascii_case(value.trim()))
+                    .ok_or_else(|| {
+                        invalid(
+                            format!("Unknown sort \"{}\"; expected start, startDesc, priority, updated or title", value),
+                            range,
+                        )
+                    })?;
+            }
+            _ => {
+                return Err(invalid(format!("Unknown filter \"{}:\"; try {}", key, FILTERS), key_range));
+            }
+        }
+    }
+
+    if !words.is_empty() {
+        query.text = Some(words.join(" "));
+    }
+    Ok(query)
+}
+
+/// A whitespace-separated piece of the search; quotes keep spaces inside it
+struct Token<'a> {
+    text: &'a str,
+    start: usize,
+}
+
+fn tokens(input: &str) -> Result<Vec<Token<'_>>, QueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let mut open_quote = None;
+        let mut end = input.len();
+        while let Some(&(i, c)) = chars.peek() {
+            if c == '"' {
+                open_quote = if open_quote.is_some() { None } else { Some(i) };
+            } else if c.is_whitespace() && open_quote.is_none() {
+                end = i;
+                break;
+            }
+            chars.next();
+        }
+        if let Some(quote) = open_quote {
+            return Err(QueryError::new("Missing closing quote", quote..quote + 1, input));
+        }
+        tokens.push(Token { text: &input[start..end], start });
+    }
+
+    Ok(tokens)
+}
+
+/// `value` without surrounding quotes, and where it starts
+fn unquote(value: &str, start: usize) -> (&str, usize) {
+    match value.strip_prefix('"').and_then(|inner| inner.strip_suffix('"')) {
+        Some(inner) => (inner, start + 1),
+        None => (value, start),
+    }
+}
+
+/// Comma-separated values and their byte ranges; empty ones are skipped
+fn split_values(value: &str, start: usize) -> Vec<(&str, Range<usize>)> {
+    let mut values = Vec::new();
+    let mut offset = 0;
+    for part in value.split(',') {
+        let trimmed = part.trim();
+        if !trimmed.is_empty() {
+            let lead = part.len() - part.trim_start().len();
+            let from = start + offset + lead;
+            values.push((trimmed, from..from + trimmed.len()));
+        }
+        offset += part.len() + 1;
+    }
+    values
+}
+
+/// Priorities matching `high`, `>=high`, `<medium` and so on; `None` when
+/// the name is not a priority
+fn priorities_matching(text: &str) -> Option<Vec<Priority>> {
+    let (compare, name): (fn(u8, u8) -> bool, &str) = if let Some(name) = text.strip_prefix(">=") {
+        (|level, bound| level >= bound, name)
+    } else if let Some(name) = text.strip_prefix("<=") {
+        (|level, bound| level <= bound, name)
+    } else if let Some(name) = text.strip_prefix('>') {
+        (|level, bound| level > bound, name)
+    } else if let Some(name) = text.strip_prefix('<') {
+        (|level, bound| level < bound, name)
+    } else {
+        (|level, bound| level == bound, text.strip_prefix('=').unwrap_or(text))
+    };
+    let bound: Priority = name.parse().ok()?;
+    Some(
+        [Priority::Low, Priority::Medium, Priority::High, Priority::Urgent]
+            .into_iter()
+            .filter(|p| compare(p.level(), bound.level()))
+            .collect(),
+    )
+}
+
+fn parse_date(value: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let value = value.trim();
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .or_else(|| parse_natural_date(value, today))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::date;
+
+    fn today() -> NaiveDate {
+        // A Wednesday
+        NaiveDate::from_ymd_opt(2026, 3, 4).unwrap()
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let query = parse_query(
+            "cat:work pri:>=high tag:review after:2026-01-01 -status:cancelled standup",
+            today(),
+        )
+        .unwrap();
+        assert_eq!(query, EventQuery::new()
+            .category(Category::Work)
+            .priority(Priority::High)
+            .priority(Priority::Urgent)
+            .tag("review")
+            .from(date("2026-01-01"))
+            .exclude_status(EventStatus::Cancelled)
+            .text("standup"));
+
+        let query = parse_query(
+            r#"cat:health,personal tag:"team offsite" -has:location before:tomorrow "weekly sync" stand* -draft sort:title"#,
+            today(),
+        )
+        .unwrap();
+        assert_eq!(query.categories, vec![Category::Health, Category::Personal]);
+        assert_eq!(query.tags, vec!["team offsite"]);
+        assert_eq!(query.has_location, Some(false));
+        assert_eq!(query.end, Some(today()));
+        assert_eq!(query.text.as_deref(), Some(r#""weekly sync" stand*"#));
+        assert_eq!(query.exclude_text, vec!["draft"]);
+        assert_eq!(query.sort, EventSort::Title);
+
+        let query = parse_query(r#"on:"next friday" pri:<medium"#, today()).unwrap();
+        assert_eq!((query.start, query.end), (Some(date("2026-03-06")), Some(date("2026-03-06"))));
+        assert_eq!(query.priorities, vec![Priority::Low]);
+
+        // Times and other colons are just text
+        assert_eq!(parse_query("10:30 call", today()).unwrap().text.as_deref(), Some("10:30 call"));
+        assert_eq!(parse_query("", today()).unwrap(), EventQuery::new());
+    }
+
+    #[test]
+    fn test_errors_point_at_the_token() {
+        let input = "cat:work pri:hgh standup";
+        let error = parse_query(input, today()).unwrap_err();
+        assert_eq!(error.message, "Unknown priority \"hgh\"; expected low, medium, high or urgent");
+        assert_eq!(&input[error.start..error.end], "hgh");
+        assert_eq!(error.to_string(), "Unknown priority \"hgh\"; expected low, medium, high or urgent (column 14)");
+        assert_eq!(error.pointer(input), "cat:work pri:hgh standup\n             ^^^");
+
+        let error = parse_query("standup colour:red", today()).unwrap_err();
+        assert!(error.message.starts_with("Unknown filter \"colour:\""));
+        assert_eq!((error.start, error.end), (8, 15));
+
+        let input = "cat:work,Not!Valid";
+        let error = parse_query(input, today()).unwrap_err();
+        assert_eq!(&input[error.start..error.end], "Not!Valid");
+
+        let input = "after:someday";
+        assert_eq!(&input[parse_query(input, today()).unwrap_err().start..], "someday");
+        assert_eq!(parse_query("-after:today", today()).unwrap_err().message, "\"after:\" cannot be negated");
+        assert_eq!(parse_query("tag:", today()).unwrap_err().message, "Missing value after \"tag:\"");
+        assert_eq!(parse_query("pri:>urgent", today()).unwrap_err().message, "No priority matches \">urgent\"");
+
+        let error = parse_query(r#"review "weekly sync"#, today()).unwrap_err();
+        assert_eq!((error.message.as_str(), error.column), ("Missing closing quote", 8));
+    }
+}
@@ -0,0 +1,30 @@
+//! Shorthand for building dates and times in tests. Other crates get it by
+//! enabling the `testing` feature as a dev-dependency.
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::models::CalendarEvent;
+
+/// Parse a `YYYY-MM-DD` date, panicking if it is invalid
+pub fn date(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+}
+
+/// Parse an `HH:MM` time, panicking if it is invalid
+pub fn time(s: &str) -> NaiveTime {
+    crate::time::parse_hhmm(s).unwrap()
+}
+
+/// Parse a `YYYY-MM-DD HH:MM` date and time, panicking if it is invalid
+pub fn at(s: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+}
+
+/// A timed event from `start` to `end`, both `YYYY-MM-DD HH:MM` on the
+/// same day
+pub fn event(title: &str, start: &str, end: &str) -> CalendarEvent {
+    let start = at(start);
+    CalendarEvent::builder(title, start.date())
+        .time_range(start.time(), at(end).time())
+        .build()
+        .unwrap()
+}
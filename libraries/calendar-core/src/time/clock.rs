@@ -0,0 +1,111 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use super::TimeZone;
+
+/// Source of the current time. Code that needs "now" or "today" takes a
+/// clock instead of asking the system, so tests can pin the date and the
+/// widget can pretend to run on another day.
+pub trait Clock: Debug + Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Current date in `zone`
+    fn today_in(&self, zone: TimeZone) -> NaiveDate {
+        zone.from_utc(self.now()).date()
+    }
+
+    /// Current date in the local zone
+    fn today(&self) -> NaiveDate {
+        self.today_in(TimeZone::Local)
+    }
+}
+
+/// Clock shared between services
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The system clock, shared
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+/// The real time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock stopped at one instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl FixedClock {
+    /// Stopped at local noon on `date`, so `today()` is `date` whatever
+    /// the local zone
+    pub fn on_date(date: NaiveDate) -> Self {
+        let noon = date.and_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap_or_default());
+        Self(TimeZone::Local.localize(noon).unwrap_or_else(|| noon.and_utc()))
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// The real time moved by a fixed offset: time keeps running, starting
+/// from another moment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetClock {
+    offset: Duration,
+}
+
+impl OffsetClock {
+    pub fn starting_at(instant: DateTime<Utc>) -> Self {
+        Self { offset: instant - Utc::now() }
+    }
+
+    /// Pretend today is `date` in `zone`, keeping the current time of day
+    pub fn starting_on(date: NaiveDate, zone: TimeZone) -> Self {
+        let now = Utc::now();
+        let shifted = zone.localize(date.and_time(zone.from_utc(now).time()));
+        Self { offset: shifted.map(|at| at - now).unwrap_or_else(Duration::zero) }
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::date;
+
+    #[test]
+    fn test_fixed_clock_dates() {
+        let clock = FixedClock::on_date(date("2026-03-01"));
+        assert_eq!(clock.today(), date("2026-03-01"));
+
+        let clock = FixedClock("2026-03-01T23:30:00Z".parse().unwrap());
+        assert_eq!(clock.today_in(TimeZone::Utc), date("2026-03-01"));
+        assert_eq!(clock.today_in(TimeZone::parse("Asia/Tokyo").unwrap()), date("2026-03-02"));
+    }
+
+    #[test]
+    fn test_offset_clock_keeps_running() {
+        let clock = OffsetClock::starting_on(date("2026-03-01"), TimeZone::Utc);
+        assert_eq!(clock.today_in(TimeZone::Utc), date("2026-03-01"));
+
+        let start = "2026-03-01T09:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let clock = OffsetClock::starting_at(start);
+        let elapsed = clock.now() - start;
+        assert!(elapsed >= Duration::zero() && elapsed < Duration::seconds(5));
+    }
+}
@@ -1,41 +1,27 @@
-use chrono::{DateTime, Utc, Local, NaiveDate, NaiveTime};
+use chrono::{DateTime, Duration, LocalResult, NaiveDateTime, Offset, Utc, Local, NaiveDate, NaiveTime};
+use crate::{AppError, AppResult};
 use once_cell::sync::Lazy;
 
-pub mod prelude {
-    pub use super::{TimeParser, TimeZone, now_utc, today, format_date, parse_date_string};
-}
+mod clock;
+mod natural;
 
-static RELATIVE_DATE_PATTERN: Lazy<regex::Regex> = 
-    Lazy::new(|| regex::Regex::new(r"^(today|tomorrow|yesterday|this week|this month|next week|next month)$").unwrap());
+pub use clock::{Clock, FixedClock, OffsetClock, SharedClock, SystemClock, system_clock};
+pub use natural::{DateMatch, find_natural_date, find_natural_date_in, parse_natural_date, parse_natural_date_in};
+pub(crate) use natural::nth_weekday;
 
-static DAY_OF_WEEK_PATTERN: Lazy<regex::Regex> = 
-    Lazy::new(|| regex::Regex::new(r"^(monday|tuesday|wednesday|thursday|friday|saturday|sunday)$").unwrap());
+pub mod prelude {
+    pub use super::{TimeParser, TimeZone, now_utc, today, format_date, parse_hhmm};
+    pub use super::{Clock, SharedClock, SystemClock, FixedClock};
+    pub use super::{find_natural_date, parse_natural_date};
+}
 
 pub struct TimeParser;
 
 impl TimeParser {
-    pub fn parse_relative_date(input: &str) -> Option<NaiveDate> {
-        let normalized = input.to_lowercase().trim().to_string();
-        let today = Local::now().date_naive();
-
-        if normalized == "today" {
-            Some(today)
-        } else if normalized == "tomorrow" {
-            Some(today.succ_opt()?)
-        } else if normalized == "yesterday" {
-            Some(today.pred_opt()?)
-        } else if normalized.starts_with("next ") {
-            let day_name = &normalized[5..];
-            Self::parse_day_of_week(day_name).map(|d| {
-                let mut date = today;
-                while date.weekday() != d {
-                    date = date.succ_opt().unwrap();
-                }
-                date
-            })
-        } else {
-            None
-        }
+    /// Resolve a date expression such as "tomorrow", "next friday" or
+    /// "the 15th" against `reference`; see [`parse_natural_date`]
+    pub fn parse_relative_date(input: &str, reference: NaiveDate) -> Option<NaiveDate> {
+        parse_natural_date(input, reference)
     }
 
     pub fn parse_day_of_week(input: &str) -> Option<chrono::Weekday> {
@@ -56,58 +42,211 @@ impl TimeParser {
             .or(NaiveTime::parse_from_str(input, "%I:%M %p").ok())
     }
 
-    pub fn parse_date_string(input: &str) -> Option<String> {
-        // Try ISO format first
+    /// `YYYY-MM-DD` for an ISO or natural-language date relative to the
+    /// clock's today. An ISO date that is not on the calendar, such as
+    /// `2026-02-30`, is `None`.
+    pub fn parse_date_string(input: &str, clock: &dyn Clock) -> Option<String> {
         if DATE_PATTERN.is_match(input) {
-            return Some(input.to_string());
-        }
-
-        // Try relative dates
-        if let Some(date) = Self::parse_relative_date(input) {
-            return Some(date.format("%Y-%m-%d").to_string());
+            return NaiveDate::parse_from_str(input, "%Y-%m-%d").ok().map(|date| format_date(&date));
         }
 
-        // Try "next [day]"
-        if let Some(rest) = input.strip_prefix("next ") {
-            if let Some(weekday) = Self::parse_day_of_week(rest) {
-                let today = Local::now().date_naive();
-                let mut date = today;
-                while date.weekday() != weekday {
-                    date = date.succ_opt().unwrap();
-                }
-                return Some(date.format("%Y-%m-%d").to_string());
-            }
-        }
-
-        None
+        Self::parse_relative_date(input, clock.today()).map(|date| format_date(&date))
     }
 }
 
-fn now_utc() -> DateTime<Utc> {
-    Utc::now()
+/// Now on the system clock; code that should be testable takes a [`Clock`]
+pub fn now_utc() -> DateTime<Utc> {
+    SystemClock.now()
 }
 
-fn today() -> NaiveDate {
-    Local::now().date_naive()
+/// Today on the system clock; code that should be testable takes a [`Clock`]
+pub fn today() -> NaiveDate {
+    SystemClock.today()
 }
 
-fn format_date(date: &NaiveDate) -> String {
+pub fn format_date(date: &NaiveDate) -> String {
     date.format("%Y-%m-%d").to_string()
 }
 
+/// Parse an `HH:MM` wall-clock time, also accepting `HH:MM:SS`
+pub fn parse_hhmm(input: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(input, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(input, "%H:%M:%S"))
+        .ok()
+}
+
+/// Serde adapter that keeps event times on the wire as `HH:MM`
+pub mod serde_hhmm {
+    use chrono::NaiveTime;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&time.format("%H:%M"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveTime, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        super::parse_hhmm(&value)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid time '{}', expected HH:MM", value)))
+    }
+
+    /// The same format for optional times
+    pub mod option {
+        use chrono::NaiveTime;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(time: &Option<NaiveTime>, serializer: S) -> Result<S::Ok, S::Error> {
+            match time {
+                Some(time) => super::serialize(time, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<NaiveTime>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|value| {
+                    super::super::parse_hhmm(&value).ok_or_else(|| {
+                        serde::de::Error::custom(format!("invalid time '{}', expected HH:MM", value))
+                    })
+                })
+                .transpose()
+        }
+    }
+}
+
 static DATE_PATTERN: Lazy<regex::Regex> = 
     Lazy::new(|| regex::Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap());
 
+/// Zone an event's wall-clock date and time are interpreted in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TimeZone {
     Local,
     Utc,
+    /// An IANA zone such as `America/New_York`
+    Named(chrono_tz::Tz),
 }
 
 impl TimeZone {
-    pub fn now(&self) -> DateTime<chrono::FixedOffset> {
+    /// Parse `local`, `UTC` or an IANA zone name
+    pub fn parse(name: &str) -> AppResult<Self> {
+        let name = name.trim();
+        if name.is_empty() || name.eq_ignore_ascii_case("local") {
+            Ok(TimeZone::Local)
+        } else if name.eq_ignore_ascii_case("utc") || name == "Z" {
+            Ok(TimeZone::Utc)
+        } else {
+            name.parse::<chrono_tz::Tz>()
+                .map(TimeZone::Named)
+                .map_err(|_| AppError::Validation(format!("Unknown time zone: {}", name)))
+        }
+    }
+
+    /// IANA identifier for `TZID` parameters; `None` for the local zone
+    pub fn tzid(&self) -> Option<&'static str> {
         match self {
-            TimeZone::Local => Local::now().fixed_offset(),
-            TimeZone::Utc => Utc::now().fixed_offset(),
+            TimeZone::Local => None,
+            TimeZone::Utc => Some("UTC"),
+            TimeZone::Named(tz) => Some(tz.name()),
         }
     }
+
+    /// Current time by `clock`, with this zone's offset
+    pub fn now(&self, clock: &dyn Clock) -> DateTime<chrono::FixedOffset> {
+        let now = clock.now();
+        match self {
+            TimeZone::Local => now.with_timezone(&Local).fixed_offset(),
+            TimeZone::Utc => now.fixed_offset(),
+            TimeZone::Named(tz) => now.with_timezone(tz).fixed_offset(),
+        }
+    }
+
+    /// Resolve a wall-clock time in this zone to an instant.
+    ///
+    /// Times repeated by a DST fall-back resolve to the earlier instant;
+    /// times skipped by a spring-forward are shifted by the size of the gap.
+    pub fn localize(&self, naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self {
+            TimeZone::Local => resolve(&Local, naive),
+            TimeZone::Utc => Some(naive.and_utc()),
+            TimeZone::Named(tz) => resolve(tz, naive),
+        }
+    }
+
+    /// Wall-clock time in this zone for an instant
+    pub fn from_utc(&self, instant: DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            TimeZone::Local => instant.with_timezone(&Local).naive_local(),
+            TimeZone::Utc => instant.naive_utc(),
+            TimeZone::Named(tz) => instant.with_timezone(tz).naive_local(),
+        }
+    }
+}
+
+impl std::str::FromStr for TimeZone {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+fn resolve<Tz: chrono::TimeZone>(tz: &Tz, naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => Some(dt.with_timezone(&Utc)),
+        LocalResult::Ambiguous(earliest, _) => Some(earliest.with_timezone(&Utc)),
+        LocalResult::None => {
+            // Inside a DST gap: apply the offset in force just before it
+            let before = tz.from_local_datetime(&(naive - Duration::days(1))).earliest()?;
+            let offset = before.offset().fix();
+            Some((naive - offset).and_utc())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::at;
+
+    #[test]
+    fn test_now_in_zone_follows_clock() {
+        let clock = FixedClock(at("2026-07-01 12:00").and_utc());
+        let tokyo = TimeZone::parse("Asia/Tokyo").unwrap();
+        assert_eq!(tokyo.now(&clock).to_rfc3339(), "2026-07-01T21:00:00+09:00");
+        assert_eq!(TimeZone::Utc.now(&clock).to_rfc3339(), "2026-07-01T12:00:00+00:00");
+    }
+
+    #[test]
+    fn test_parse_date_string() {
+        let clock = FixedClock::on_date(at("2026-03-01 00:00").date());
+        assert_eq!(TimeParser::parse_date_string("2026-03-02", &clock).as_deref(), Some("2026-03-02"));
+        assert_eq!(TimeParser::parse_date_string("tomorrow", &clock).as_deref(), Some("2026-03-02"));
+        assert_eq!(TimeParser::parse_date_string("2026-99-99", &clock), None);
+        assert_eq!(TimeParser::parse_date_string("2026-02-30", &clock), None);
+    }
+
+    #[test]
+    fn test_parse_zones() {
+        assert_eq!(TimeZone::parse("local").unwrap(), TimeZone::Local);
+        assert_eq!(TimeZone::parse("UTC").unwrap(), TimeZone::Utc);
+        assert_eq!(TimeZone::parse("Europe/Berlin").unwrap().tzid(), Some("Europe/Berlin"));
+        assert!(TimeZone::parse("Mars/Olympus").is_err());
+    }
+
+    #[test]
+    fn test_localize_across_dst() {
+        let ny = TimeZone::parse("America/New_York").unwrap();
+        assert_eq!(ny.localize(at("2026-03-02 09:00")).unwrap().to_rfc3339(), "2026-03-02T14:00:00+00:00");
+        assert_eq!(ny.localize(at("2026-03-09 09:00")).unwrap().to_rfc3339(), "2026-03-09T13:00:00+00:00");
+        assert_eq!(ny.from_utc(ny.localize(at("2026-03-09 09:00")).unwrap()), at("2026-03-09 09:00"));
+    }
+
+    #[test]
+    fn test_localize_gap_and_fold() {
+        let ny = TimeZone::parse("America/New_York").unwrap();
+        // 02:30 does not exist on 2026-03-08; it lands at 03:30 EDT
+        assert_eq!(ny.localize(at("2026-03-08 02:30")).unwrap().to_rfc3339(), "2026-03-08T07:30:00+00:00");
+        // 01:30 happens twice on 2026-11-01; the EDT instance comes first
+        assert_eq!(ny.localize(at("2026-11-01 01:30")).unwrap().to_rfc3339(), "2026-11-01T05:30:00+00:00");
+    }
 }
@@ -0,0 +1,587 @@
+//! Natural-language dates ("in 3 days", "the 15th", "a week from Friday")
+//! resolved against a reference date.
+//!
+//! Business days ("3 business days after the 20th") skip weekends and the
+//! holidays of a [`HolidayCalendar`], whose holiday names ("Thanksgiving")
+//! are understood as dates too.
+//!
+//! Dates without a year ("March 3", "3/4", "the 15th") resolve to their
+//! next occurrence on or after the reference date. Numeric dates are read
+//! month first.
+
+use std::ops::Range;
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use crate::holidays::HolidayCalendar;
+
+const WEEKDAY: &str = "(monday|tuesday|wednesday|thursday|friday|saturday|sunday)";
+const MONTH: &str =
+    "(january|february|march|april|may|june|july|august|september|october|november|december|jan|feb|mar|apr|jun|jul|aug|sept|sep|oct|nov|dec)";
+const NUMBER: &str = r"(\d{1,4}|a|an|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)";
+const UNIT: &str = "(day|week|month|year)s?";
+const ORDINAL: &str = "(first|second|third|fourth|fifth|last|1st|2nd|3rd|4th|5th)";
+
+type Resolver = fn(&Captures, NaiveDate) -> Option<NaiveDate>;
+
+/// Expressions in the order they are tried; when two match the same span
+/// the earlier one wins
+static RULES: Lazy<Vec<(Regex, Resolver)>> = Lazy::new(|| {
+    let rule = |pattern: String, resolve: Resolver| {
+        (Regex::new(&format!(r"(?i)\b{}\b", pattern)).unwrap(), resolve)
+    };
+    vec![
+        rule(r"(\d{4})-(\d{2})-(\d{2})".to_string(), iso_date),
+        rule(r"(\d{1,2})/(\d{1,2})(?:/(\d{4}|\d{2}))?".to_string(), numeric_date),
+        rule(
+            r"(?:the\s+)?(day\s+after\s+tomorrow|day\s+before\s+yesterday|today|tonight|tomorrow|yesterday)".to_string(),
+            named_day,
+        ),
+        rule(format!(r"in\s+{}\s+{}", NUMBER, UNIT), in_offset),
+        rule(format!(r"{}\s+{}\s+(from\s+now|ago)", NUMBER, UNIT), offset_from_now),
+        rule(
+            format!(r"{}\s+{}\s+(from|after|before)\s+((?:next\s+)?{}|today|tomorrow|yesterday)", NUMBER, UNIT, WEEKDAY),
+            offset_from_anchor,
+        ),
+        rule(format!(r"(?:(this|next|last|on)\s+)?{}", WEEKDAY), weekday),
+        rule(
+            format!(
+                r"(?:the\s+)?{}\s+{}(?:\s+(?:of|in)\s+(?:(this|next|the)\s+month|{}(?:\s+(\d{{4}}))?))?",
+                ORDINAL, WEEKDAY, MONTH
+            ),
+            ordinal_weekday,
+        ),
+        rule(format!(r"(?:on\s+)?{}\.?\s+(\d{{1,2}})(?:st|nd|rd|th)?(?:,?\s+(\d{{4}}))?", MONTH), month_day),
+        rule(format!(r"(?:on\s+)?(?:the\s+)?(\d{{1,2}})(?:st|nd|rd|th)?\s+(?:of\s+)?{}(?:,?\s+(\d{{4}}))?", MONTH), day_month),
+        rule(r"(?:on\s+)?(?:the\s+)?(\d{1,2})(?:st|nd|rd|th)".to_string(), day_of_month),
+        rule(
+            r"(end|start|beginning)\s+of\s+(?:the\s+)?(?:(this|next|last)\s+)?(week|month|year)".to_string(),
+            period_boundary,
+        ),
+        rule(format!(r"(end|start|beginning)\s+of\s+{}", MONTH), month_boundary),
+        rule(r"(?:(this|next)\s+)?weekend".to_string(), weekend),
+        rule(r"(this|next|last)\s+(week|month|year)".to_string(), relative_period),
+    ]
+});
+
+type BusinessResolver = fn(&Captures, &str, NaiveDate, &HolidayCalendar) -> Option<DateMatch>;
+
+/// Expressions counting business days; they see the whole text so an
+/// anchor date after them can extend the match
+static BUSINESS_RULES: Lazy<Vec<(Regex, BusinessResolver)>> = Lazy::new(|| {
+    let rule = |pattern: String, resolve: BusinessResolver| {
+        (Regex::new(&format!(r"(?i)\b{}\b", pattern)).unwrap(), resolve)
+    };
+    const BUSINESS_DAYS: &str = r"(?:business|working)\s+days?";
+    vec![
+        rule(format!(r"in\s+{}\s+{}", NUMBER, BUSINESS_DAYS), business_days_from_now),
+        rule(format!(r"{}\s+{}\s+(from\s+now|ago)", NUMBER, BUSINESS_DAYS), business_days_from_now),
+        rule(format!(r"{}\s+{}\s+(from|after|before)\s+", NUMBER, BUSINESS_DAYS), business_days_from_anchor),
+        rule(r"(next|previous)\s+(?:business|working)\s+day".to_string(), next_business_day),
+    ]
+});
+
+/// Calendar for business days when none is given: weekends off, no holidays
+static WEEKENDS_ONLY: Lazy<HolidayCalendar> = Lazy::new(HolidayCalendar::default);
+
+/// A date expression found in free text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateMatch {
+    pub date: NaiveDate,
+    /// Byte range of the expression in the searched text
+    pub start: usize,
+    pub end: usize,
+}
+
+impl DateMatch {
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// Parse `input` as a single date expression relative to `reference`
+pub fn parse_natural_date(input: &str, reference: NaiveDate) -> Option<NaiveDate> {
+    parse_natural_date_in(input, reference, &WEEKENDS_ONLY)
+}
+
+/// [`parse_natural_date`] with the business days and holidays of `calendar`
+pub fn parse_natural_date_in(input: &str, reference: NaiveDate, calendar: &HolidayCalendar) -> Option<NaiveDate> {
+    let input = input.trim();
+    find_natural_date_in(input, reference, calendar)
+        .filter(|found| found.start == 0 && found.end == input.len())
+        .map(|found| found.date)
+}
+
+/// First date expression in `text`, preferring the longest one when
+/// several start at the same position
+pub fn find_natural_date(text: &str, reference: NaiveDate) -> Option<DateMatch> {
+    find_natural_date_in(text, reference, &WEEKENDS_ONLY)
+}
+
+/// [`find_natural_date`] with the business days and holidays of `calendar`
+pub fn find_natural_date_in(text: &str, reference: NaiveDate, calendar: &HolidayCalendar) -> Option<DateMatch> {
+    let mut best: Option<DateMatch> = None;
+    let mut consider = |found: Option<DateMatch>| {
+        if let Some(found) = found {
+            let better = best.as_ref().is_none_or(|current| {
+                found.start < current.start || (found.start == current.start && found.end > current.end)
+            });
+            if better {
+                best = Some(found);
+            }
+        }
+    };
+
+    for (pattern, resolve) in RULES.iter() {
+        consider(pattern.captures_iter(text).find_map(|caps| {
+            let whole = caps.get(0)?;
+            resolve(&caps, reference).map(|date| DateMatch { date, start: whole.start(), end: whole.end() })
+        }));
+    }
+    for (pattern, resolve) in BUSINESS_RULES.iter() {
+        consider(pattern.captures_iter(text).find_map(|caps| resolve(&caps, text, reference, calendar)));
+    }
+    for holiday in &calendar.holidays {
+        let Ok(pattern) = Regex::new(&format!(r"(?i)\b(?:on\s+)?{}\b", regex::escape(&holiday.name))) else { continue };
+        consider(pattern.find(text).and_then(|whole| {
+            let date = calendar.next_named(&holiday.name, reference)?;
+            Some(DateMatch { date, start: whole.start(), end: whole.end() })
+        }));
+    }
+    best
+}
+
+fn iso_date(caps: &Captures, _: NaiveDate) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(number_at(caps, 1)? as i32, number_at(caps, 2)?, number_at(caps, 3)?)
+}
+
+fn numeric_date(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let (month, day) = (number_at(caps, 1)?, number_at(caps, 2)?);
+    match caps.get(3) {
+        Some(year) => {
+            let year: i32 = year.as_str().parse().ok()?;
+            let year = if year < 100 { 2000 + year } else { year };
+            NaiveDate::from_ymd_opt(year, month, day)
+        }
+        None => upcoming_month_day(reference, month, day),
+    }
+}
+
+fn named_day(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let name = normalize(&caps[1]);
+    match name.as_str() {
+        "today" | "tonight" => Some(reference),
+        "tomorrow" => reference.succ_opt(),
+        "yesterday" => reference.pred_opt(),
+        "day after tomorrow" => reference.checked_add_days(Days::new(2)),
+        "day before yesterday" => reference.checked_sub_days(Days::new(2)),
+        _ => None,
+    }
+}
+
+fn in_offset(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    shift(reference, count(&caps[1])?, &caps[2], true)
+}
+
+fn offset_from_now(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let forward = !caps[3].eq_ignore_ascii_case("ago");
+    shift(reference, count(&caps[1])?, &caps[2], forward)
+}
+
+fn offset_from_anchor(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let anchor = parse_natural_date(&caps[4], reference)?;
+    let forward = !caps[3].eq_ignore_ascii_case("before");
+    shift(anchor, count(&caps[1])?, &caps[2], forward)
+}
+
+fn business_days_from_now(caps: &Captures, _: &str, reference: NaiveDate, calendar: &HolidayCalendar) -> Option<DateMatch> {
+    let whole = caps.get(0)?;
+    let days = count(&caps[1])? as i64;
+    let ago = caps.get(2).is_some_and(|m| m.as_str().eq_ignore_ascii_case("ago"));
+    let date = calendar.add_business_days(reference, if ago { -days } else { days })?;
+    Some(DateMatch { date, start: whole.start(), end: whole.end() })
+}
+
+/// "3 business days after the 20th": the anchor is whatever date
+/// expression follows
+fn business_days_from_anchor(caps: &Captures, text: &str, reference: NaiveDate, calendar: &HolidayCalendar) -> Option<DateMatch> {
+    let whole = caps.get(0)?;
+    let rest = &text[whole.end()..];
+    let anchor = find_natural_date_in(rest, reference, calendar).filter(|found| found.start == 0)?;
+    let days = count(&caps[1])? as i64;
+    let days = if caps[2].eq_ignore_ascii_case("before") { -days } else { days };
+    let date = calendar.add_business_days(anchor.date, days)?;
+    Some(DateMatch { date, start: whole.start(), end: whole.end() + anchor.end })
+}
+
+fn next_business_day(caps: &Captures, _: &str, reference: NaiveDate, calendar: &HolidayCalendar) -> Option<DateMatch> {
+    let whole = caps.get(0)?;
+    let days = if caps[1].eq_ignore_ascii_case("next") { 1 } else { -1 };
+    let date = calendar.add_business_days(reference, days)?;
+    Some(DateMatch { date, start: whole.start(), end: whole.end() })
+}
+
+/// Bare and "this" weekdays are the next one on or after the reference
+/// date, "next" skips the reference date itself, "last" looks back
+fn weekday(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let weekday = parse_weekday(&caps[2])?;
+    match caps.get(1).map(|m| m.as_str().to_lowercase()).as_deref() {
+        Some("next") => upcoming_weekday(reference.succ_opt()?, weekday),
+        Some("last") => {
+            let back = (reference.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday() - 1) % 7 + 1;
+            reference.checked_sub_days(Days::new(back as u64))
+        }
+        _ => upcoming_weekday(reference, weekday),
+    }
+}
+
+/// "the 2nd Tuesday of March", "last Friday of the month"; without a
+/// month, this month's unless it has passed
+fn ordinal_weekday(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let ordinal = parse_ordinal(&caps[1])?;
+    let weekday = parse_weekday(&caps[2])?;
+    let this_month = first_of_month(reference);
+
+    if let Some(month) = caps.get(4) {
+        let month = parse_month(month.as_str())?;
+        if let Some(year) = caps.get(5) {
+            return nth_weekday(year.as_str().parse().ok()?, month, weekday, ordinal);
+        }
+        return (0..=1)
+            .filter_map(|offset| nth_weekday(reference.year() + offset, month, weekday, ordinal))
+            .find(|date| *date >= reference);
+    }
+
+    let month = match caps.get(3).map(|m| m.as_str().to_lowercase()).as_deref() {
+        Some("next") => this_month.checked_add_months(Months::new(1))?,
+        Some(_) => this_month,
+        None => {
+            let date = nth_weekday(this_month.year(), this_month.month(), weekday, ordinal)?;
+            if date >= reference {
+                return Some(date);
+            }
+            this_month.checked_add_months(Months::new(1))?
+        }
+    };
+    nth_weekday(month.year(), month.month(), weekday, ordinal)
+}
+
+fn month_day(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let (month, day) = (parse_month(&caps[1])?, number_at(caps, 2)?);
+    match caps.get(3) {
+        Some(year) => NaiveDate::from_ymd_opt(year.as_str().parse().ok()?, month, day),
+        None => upcoming_month_day(reference, month, day),
+    }
+}
+
+fn day_month(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let (day, month) = (number_at(caps, 1)?, parse_month(&caps[2])?);
+    match caps.get(3) {
+        Some(year) => NaiveDate::from_ymd_opt(year.as_str().parse().ok()?, month, day),
+        None => upcoming_month_day(reference, month, day),
+    }
+}
+
+/// "the 15th": the next month that has that day
+fn day_of_month(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let day = number_at(caps, 1)?;
+    let this_month = first_of_month(reference);
+    (0..12)
+        .filter_map(|offset| this_month.checked_add_months(Months::new(offset)))
+        .filter_map(|month| month.with_day(day))
+        .find(|date| *date >= reference)
+}
+
+/// Weeks run Monday to Sunday
+fn period_boundary(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let end = caps[1].eq_ignore_ascii_case("end");
+    let period = caps[3].to_lowercase();
+    let offset = match caps.get(2).map(|m| m.as_str().to_lowercase()).as_deref() {
+        Some("next") => 1,
+        Some("last") => -1,
+        _ => 0,
+    };
+    let start = period_start(reference, &period, offset)?;
+    if !end {
+        return Some(start);
+    }
+    period_start(start, &period, 1)?.pred_opt()
+}
+
+fn month_boundary(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let month = parse_month(&caps[2])?;
+    let year = if month < reference.month() { reference.year() + 1 } else { reference.year() };
+    let start = NaiveDate::from_ymd_opt(year, month, 1)?;
+    if caps[1].eq_ignore_ascii_case("end") {
+        start.checked_add_months(Months::new(1))?.pred_opt()
+    } else {
+        Some(start)
+    }
+}
+
+/// The weekend's Saturday, or the reference date when that is already
+/// the weekend
+fn weekend(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    let saturday = match reference.weekday() {
+        Weekday::Sun => reference.pred_opt()?,
+        _ => upcoming_weekday(reference, Weekday::Sat)?,
+    };
+    match caps.get(1) {
+        Some(which) if which.as_str().eq_ignore_ascii_case("next") => saturday.checked_add_days(Days::new(7)),
+        _ => Some(saturday.max(reference)),
+    }
+}
+
+/// "this week" is the reference date, "next"/"last" the start of that
+/// period
+fn relative_period(caps: &Captures, reference: NaiveDate) -> Option<NaiveDate> {
+    match caps[1].to_lowercase().as_str() {
+        "next" => period_start(reference, &caps[2].to_lowercase(), 1),
+        "last" => period_start(reference, &caps[2].to_lowercase(), -1),
+        _ => Some(reference),
+    }
+}
+
+/// First day of the week, month or year containing `date`, moved by
+/// `offset` periods
+fn period_start(date: NaiveDate, period: &str, offset: i32) -> Option<NaiveDate> {
+    match period {
+        "week" => {
+            let monday = date.checked_sub_days(Days::new(date.weekday().num_days_from_monday() as u64))?;
+            add_days(monday, offset as i64 * 7)
+        }
+        "month" => add_months(first_of_month(date), offset),
+        "year" => NaiveDate::from_ymd_opt(date.year() + offset, 1, 1),
+        _ => None,
+    }
+}
+
+fn shift(date: NaiveDate, amount: u32, unit: &str, forward: bool) -> Option<NaiveDate> {
+    let amount = if forward { amount as i64 } else { -(amount as i64) };
+    match unit.to_lowercase().trim_end_matches('s') {
+        "day" => add_days(date, amount),
+        "week" => add_days(date, amount * 7),
+        "month" => add_months(date, i32::try_from(amount).ok()?),
+        "year" => add_months(date, i32::try_from(amount * 12).ok()?),
+        _ => None,
+    }
+}
+
+fn add_days(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+    if days >= 0 {
+        date.checked_add_days(Days::new(days as u64))
+    } else {
+        date.checked_sub_days(Days::new(days.unsigned_abs()))
+    }
+}
+
+/// Month arithmetic clamps to the end of shorter months (Jan 31 + 1 month
+/// is Feb 28)
+fn add_months(date: NaiveDate, months: i32) -> Option<NaiveDate> {
+    if months >= 0 {
+        date.checked_add_months(Months::new(months as u32))
+    } else {
+        date.checked_sub_months(Months::new(months.unsigned_abs()))
+    }
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+fn upcoming_weekday(from: NaiveDate, weekday: Weekday) -> Option<NaiveDate> {
+    let ahead = (weekday.num_days_from_monday() + 7 - from.weekday().num_days_from_monday()) % 7;
+    from.checked_add_days(Days::new(ahead as u64))
+}
+
+/// Next occurrence of a month and day on or after `reference`; Feb 29
+/// waits for the next leap year
+fn upcoming_month_day(reference: NaiveDate, month: u32, day: u32) -> Option<NaiveDate> {
+    (0..=8)
+        .filter_map(|offset| NaiveDate::from_ymd_opt(reference.year() + offset, month, day))
+        .find(|date| *date >= reference)
+}
+
+/// `ordinal` is 1-5, or -1 for the last one in the month
+pub(crate) fn nth_weekday(year: i32, month: u32, weekday: Weekday, ordinal: i8) -> Option<NaiveDate> {
+    if ordinal > 0 {
+        return NaiveDate::from_weekday_of_month_opt(year, month, weekday, ordinal as u8);
+    }
+    let last = NaiveDate::from_ymd_opt(year, month, 1)?.checked_add_months(Months::new(1))?.pred_opt()?;
+    let back = (last.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+    last.checked_sub_days(Days::new(back as u64))
+}
+
+fn parse_weekday(name: &str) -> Option<Weekday> {
+    name.to_lowercase().parse().ok()
+}
+
+fn parse_month(name: &str) -> Option<u32> {
+    let month = match name.to_lowercase().get(..3)? {
+        "jan" => 1,
+        "feb" => 2,
+        "mar" => 3,
+        "apr" => 4,
+        "may" => 5,
+        "jun" => 6,
+        "jul" => 7,
+        "aug" => 8,
+        "sep" => 9,
+        "oct" => 10,
+        "nov" => 11,
+        "dec" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+fn parse_ordinal(word: &str) -> Option<i8> {
+    match word.to_lowercase().as_str() {
+        "first" | "1st" => Some(1),
+        "second" | "2nd" => Some(2),
+        "third" | "3rd" => Some(3),
+        "fourth" | "4th" => Some(4),
+        "fifth" | "5th" => Some(5),
+        "last" => Some(-1),
+        _ => None,
+    }
+}
+
+fn count(word: &str) -> Option<u32> {
+    let word = word.to_lowercase();
+    let count = match word.as_str() {
+        "a" | "an" | "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        digits => return digits.parse().ok(),
+    };
+    Some(count)
+}
+
+fn number_at(caps: &Captures, group: usize) -> Option<u32> {
+    caps.get(group)?.as_str().parse().ok()
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::date;
+
+    /// Saturday, October 17 2026
+    fn reference() -> NaiveDate {
+        date("2026-10-17")
+    }
+
+    fn parse(input: &str) -> Option<String> {
+        parse_natural_date(input, reference()).map(|d| d.to_string())
+    }
+
+    #[test]
+    fn test_named_and_offset_days() {
+        assert_eq!(parse("today").as_deref(), Some("2026-10-17"));
+        assert_eq!(parse("Tomorrow").as_deref(), Some("2026-10-18"));
+        assert_eq!(parse("the day after tomorrow").as_deref(), Some("2026-10-19"));
+        assert_eq!(parse("in 3 days").as_deref(), Some("2026-10-20"));
+        assert_eq!(parse("in a week").as_deref(), Some("2026-10-24"));
+        assert_eq!(parse("in two months").as_deref(), Some("2026-12-17"));
+        assert_eq!(parse("2 weeks ago").as_deref(), Some("2026-10-03"));
+        assert_eq!(parse("a week from Friday").as_deref(), Some("2026-10-30"));
+        assert_eq!(parse("3 days before tomorrow").as_deref(), Some("2026-10-15"));
+        assert_eq!(parse("someday"), None);
+    }
+
+    #[test]
+    fn test_weekdays() {
+        assert_eq!(parse("saturday").as_deref(), Some("2026-10-17"));
+        assert_eq!(parse("next saturday").as_deref(), Some("2026-10-24"));
+        assert_eq!(parse("on Monday").as_deref(), Some("2026-10-19"));
+        assert_eq!(parse("last friday").as_deref(), Some("2026-10-16"));
+        assert_eq!(parse("this weekend").as_deref(), Some("2026-10-17"));
+        assert_eq!(parse("next weekend").as_deref(), Some("2026-10-24"));
+        assert_eq!(parse_natural_date("this weekend", date("2026-10-14")), Some(date("2026-10-17")));
+    }
+
+    #[test]
+    fn test_ordinal_weekdays() {
+        assert_eq!(parse("the first monday of next month").as_deref(), Some("2026-11-02"));
+        assert_eq!(parse("last friday of the month").as_deref(), Some("2026-10-30"));
+        assert_eq!(parse("2nd tuesday in March").as_deref(), Some("2027-03-09"));
+        assert_eq!(parse("fourth thursday of november").as_deref(), Some("2026-11-26"));
+        // October's first Monday has passed
+        assert_eq!(parse("first monday").as_deref(), Some("2026-11-02"));
+    }
+
+    #[test]
+    fn test_calendar_dates() {
+        assert_eq!(parse("the 15th").as_deref(), Some("2026-11-15"));
+        assert_eq!(parse("the 31st").as_deref(), Some("2026-10-31"));
+        assert_eq!(parse_natural_date("the 31st", date("2026-11-01")), Some(date("2026-12-31")));
+        assert_eq!(parse("March 3").as_deref(), Some("2027-03-03"));
+        assert_eq!(parse("Dec 25th").as_deref(), Some("2026-12-25"));
+        assert_eq!(parse("3rd of March 2028").as_deref(), Some("2028-03-03"));
+        assert_eq!(parse("3/4").as_deref(), Some("2027-03-04"));
+        assert_eq!(parse("12/1/27").as_deref(), Some("2027-12-01"));
+        assert_eq!(parse("2026-02-29"), None);
+        assert_eq!(parse("feb 29").as_deref(), Some("2028-02-29"));
+    }
+
+    #[test]
+    fn test_period_boundaries() {
+        assert_eq!(parse("end of month").as_deref(), Some("2026-10-31"));
+        assert_eq!(parse("end of next month").as_deref(), Some("2026-11-30"));
+        assert_eq!(parse("start of next week").as_deref(), Some("2026-10-19"));
+        assert_eq!(parse("end of the week").as_deref(), Some("2026-10-18"));
+        assert_eq!(parse("end of the year").as_deref(), Some("2026-12-31"));
+        assert_eq!(parse("end of february").as_deref(), Some("2027-02-28"));
+        assert_eq!(parse("next month").as_deref(), Some("2026-11-01"));
+        assert_eq!(parse("next week").as_deref(), Some("2026-10-19"));
+    }
+
+    #[test]
+    fn test_find_in_text() {
+        let text = "Dentist a week from Friday at 3pm";
+        let found = find_natural_date(text, reference()).unwrap();
+        assert_eq!(found.date, date("2026-10-30"));
+        assert_eq!(&text[found.range()], "a week from Friday");
+
+        let found = find_natural_date("Pay rent on the 1st", reference()).unwrap();
+        assert_eq!(found.date, date("2026-11-01"));
+
+        // Times and plain numbers are not dates
+        assert_eq!(find_natural_date("Buy 2 tickets at 5pm", reference()), None);
+        assert_eq!(find_natural_date("Call May about 5pm", reference()), None);
+    }
+
+    #[test]
+    fn test_business_days_and_holidays() {
+        assert_eq!(parse("in 3 business days").as_deref(), Some("2026-10-21"));
+        assert_eq!(parse("2 working days ago").as_deref(), Some("2026-10-15"));
+        assert_eq!(parse("next business day").as_deref(), Some("2026-10-19"));
+        assert_eq!(parse("3 business days after the 20th").as_deref(), Some("2026-10-23"));
+
+        // Thanksgiving is skipped with the US calendar
+        let us = HolidayCalendar::country("US").unwrap();
+        let text = "Invoice due 3 business days after November 24th, 5pm";
+        let found = find_natural_date_in(text, reference(), &us).unwrap();
+        assert_eq!(found.date, date("2026-11-30"));
+        assert_eq!(&text[found.range()], "3 business days after November 24th");
+
+        let found = find_natural_date_in("Dinner on Thanksgiving at 6pm", reference(), &us).unwrap();
+        assert_eq!(found.date, date("2026-11-26"));
+        assert_eq!(found.range(), 7..22);
+        assert_eq!(parse_natural_date_in("christmas day", reference(), &us), Some(date("2026-12-25")));
+    }
+}
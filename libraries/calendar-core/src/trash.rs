@@ -0,0 +1,72 @@
+//! Deleted events, kept for a while so they can be restored.
+//!
+//! Deleting an event moves it to the trash, where it no longer shows up
+//! anywhere else. Events stay there until they are restored, purged by
+//! hand, or outlive the retention period.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::CalendarEvent;
+
+/// Days a deleted event is kept unless configured otherwise
+pub const DEFAULT_RETENTION_DAYS: u32 = 30;
+
+/// An event in the trash
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedEvent {
+    pub event: CalendarEvent,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// How long deleted events are kept before they are purged for good
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPolicy {
+    /// Zero purges everything in the trash
+    pub days: u32,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self { days: DEFAULT_RETENTION_DAYS }
+    }
+}
+
+impl RetentionPolicy {
+    pub fn days(days: u32) -> Self {
+        Self { days }
+    }
+
+    /// Events deleted at or before this are due to be purged
+    pub fn cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - Duration::days(self.days as i64)
+    }
+}
+
+impl TrashedEvent {
+    /// When the event is due to be purged under `policy`
+    pub fn expires_at(&self, policy: &RetentionPolicy) -> DateTime<Utc> {
+        self.deleted_at + Duration::days(policy.days as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_retention_cutoff() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 31, 12, 0, 0).unwrap();
+        let policy = RetentionPolicy::default();
+        assert_eq!(policy.cutoff(now), Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap());
+        assert_eq!(RetentionPolicy::days(0).cutoff(now), now);
+
+        let trashed = TrashedEvent {
+            event: CalendarEvent::new("Dentist".to_string(), now.date_naive()),
+            deleted_at: now,
+        };
+        assert_eq!(trashed.expires_at(&RetentionPolicy::days(7)), Utc.with_ymd_and_hms(2026, 4, 7, 12, 0, 0).unwrap());
+    }
+}
@@ -1,17 +1,88 @@
+use chrono::Weekday;
 use regex::Regex;
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use crate::models::{
+    Attendee, AttendeeRole, CalendarEvent, Category, CategoryDefinition, RecurrenceConfig, ReminderConfig, Task,
+};
+use crate::availability::WorkingHours;
+use crate::conflicts::ConflictCheck;
+use crate::holidays::{HolidayCalendar, HolidayRule};
+use crate::query::{EventQuery, MAX_PAGE_SIZE};
+use crate::time::TimeZone;
 
-static TIME_PATTERN: Lazy<Regex> = 
+static TIME_PATTERN: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^(?:[01]?[0-9]|2[0-3]):[0-5][0-9]$").unwrap());
 
-static DATE_PATTERN: Lazy<Regex> = 
+static DATE_PATTERN: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap());
 
+static HEX_COLOR_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^#[0-9A-Fa-f]{6}$").unwrap());
+
+static CATEGORY_NAME_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[a-z0-9][a-z0-9_-]*$").unwrap());
+
+static EMAIL_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap());
+
+/// Limits shared with the frontend schema in `types/event.ts`
+pub const MAX_TITLE_LENGTH: usize = 500;
+pub const MAX_NOTES_LENGTH: usize = 5000;
+pub const MAX_TAG_LENGTH: usize = 50;
+pub const MAX_CATEGORY_NAME_LENGTH: usize = 50;
+
+/// Longest reminder lead time accepted (four weeks)
+pub const MAX_REMINDER_MINUTES: u32 = 4 * 7 * 24 * 60;
+
+/// Furthest ahead a conflict check follows a recurring event (two years)
+pub const MAX_HORIZON_DAYS: u32 = 2 * 366;
+
+/// A problem with a single field, addressed by its wire path
+/// (e.g. `endTime`, `tags[2]`, `recurring.interval`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
 pub struct Validator;
 
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun,
+];
+
+/// Field name of a weekday in [`WorkingHours`]
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "monday",
+        Weekday::Tue => "tuesday",
+        Weekday::Wed => "wednesday",
+        Weekday::Thu => "thursday",
+        Weekday::Fri => "friday",
+        Weekday::Sat => "saturday",
+        Weekday::Sun => "sunday",
+    }
+}
+
 impl Validator {
     pub fn validate_time(time: &str) -> Result<(), String> {
-        if !TIME_PATTERN.is_match(time) {
+        if !TIME_PATTERN.is_match(time) || crate::time::parse_hhmm(time).is_none() {
             Err(format!("Invalid time format: {}", time))
         } else {
             Ok(())
@@ -19,7 +90,8 @@ impl Validator {
     }
 
     pub fn validate_date(date: &str) -> Result<(), String> {
-        if !DATE_PATTERN.is_match(date) {
+        // The pattern pins the layout; chrono rejects dates like 2026-02-30
+        if !DATE_PATTERN.is_match(date) || chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
             Err(format!("Invalid date format: {}", date))
         } else {
             Ok(())
@@ -27,14 +99,615 @@ impl Validator {
     }
 
     pub fn validate_hex_color(color: &str) -> Result<(), String> {
-        if !color.starts_with('#') || color.len() != 7 {
+        if !HEX_COLOR_PATTERN.is_match(color) {
             Err(format!("Invalid hex color: {}", color))
         } else {
             Ok(())
         }
     }
 
+    /// User-defined category names are lowercase slugs, e.g. `side-project`
+    pub fn validate_category_name(name: &str) -> Result<(), String> {
+        if name.len() > MAX_CATEGORY_NAME_LENGTH {
+            Err(format!("Category name cannot exceed {} characters", MAX_CATEGORY_NAME_LENGTH))
+        } else if !CATEGORY_NAME_PATTERN.is_match(name) {
+            Err(format!("Invalid category name: {}", name))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn sanitize_input(input: &str) -> String {
         input.trim().to_string()
     }
+
+    /// Check every field of `event`, returning all problems found (empty
+    /// when the event is valid)
+    pub fn validate_event(event: &CalendarEvent) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+
+        if event.event.trim().is_empty() {
+            errors.push(FieldError::new("event", "Event title cannot be empty"));
+        } else if event.event.chars().count() > MAX_TITLE_LENGTH {
+            errors.push(FieldError::new(
+                "event",
+                format!("Event title cannot exceed {} characters", MAX_TITLE_LENGTH),
+            ));
+        }
+
+        if event.notes.as_ref().is_some_and(|notes| notes.chars().count() > MAX_NOTES_LENGTH) {
+            errors.push(FieldError::new(
+                "notes",
+                format!("Notes cannot exceed {} characters", MAX_NOTES_LENGTH),
+            ));
+        }
+
+        if event.end_date.is_some_and(|end| end < event.date) {
+            errors.push(FieldError::new("endDate", "End date cannot be before start date"));
+        }
+
+        if !event.all_day {
+            match (event.time, event.end_time) {
+                (None, Some(_)) => {
+                    errors.push(FieldError::new("endTime", "End time requires a start time"));
+                }
+                // An end date before the start is already reported above
+                (Some(_), Some(_))
+                    if event.end_date.is_none_or(|end| end >= event.date)
+                        && event.end_naive().is_some_and(|end| end < event.start_naive()) =>
+                {
+                    errors.push(FieldError::new("endTime", "End time cannot be before start time"));
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(color) = &event.color {
+            if let Err(e) = Self::validate_hex_color(color) {
+                errors.push(FieldError::new("color", e));
+            }
+        }
+
+        Self::check_category(&event.category, &mut errors);
+
+        Self::check_tags(&event.tags, &mut errors);
+
+        if let Some(tz) = &event.timezone {
+            if let Err(e) = TimeZone::parse(tz) {
+                errors.push(FieldError::new("timezone", e.to_string()));
+            }
+        }
+
+        if let Some(recurring) = &event.recurring {
+            Self::check_recurrence(recurring, event.date, &mut errors);
+        }
+
+        let start = event.start_utc(TimeZone::Local);
+        for (i, reminder) in event.reminders.iter().enumerate() {
+            Self::check_reminder(reminder, i, start, &mut errors);
+        }
+
+        Self::check_attendees(&event.attendees, &mut errors);
+
+        errors
+    }
+
+    /// Check every field of `task`, returning all problems found
+    pub fn validate_task(task: &Task) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+
+        if task.title.trim().is_empty() {
+            errors.push(FieldError::new("title", "Task title cannot be empty"));
+        } else if task.title.chars().count() > MAX_TITLE_LENGTH {
+            errors.push(FieldError::new(
+                "title",
+                format!("Task title cannot exceed {} characters", MAX_TITLE_LENGTH),
+            ));
+        }
+
+        if task.notes.as_ref().is_some_and(|notes| notes.chars().count() > MAX_NOTES_LENGTH) {
+            errors.push(FieldError::new(
+                "notes",
+                format!("Notes cannot exceed {} characters", MAX_NOTES_LENGTH),
+            ));
+        }
+
+        if task.due_time.is_some() && task.due_date.is_none() {
+            errors.push(FieldError::new("dueTime", "Due time requires a due date"));
+        }
+
+        if task.percent_complete > 100 {
+            errors.push(FieldError::new("percentComplete", "Percent complete cannot exceed 100"));
+        }
+
+        if task.parent_id == Some(task.id) {
+            errors.push(FieldError::new("parentId", "A task cannot be its own subtask"));
+        }
+
+        Self::check_category(&task.category, &mut errors);
+
+        Self::check_tags(&task.tags, &mut errors);
+
+        errors
+    }
+
+    /// Check every field of a category definition
+    pub fn validate_category(definition: &CategoryDefinition) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+
+        Self::check_category(&definition.category, &mut errors);
+
+        if definition.display_name.trim().is_empty() {
+            errors.push(FieldError::new("displayName", "Display name cannot be empty"));
+        } else if definition.display_name.chars().count() > MAX_CATEGORY_NAME_LENGTH {
+            errors.push(FieldError::new(
+                "displayName",
+                format!("Display name cannot exceed {} characters", MAX_CATEGORY_NAME_LENGTH),
+            ));
+        }
+
+        if let Err(e) = Self::validate_hex_color(&definition.color) {
+            errors.push(FieldError::new("color", e));
+        }
+
+        for (i, keyword) in definition.keywords.iter().enumerate() {
+            if !keyword.chars().any(char::is_alphanumeric) {
+                errors.push(FieldError::new(format!("keywords[{}]", i), "Keyword must contain a word"));
+            } else if keyword.chars().count() > MAX_TAG_LENGTH {
+                errors.push(FieldError::new(
+                    format!("keywords[{}]", i),
+                    format!("Keyword cannot exceed {} characters", MAX_TAG_LENGTH),
+                ));
+            }
+        }
+
+        errors
+    }
+
+    /// Every working day must end after it starts, and its breaks must fall
+    /// within it
+    pub fn validate_working_hours(hours: &WorkingHours) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+
+        for weekday in WEEKDAYS {
+            let Some(day) = hours.day(weekday) else { continue };
+            let name = weekday_name(weekday);
+            if day.start >= day.end {
+                errors.push(FieldError::new(format!("{}.end", name), "End must be after start"));
+            }
+            for (i, b) in day.breaks.iter().enumerate() {
+                if b.start >= b.end {
+                    errors.push(FieldError::new(format!("{}.breaks[{}].end", name, i), "End must be after start"));
+                } else if b.start < day.start || b.end > day.end {
+                    errors.push(FieldError::new(
+                        format!("{}.breaks[{}]", name, i),
+                        "Break must fall within the working day",
+                    ));
+                }
+            }
+        }
+
+        errors
+    }
+
+    pub fn validate_conflict_check(check: &ConflictCheck) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+
+        if check.horizon_days > MAX_HORIZON_DAYS {
+            errors.push(FieldError::new(
+                "horizonDays",
+                format!("Horizon cannot be more than {} days", MAX_HORIZON_DAYS),
+            ));
+        }
+
+        errors
+    }
+
+    pub fn validate_holiday_calendar(calendar: &HolidayCalendar) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+
+        if calendar.name.trim().is_empty() {
+            errors.push(FieldError::new("name", "Calendar name cannot be empty"));
+        }
+        for (i, holiday) in calendar.holidays.iter().enumerate() {
+            let field = |name: &str| format!("holidays[{}].{}", i, name);
+            if holiday.name.trim().is_empty() {
+                errors.push(FieldError::new(field("name"), "Holiday name cannot be empty"));
+            }
+            match holiday.rule {
+                HolidayRule::Fixed { month, day, .. } => {
+                    if !(1..=12).contains(&month) {
+                        errors.push(FieldError::new(field("month"), "Month must be between 1 and 12"));
+                    } else if chrono::NaiveDate::from_ymd_opt(2000, month, day).is_none() {
+                        // 2000 is a leap year, so February 29 is allowed
+                        errors.push(FieldError::new(field("day"), "Day does not exist in that month"));
+                    }
+                }
+                HolidayRule::NthWeekday { month, n, .. } => {
+                    if !(1..=12).contains(&month) {
+                        errors.push(FieldError::new(field("month"), "Month must be between 1 and 12"));
+                    }
+                    if n != -1 && !(1..=5).contains(&n) {
+                        errors.push(FieldError::new(field("n"), "Must be 1 to 5, or -1 for the last"));
+                    }
+                }
+                HolidayRule::Easter { offset } => {
+                    if offset.abs() > 365 {
+                        errors.push(FieldError::new(field("offset"), "Offset must be within a year of Easter"));
+                    }
+                }
+            }
+        }
+        let mut weekend = calendar.weekend.clone();
+        weekend.sort_by_key(|day| day.num_days_from_monday());
+        weekend.dedup();
+        if weekend.len() >= WEEKDAYS.len() {
+            errors.push(FieldError::new("weekend", "At least one day of the week must be a business day"));
+        }
+
+        errors
+    }
+
+    pub fn validate_event_query(query: &EventQuery) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+
+        if let (Some(start), Some(end)) = (query.start, query.end) {
+            if end < start {
+                errors.push(FieldError::new("end", "End cannot be before start"));
+            }
+        }
+        for (field, tags) in [("tags", &query.tags), ("excludeTags", &query.exclude_tags)] {
+            for (i, tag) in tags.iter().enumerate() {
+                if tag.trim().is_empty() {
+                    errors.push(FieldError::new(format!("{}[{}]", field, i), "Tag cannot be empty"));
+                }
+            }
+        }
+        if let Some(limit) = query.limit {
+            if limit == 0 || limit > MAX_PAGE_SIZE {
+                errors.push(FieldError::new("limit", format!("Limit must be between 1 and {}", MAX_PAGE_SIZE)));
+            }
+        }
+        if query.cursor.is_some() && query.offset > 0 {
+            errors.push(FieldError::new("cursor", "Use either a cursor or an offset, not both"));
+        }
+
+        errors
+    }
+
+    /// Only user-defined categories can carry a bad name, when built
+    /// directly rather than parsed
+    fn check_category(category: &Category, errors: &mut Vec<FieldError>) {
+        if let Category::Custom(name) = category {
+            if let Err(e) = Self::validate_category_name(name) {
+                errors.push(FieldError::new("category", e));
+            }
+        }
+    }
+
+    fn check_tags(tags: &[String], errors: &mut Vec<FieldError>) {
+        for (i, tag) in tags.iter().enumerate() {
+            if tag.trim().is_empty() {
+                errors.push(FieldError::new(format!("tags[{}]", i), "Tag cannot be empty"));
+            } else if tag.chars().count() > MAX_TAG_LENGTH {
+                errors.push(FieldError::new(
+                    format!("tags[{}]", i),
+                    format!("Tag cannot exceed {} characters", MAX_TAG_LENGTH),
+                ));
+            }
+        }
+    }
+
+    fn check_recurrence(rule: &RecurrenceConfig, start: chrono::NaiveDate, errors: &mut Vec<FieldError>) {
+        let mut push = |field: String, message: String| errors.push(FieldError::new(field, message));
+
+        if rule.interval == 0 {
+            push("recurring.interval".into(), "Interval must be at least 1".into());
+        }
+        for (i, day) in rule.days_of_week.iter().enumerate() {
+            if *day > 6 {
+                push(
+                    format!("recurring.daysOfWeek[{}]", i),
+                    format!("Weekday must be 0 (Sunday) to 6 (Saturday), got {}", day),
+                );
+            }
+        }
+        for (i, ordinal) in rule.ordinal_weekdays.iter().enumerate() {
+            if ordinal.weekday > 6 {
+                push(
+                    format!("recurring.ordinalWeekdays[{}].weekday", i),
+                    format!("Weekday must be 0 (Sunday) to 6 (Saturday), got {}", ordinal.weekday),
+                );
+            }
+            if ordinal.ordinal == 0 || ordinal.ordinal.unsigned_abs() > 53 {
+                push(
+                    format!("recurring.ordinalWeekdays[{}].ordinal", i),
+                    format!("Ordinal must be 1 to 53 or -53 to -1, got {}", ordinal.ordinal),
+                );
+            }
+        }
+        for (i, day) in rule.by_month_day.iter().enumerate() {
+            if *day == 0 || day.unsigned_abs() > 31 {
+                push(
+                    format!("recurring.byMonthDay[{}]", i),
+                    format!("Day of month must be 1 to 31 or -31 to -1, got {}", day),
+                );
+            }
+        }
+        for (i, month) in rule.by_month.iter().enumerate() {
+            if !(1..=12).contains(month) {
+                push(format!("recurring.byMonth[{}]", i), format!("Month must be 1 to 12, got {}", month));
+            }
+        }
+        for (i, pos) in rule.by_set_pos.iter().enumerate() {
+            if *pos == 0 || pos.unsigned_abs() > 366 {
+                push(
+                    format!("recurring.bySetPos[{}]", i),
+                    format!("Set position must be 1 to 366 or -366 to -1, got {}", pos),
+                );
+            }
+        }
+        if rule.week_start.is_some_and(|day| day > 6) {
+            push("recurring.weekStart".into(), "Week start must be 0 (Sunday) to 6 (Saturday)".into());
+        }
+        if rule.end_date.is_some_and(|end| end < start) {
+            push("recurring.endDate".into(), "Recurrence cannot end before the event starts".into());
+        }
+        if rule.occurrences == Some(0) {
+            push("recurring.occurrences".into(), "Occurrence count must be at least 1".into());
+        }
+        // RFC 5545: COUNT and UNTIL must not both be given
+        if rule.end_date.is_some() && rule.occurrences.is_some() {
+            push(
+                "recurring.occurrences".into(),
+                "Set either an end date or an occurrence count, not both".into(),
+            );
+        }
+    }
+
+    fn check_attendees(attendees: &[Attendee], errors: &mut Vec<FieldError>) {
+        let mut seen_organizer = false;
+        for (i, attendee) in attendees.iter().enumerate() {
+            if !EMAIL_PATTERN.is_match(attendee.email.trim()) {
+                errors.push(FieldError::new(
+                    format!("attendees[{}].email", i),
+                    format!("Invalid email address: {}", attendee.email),
+                ));
+            } else if attendees[..i].iter().any(|other| other.has_email(&attendee.email)) {
+                errors.push(FieldError::new(
+                    format!("attendees[{}].email", i),
+                    format!("Duplicate attendee: {}", attendee.email),
+                ));
+            }
+            if attendee.role == AttendeeRole::Organizer {
+                if seen_organizer {
+                    errors.push(FieldError::new(
+                        format!("attendees[{}].role", i),
+                        "An event can only have one organizer",
+                    ));
+                }
+                seen_organizer = true;
+            }
+        }
+    }
+
+    fn check_reminder(
+        reminder: &ReminderConfig,
+        index: usize,
+        start: Option<chrono::DateTime<chrono::Utc>>,
+        errors: &mut Vec<FieldError>,
+    ) {
+        let mut push = |field: &str, message: String| {
+            errors.push(FieldError::new(format!("reminders[{}].{}", index, field), message))
+        };
+        let too_early = format!("Reminder cannot be more than {} minutes before the event", MAX_REMINDER_MINUTES);
+
+        match (reminder.at, start) {
+            (Some(at), Some(start)) if (start - at).num_minutes() > MAX_REMINDER_MINUTES as i64 => {
+                push("at", too_early);
+            }
+            (None, _) if reminder.minutes_before > MAX_REMINDER_MINUTES => {
+                push("minutesBefore", too_early);
+            }
+            _ => {}
+        }
+        if reminder.max_reminders == 0 {
+            push("maxReminders", "At least one reminder is required".to_string());
+        }
+        if reminder.repeat_minutes == Some(0) {
+            push("repeatMinutes", "Repeat interval must be at least 1 minute".to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{OrdinalWeekday, RecurrenceFrequency};
+    use crate::testing::{date, time};
+
+    fn fields(event: &CalendarEvent) -> Vec<String> {
+        Validator::validate_event(event).into_iter().map(|e| e.field).collect()
+    }
+
+    #[test]
+    fn test_valid_event_has_no_errors() {
+        let mut event = CalendarEvent::new("Standup".to_string(), date("2026-03-02"));
+        event.time = Some(time("09:00"));
+        event.end_time = Some(time("09:15"));
+        event.color = Some("#3b82F6".to_string());
+        event.tags = vec!["team".to_string()];
+        assert!(Validator::validate_event(&event).is_empty());
+    }
+
+    #[test]
+    fn test_reports_every_problem() {
+        let mut event = CalendarEvent::new(" ".to_string(), date("2026-03-02"));
+        event.time = Some(time("10:00"));
+        event.end_time = Some(time("09:00"));
+        event.notes = Some("x".repeat(MAX_NOTES_LENGTH + 1));
+        event.color = Some("#GGGGGG".to_string());
+        event.tags = vec!["ok".to_string(), "t".repeat(MAX_TAG_LENGTH + 1)];
+
+        assert_eq!(fields(&event), vec!["event", "notes", "endTime", "color", "tags[1]"]);
+    }
+
+    #[test]
+    fn test_overnight_and_multi_day_ends() {
+        let mut event = CalendarEvent::new("Night shift".to_string(), date("2026-03-02"));
+        event.time = Some(time("22:00"));
+        event.end_time = Some(time("06:00"));
+        event.end_date = Some(date("2026-03-03"));
+        assert!(Validator::validate_event(&event).is_empty());
+
+        event.end_date = Some(date("2026-03-01"));
+        assert_eq!(fields(&event), vec!["endDate"]);
+    }
+
+    #[test]
+    fn test_recurrence_consistency() {
+        let mut event = CalendarEvent::new("Review".to_string(), date("2026-03-02"));
+        event.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Weekly,
+            interval: 0,
+            days_of_week: vec![1, 7],
+            ordinal_weekdays: vec![OrdinalWeekday { ordinal: 0, weekday: 2 }],
+            end_date: Some(date("2026-02-01")),
+            ..Default::default()
+        });
+
+        assert_eq!(fields(&event), vec![
+            "recurring.interval",
+            "recurring.daysOfWeek[1]",
+            "recurring.ordinalWeekdays[0].ordinal",
+            "recurring.endDate",
+        ]);
+    }
+
+    #[test]
+    fn test_reminder_sanity() {
+        let mut event = CalendarEvent::new("Call".to_string(), date("2026-03-02"));
+        event.time = crate::time::parse_hhmm("09:00");
+        event.reminders = vec![
+            ReminderConfig::before(30),
+            ReminderConfig {
+                repeat_minutes: Some(0),
+                max_reminders: 0,
+                ..ReminderConfig::before(MAX_REMINDER_MINUTES + 1)
+            },
+            ReminderConfig::at("2026-01-01T09:00:00Z".parse().unwrap()),
+            ReminderConfig::at("2026-02-27T09:00:00Z".parse().unwrap()),
+        ];
+
+        assert_eq!(fields(&event), vec![
+            "reminders[1].minutesBefore",
+            "reminders[1].maxReminders",
+            "reminders[1].repeatMinutes",
+            "reminders[2].at",
+        ]);
+    }
+
+    #[test]
+    fn test_task_fields() {
+        let mut task = Task::new("");
+        task.due_time = Some(time("17:00"));
+        task.percent_complete = 101;
+        task.parent_id = Some(task.id);
+        task.tags = vec![String::new()];
+
+        let fields: Vec<_> = Validator::validate_task(&task).into_iter().map(|e| e.field).collect();
+        assert_eq!(fields, vec!["title", "dueTime", "percentComplete", "parentId", "tags[0]"]);
+
+        assert!(Validator::validate_task(&Task::new("Pay rent").due(date("2026-03-01"))).is_empty());
+    }
+
+    #[test]
+    fn test_category_definition() {
+        let mut definition = CategoryDefinition::new(Category::Custom("Bad Name".to_string()), " ", "blue");
+        definition.keywords = vec!["garden".to_string(), "--".to_string()];
+
+        let fields: Vec<_> = Validator::validate_category(&definition).into_iter().map(|e| e.field).collect();
+        assert_eq!(fields, vec!["category", "displayName", "color", "keywords[1]"]);
+
+        let garden = CategoryDefinition::new(Category::Custom("garden".to_string()), "Garden", "#22C55E");
+        assert!(Validator::validate_category(&garden).is_empty());
+    }
+
+    #[test]
+    fn test_working_hours() {
+        let mut hours = crate::WorkingHours::default();
+        assert!(Validator::validate_working_hours(&hours).is_empty());
+
+        hours.monday = Some(crate::WorkingDay::new(time("17:00"), time("09:00")));
+        hours.friday = Some(
+            crate::WorkingDay::new(time("09:00"), time("13:00"))
+                .with_break(time("12:00"), time("14:00"))
+                .with_break(time("10:30"), time("10:00")),
+        );
+        let fields: Vec<_> = Validator::validate_working_hours(&hours).into_iter().map(|e| e.field).collect();
+        assert_eq!(fields, vec!["monday.end", "friday.breaks[0]", "friday.breaks[1].end"]);
+    }
+
+    #[test]
+    fn test_conflict_check() {
+        let check = ConflictCheck::default().horizon_days(MAX_HORIZON_DAYS);
+        assert!(Validator::validate_conflict_check(&check).is_empty());
+
+        let fields: Vec<_> = Validator::validate_conflict_check(&check.horizon_days(u32::MAX))
+            .into_iter()
+            .map(|e| e.field)
+            .collect();
+        assert_eq!(fields, vec!["horizonDays"]);
+    }
+
+    #[test]
+    fn test_holiday_calendar() {
+        use crate::holidays::Holiday;
+        assert!(Validator::validate_holiday_calendar(&HolidayCalendar::country("FR").unwrap()).is_empty());
+
+        let calendar = HolidayCalendar {
+            name: "Office".to_string(),
+            holidays: vec![
+                Holiday::new("Leap day", HolidayRule::Fixed { month: 2, day: 29, observed: Default::default() }),
+                Holiday::new("Nothing", HolidayRule::NthWeekday { month: 13, weekday: Weekday::Mon, n: 0 }),
+            ],
+            weekend: WEEKDAYS.to_vec(),
+        };
+        let fields: Vec<_> = Validator::validate_holiday_calendar(&calendar).into_iter().map(|e| e.field).collect();
+        assert_eq!(fields, vec!["holidays[1].month", "holidays[1].n", "weekend"]);
+    }
+
+    #[test]
+    fn test_attendees() {
+        let mut event = CalendarEvent::new("Planning".to_string(), date("2026-03-02"));
+        event.attendees = vec![
+            Attendee::new("lead@example.com").with_role(AttendeeRole::Organizer),
+            Attendee::new("not-an-email"),
+            Attendee::new("LEAD@example.com"),
+            Attendee::new("second@example.com").with_role(AttendeeRole::Organizer),
+        ];
+
+        assert_eq!(fields(&event), vec!["attendees[1].email", "attendees[2].email", "attendees[3].role"]);
+    }
+
+    #[test]
+    fn test_hex_color_digits() {
+        assert!(Validator::validate_hex_color("#a1B2c3").is_ok());
+        assert!(Validator::validate_hex_color("#12345G").is_err());
+        assert!(Validator::validate_hex_color("123456").is_err());
+    }
+
+    #[test]
+    fn test_event_query() {
+        assert!(Validator::validate_event_query(&EventQuery::new().between(date("2026-03-01"), date("2026-03-31"))).is_empty());
+
+        let query = EventQuery::new()
+            .between(date("2026-03-31"), date("2026-03-01"))
+            .tag(" ")
+            .limit(0)
+            .offset(20)
+            .cursor("abc");
+        let fields: Vec<_> = Validator::validate_event_query(&query).into_iter().map(|e| e.field).collect();
+        assert_eq!(fields, vec!["end", "tags[0]", "limit", "cursor"]);
+    }
 }
@@ -126,10 +126,11 @@ impl DeepSeekClient {
             .context("Failed to send request")?;
 
         if !response.status().is_success() {
+            let status = response.status();
             let error_body = response.text().await?;
             return Err(anyhow::anyhow!(
                 "API error ({}): {}", 
-                response.status(), 
+                status, 
                 error_body
             ));
         }
@@ -1,7 +1,6 @@
 pub mod client;
 pub mod models;
-pub mod parser;
 pub mod prompts;
 
-pub use client::DeepSeekClient;
+pub use client::{DeepSeekClient, DeepSeekConfig};
 pub use models::{ChatMessage, MessageRole, ApiRequest, ApiResponse, Choice};
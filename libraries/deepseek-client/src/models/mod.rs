@@ -15,7 +15,7 @@ pub enum MessageRole {
     Function,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ApiRequest {
     pub model: String,
     pub messages: Vec<ChatMessage>,
@@ -1,5 +1,7 @@
 pub mod repository;
 pub mod migrations;
+mod query;
+mod search;
 
 pub use repository::CalendarRepository;
 pub use calendar_core::{AppError, AppResult};
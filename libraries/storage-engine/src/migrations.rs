@@ -1,9 +1,41 @@
-pub struct Migrations;
+//! Versioned schema migrations.
+//!
+//! Each migration is applied once, inside its own transaction, and recorded
+//! in `schema_migrations`. Columns are only ever appended, so `SELECT *`
+//! rows keep the layout the repository reads them with.
+//!
+//! Databases written before the runner existed record only version 1 but
+//! may already have some of the later columns and tables, so tables are
+//! created `IF NOT EXISTS` and columns that are already there are skipped.
 
-impl Migrations {
-    pub fn get_migrations() -> Vec<&'static str> {
-        vec![
-            // V1: Initial schema
+use rusqlite::{params, Connection};
+use calendar_core::{AppError, AppResult};
+
+/// One change to the schema
+#[derive(Debug, Clone, Copy)]
+pub enum Step {
+    Sql(&'static str),
+    /// Add a column unless the table already has it
+    AddColumn {
+        table: &'static str,
+        column: &'static str,
+        definition: &'static str,
+    },
+}
+
+/// A numbered set of steps applied together
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    pub version: u32,
+    pub description: &'static str,
+    pub steps: &'static [Step],
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Initial schema",
+        steps: &[Step::Sql(
             r#"
             CREATE TABLE IF NOT EXISTS events (
                 id TEXT PRIMARY KEY,
@@ -20,16 +52,394 @@ impl Migrations {
                 tags TEXT,
                 status TEXT NOT NULL DEFAULT 'confirmed',
                 visibility TEXT NOT NULL DEFAULT 'private',
-                metadata TEXT
+                recurring TEXT,
+                reminder TEXT,
+                location TEXT,
+                metadata TEXT NOT NULL DEFAULT '{}'
             );
+
             CREATE INDEX IF NOT EXISTS idx_events_date ON events(date);
             CREATE INDEX IF NOT EXISTS idx_events_category ON events(category);
             CREATE INDEX IF NOT EXISTS idx_events_priority ON events(priority);
+            "#,
+        )],
+    },
+    Migration {
+        version: 2,
+        description: "Per-event time zones",
+        steps: &[Step::AddColumn { table: "events", column: "timezone", definition: "TEXT" }],
+    },
+    Migration {
+        version: 3,
+        description: "All-day and multi-day events",
+        steps: &[
+            Step::AddColumn { table: "events", column: "end_date", definition: "TEXT" },
+            Step::AddColumn { table: "events", column: "all_day", definition: "INTEGER NOT NULL DEFAULT 0" },
+        ],
+    },
+    Migration {
+        version: 4,
+        description: "Per-occurrence overrides of recurring events",
+        steps: &[Step::Sql(
+            r#"
+            CREATE TABLE IF NOT EXISTS event_overrides (
+                series_id TEXT NOT NULL,
+                recurrence_id TEXT NOT NULL,
+                cancelled INTEGER NOT NULL DEFAULT 0,
+                data TEXT,
+                PRIMARY KEY (series_id, recurrence_id)
+            );
+            "#,
+        )],
+    },
+    Migration {
+        version: 5,
+        description: "Event attendees",
+        steps: &[Step::AddColumn { table: "events", column: "attendees", definition: "TEXT" }],
+    },
+    Migration {
+        version: 6,
+        description: "Tasks",
+        steps: &[Step::Sql(
+            r#"
+            CREATE TABLE IF NOT EXISTS tasks (
+                id TEXT PRIMARY KEY,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                title TEXT NOT NULL,
+                notes TEXT,
+                due_date TEXT,
+                due_time TEXT,
+                priority TEXT NOT NULL DEFAULT 'medium',
+                category TEXT NOT NULL DEFAULT 'other',
+                percent_complete INTEGER NOT NULL DEFAULT 0,
+                completed_at TEXT,
+                parent_id TEXT,
+                tags TEXT
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_tasks_due_date ON tasks(due_date);
+            CREATE INDEX IF NOT EXISTS idx_tasks_parent_id ON tasks(parent_id);
+            "#,
+        )],
+    },
+    Migration {
+        version: 7,
+        description: "User-defined categories",
+        steps: &[Step::Sql(
+            r#"
+            CREATE TABLE IF NOT EXISTS categories (
+                name TEXT PRIMARY KEY,
+                display_name TEXT NOT NULL,
+                color TEXT NOT NULL,
+                icon TEXT,
+                keywords TEXT
+            );
+            "#,
+        )],
+    },
+    Migration {
+        version: 8,
+        description: "Full-text search",
+        steps: &[Step::Sql(
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS events_fts USING fts5(
+                event_id UNINDEXED,
+                title,
+                notes,
+                tags,
+                location,
+                tokenize = 'unicode61 remove_diacritics 2',
+                prefix = '2 3'
+            );
+
+            -- Also covers INSERT OR REPLACE, which does not fire delete triggers
+            CREATE TRIGGER IF NOT EXISTS events_fts_insert AFTER INSERT ON events BEGIN
+                DELETE FROM events_fts WHERE event_id = NEW.id;
+                INSERT INTO events_fts (event_id, title, notes, tags, location) VALUES (
+                    NEW.id,
+                    NEW.event,
+                    NEW.notes,
+                    CASE WHEN json_valid(NEW.tags) THEN (SELECT group_concat(value, ' ') FROM json_each(NEW.tags)) END,
+                    CASE WHEN json_valid(NEW.location) THEN json_extract(NEW.location, '$.address') END
+                );
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS events_fts_update AFTER UPDATE ON events BEGIN
+                DELETE FROM events_fts WHERE event_id = OLD.id;
+                INSERT INTO events_fts (event_id, title, notes, tags, location) VALUES (
+                    NEW.id,
+                    NEW.event,
+                    NEW.notes,
+                    CASE WHEN json_valid(NEW.tags) THEN (SELECT group_concat(value, ' ') FROM json_each(NEW.tags)) END,
+                    CASE WHEN json_valid(NEW.location) THEN json_extract(NEW.location, '$.address') END
+                );
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS events_fts_delete AFTER DELETE ON events BEGIN
+                DELETE FROM events_fts WHERE event_id = OLD.id;
+            END;
+
+            DELETE FROM events_fts;
+            INSERT INTO events_fts (event_id, title, notes, tags, location)
+            SELECT
+                id,
+                event,
+                notes,
+                CASE WHEN json_valid(tags) THEN (SELECT group_concat(value, ' ') FROM json_each(tags)) END,
+                CASE WHEN json_valid(location) THEN json_extract(location, '$.address') END
+            FROM events;
+            "#,
+        )],
+    },
+    Migration {
+        version: 9,
+        description: "Trash for deleted events",
+        steps: &[
+            Step::AddColumn { table: "events", column: "deleted_at", definition: "TEXT" },
+            Step::Sql("CREATE INDEX IF NOT EXISTS idx_events_deleted_at ON events(deleted_at);"),
+        ],
+    },
+];
+
+pub struct Migrations;
+
+impl Migrations {
+    /// Every migration, oldest first
+    pub fn get_migrations() -> &'static [Migration] {
+        MIGRATIONS
+    }
+
+    /// Schema version this build writes
+    pub fn latest_version() -> u32 {
+        MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+    }
+
+    /// Highest version recorded in the database; 0 for a new database
+    pub fn current_version(conn: &Connection) -> AppResult<u32> {
+        let tracked: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = 'schema_migrations'",
+            [],
+            |row| row.get(0),
+        )
+        .map_err(|e| AppError::Database(format!("Schema check failed: {}", e)))?;
+
+        if !tracked {
+            return Ok(0);
+        }
+        conn.query_row("SELECT COALESCE(MAX(version), 0) FROM schema_migrations", [], |row| row.get(0))
+            .map_err(|e| AppError::Database(format!("Schema check failed: {}", e)))
+    }
+
+    /// Bring the database up to [`Self::latest_version`], returning the
+    /// versions applied. Databases written by a newer build are refused
+    /// rather than risk losing what that build stores.
+    pub fn run(conn: &mut Connection) -> AppResult<Vec<u32>> {
+        Self::apply(conn, MIGRATIONS, Self::latest_version())
+    }
+
+    fn apply(conn: &mut Connection, migrations: &[Migration], target: u32) -> AppResult<Vec<u32>> {
+        conn.execute_batch(
+            r#"
             CREATE TABLE IF NOT EXISTS schema_migrations (
                 version INTEGER PRIMARY KEY,
-                applied_at TEXT NOT NULL
+                applied_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
             );
             "#,
-        ]
+        )
+        .map_err(|e| AppError::Database(format!("Schema init failed: {}", e)))?;
+
+        let current = Self::current_version(conn)?;
+        let supported = migrations.last().map(|m| m.version).unwrap_or(0);
+        if current > supported {
+            return Err(AppError::Database(format!(
+                "Database schema version {} is newer than this build supports ({}); please update the app",
+                current, supported
+            )));
+        }
+
+        let mut applied = Vec::new();
+        for migration in migrations.iter().filter(|m| m.version > current && m.version <= target) {
+            let tx = conn.transaction()
+                .map_err(|e| AppError::Database(format!("Transaction failed: {}", e)))?;
+
+            for step in migration.steps {
+                Self::apply_step(&tx, step).map_err(|e| {
+                    AppError::Database(format!("Migration {} ({}) failed: {}", migration.version, migration.description, e))
+                })?;
+            }
+            tx.execute(
+                "INSERT INTO schema_migrations (version, applied_at) VALUES (?1, ?2)",
+                params![migration.version, chrono::Utc::now().to_rfc3339()],
+            )
+            .map_err(|e| AppError::Database(format!("Failed to record migration {}: {}", migration.version, e)))?;
+
+            tx.commit()
+                .map_err(|e| AppError::Database(format!("Commit failed: {}", e)))?;
+            applied.push(migration.version);
+        }
+
+        Ok(applied)
+    }
+
+    fn apply_step(conn: &Connection, step: &Step) -> rusqlite::Result<()> {
+        match *step {
+            Step::Sql(sql) => conn.execute_batch(sql),
+            Step::AddColumn { table, column, definition } => {
+                let exists: bool = conn.query_row(
+                    &format!("SELECT COUNT(*) > 0 FROM pragma_table_info('{}') WHERE name = ?1", table),
+                    [column],
+                    |row| row.get(0),
+                )?;
+                if !exists {
+                    conn.execute_batch(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Column order of `events` that `SELECT *` readers rely on
+    const EVENT_COLUMNS: [&str; 23] = [
+        "id", "created_at", "updated_at", "date", "time", "end_time", "event", "notes", "priority",
+        "category", "color", "tags", "status", "visibility", "recurring", "reminder", "location",
+        "metadata", "timezone", "end_date", "all_day", "attendees", "deleted_at",
+    ];
+
+    fn database_at(version: u32) -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        Migrations::apply(&mut conn, MIGRATIONS, version).unwrap();
+        conn
+    }
+
+    fn columns(conn: &Connection, table: &str) -> Vec<String> {
+        let mut stmt = conn.prepare(&format!("SELECT name FROM pragma_table_info('{}')", table)).unwrap();
+        stmt.query_map([], |row| row.get(0)).unwrap().map(Result::unwrap).collect()
+    }
+
+    fn has_table(conn: &Connection, table: &str) -> bool {
+        conn.query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+            [table],
+            |row| row.get(0),
+        )
+        .unwrap()
+    }
+
+    fn insert_v1_event(conn: &Connection) {
+        conn.execute(
+            "INSERT INTO events (id, created_at, updated_at, date, event, metadata)
+             VALUES ('e1', '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z', '2026-01-05', 'Dentist', '{\"k\":1}')",
+            [],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_versions_are_sequential() {
+        for (i, migration) in Migrations::get_migrations().iter().enumerate() {
+            assert_eq!(migration.version, i as u32 + 1, "{}", migration.description);
+        }
+    }
+
+    #[test]
+    fn test_new_database_gets_latest_schema() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let applied = Migrations::run(&mut conn).unwrap();
+        assert_eq!(applied, (1..=Migrations::latest_version()).collect::<Vec<_>>());
+        assert_eq!(Migrations::current_version(&conn).unwrap(), Migrations::latest_version());
+        assert_eq!(columns(&conn, "events"), EVENT_COLUMNS);
+        for table in ["event_overrides", "tasks", "categories", "events_fts"] {
+            assert!(has_table(&conn, table), "{}", table);
+        }
+
+        // Running again is a no-op
+        assert!(Migrations::run(&mut conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_upgrade_from_every_version() {
+        for version in 1..Migrations::latest_version() {
+            let mut conn = database_at(version);
+            assert_eq!(Migrations::current_version(&conn).unwrap(), version);
+            insert_v1_event(&conn);
+
+            let applied = Migrations::run(&mut conn).unwrap();
+            assert_eq!(applied, (version + 1..=Migrations::latest_version()).collect::<Vec<_>>());
+            assert_eq!(columns(&conn, "events"), EVENT_COLUMNS, "from version {}", version);
+
+            let (title, metadata, all_day): (String, String, i64) = conn
+                .query_row("SELECT event, metadata, all_day FROM events WHERE id = 'e1'", [], |row| {
+                    Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+                })
+                .unwrap();
+            assert_eq!((title.as_str(), metadata.as_str(), all_day), ("Dentist", "{\"k\":1}", 0));
+        }
+    }
+
+    #[test]
+    fn test_untracked_database_upgrades_in_place() {
+        // Written before schema_migrations existed
+        let conn = Connection::open_in_memory().unwrap();
+        if let Step::Sql(sql) = MIGRATIONS[0].steps[0] {
+            conn.execute_batch(sql).unwrap();
+        }
+        insert_v1_event(&conn);
+        assert_eq!(Migrations::current_version(&conn).unwrap(), 0);
+
+        let mut conn = conn;
+        Migrations::run(&mut conn).unwrap();
+        assert_eq!(columns(&conn, "events"), EVENT_COLUMNS);
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM events", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_database_from_before_the_runner_upgrades_in_place() {
+        // Every table and column present, but only version 1 recorded
+        let mut conn = database_at(Migrations::latest_version());
+        conn.execute("DELETE FROM schema_migrations WHERE version > 1", []).unwrap();
+        insert_v1_event(&conn);
+
+        let applied = Migrations::run(&mut conn).unwrap();
+        assert_eq!(applied, (2..=Migrations::latest_version()).collect::<Vec<_>>());
+        assert_eq!(columns(&conn, "events"), EVENT_COLUMNS);
+    }
+
+    #[test]
+    fn test_newer_database_is_refused() {
+        let mut conn = database_at(Migrations::latest_version());
+        conn.execute(
+            "INSERT INTO schema_migrations (version, applied_at) VALUES (?1, 'later')",
+            [Migrations::latest_version() + 1],
+        )
+        .unwrap();
+
+        let err = Migrations::run(&mut conn).unwrap_err();
+        assert!(err.to_string().contains("newer than this build supports"), "{}", err);
+    }
+
+    #[test]
+    fn test_failed_migration_is_rolled_back() {
+        const BROKEN: &[Migration] = &[
+            Migration { version: 1, description: "ok", steps: &[Step::Sql("CREATE TABLE a (x TEXT);")] },
+            Migration {
+                version: 2,
+                description: "broken",
+                steps: &[Step::Sql("CREATE TABLE b (x TEXT);"), Step::Sql("NOT SQL")],
+            },
+        ];
+        let mut conn = Connection::open_in_memory().unwrap();
+        let err = Migrations::apply(&mut conn, BROKEN, 2).unwrap_err();
+        assert!(err.to_string().contains("Migration 2 (broken) failed"), "{}", err);
+
+        assert!(has_table(&conn, "a"));
+        assert!(!has_table(&conn, "b"));
+        assert_eq!(Migrations::current_version(&conn).unwrap(), 1);
     }
 }
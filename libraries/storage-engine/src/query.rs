@@ -0,0 +1,170 @@
+//! Translating [`EventQuery`] filters, sort order and cursors to SQL.
+
+use rusqlite::types::Value;
+use calendar_core::{AppError, AppResult, EventQuery, EventSort};
+use crate::search::match_expression;
+
+/// `WHERE` clause for the filters of `query` and the values bound to its
+/// placeholders, in order. Events in the trash never match.
+pub(crate) fn filter(query: &EventQuery) -> (String, Vec<Value>) {
+    let mut clauses: Vec<String> = vec!["deleted_at IS NULL".to_string()];
+    let mut params: Vec<Value> = Vec::new();
+
+    if let Some(end) = query.end {
+        clauses.push("date <= ?".to_string());
+        params.push(Value::Text(end.to_string()));
+    }
+    if let Some(start) = query.start {
+        // A series reaches the range unless its recurrence ended before it
+        clauses.push(
+            "CASE WHEN recurring IS NULL THEN COALESCE(end_date, date) >= ?
+             ELSE COALESCE(json_extract(recurring, '$.endDate') >= ?, 1) END"
+                .to_string(),
+        );
+        params.push(Value::Text(start.to_string()));
+        params.push(Value::Text(start.to_string()));
+    }
+
+    any_of(&mut clauses, &mut params, "category", query.categories.iter().map(|c| c.as_str()));
+    any_of(&mut clauses, &mut params, "priority", query.priorities.iter().map(|p| p.as_str()));
+    any_of(&mut clauses, &mut params, "status", query.statuses.iter().map(|s| s.as_str()));
+    any_of(&mut clauses, &mut params, "visibility", query.visibilities.iter().map(|v| v.as_str()));
+    none_of(&mut clauses, &mut params, "category", query.exclude_categories.iter().map(|c| c.as_str()));
+    none_of(&mut clauses, &mut params, "priority", query.exclude_priorities.iter().map(|p| p.as_str()));
+    none_of(&mut clauses, &mut params, "status", query.exclude_statuses.iter().map(|s| s.as_str()));
+    none_of(&mut clauses, &mut params, "visibility", query.exclude_visibilities.iter().map(|v| v.as_str()));
+
+    let has_tag = "EXISTS (SELECT 1 FROM json_each(CASE WHEN json_valid(tags) THEN tags ELSE '[]' END)
+                   WHERE lower(value) = lower(?))";
+    for tag in &query.tags {
+        clauses.push(has_tag.to_string());
+        params.push(Value::Text(tag.trim().to_string()));
+    }
+    for tag in &query.exclude_tags {
+        clauses.push(format!("NOT {}", has_tag));
+        params.push(Value::Text(tag.trim().to_string()));
+    }
+
+    let matches = "id IN (SELECT event_id FROM events_fts WHERE events_fts MATCH ?)";
+    if let Some(expression) = query.text.as_deref().and_then(match_expression) {
+        clauses.push(matches.to_string());
+        params.push(Value::Text(expression));
+    }
+    for expression in query.exclude_text.iter().filter_map(|text| match_expression(text)) {
+        clauses.push(format!("NOT {}", matches));
+        params.push(Value::Text(expression));
+    }
+
+    // Older rows hold a single reminder object rather than a list
+    if let Some(has_reminder) = query.has_reminder {
+        let present = "(reminder IS NOT NULL AND reminder NOT IN ('', '[]', 'null'))";
+        clauses.push(if has_reminder { present.to_string() } else { format!("NOT {}", present) });
+    }
+    if let Some(has_location) = query.has_location {
+        let present = "(location IS NOT NULL AND location != 'null')";
+        clauses.push(if has_location { present.to_string() } else { format!("NOT {}", present) });
+    }
+
+    (clauses.join(" AND "), params)
+}
+
+fn any_of<'a>(
+    clauses: &mut Vec<String>,
+    params: &mut Vec<Value>,
+    column: &str,
+    values: impl Iterator<Item = &'a str>,
+) {
+    in_list(clauses, params, column, "IN", values);
+}
+
+fn none_of<'a>(
+    clauses: &mut Vec<String>,
+    params: &mut Vec<Value>,
+    column: &str,
+    values: impl Iterator<Item = &'a str>,
+) {
+    in_list(clauses, params, column, "NOT IN", values);
+}
+
+fn in_list<'a>(
+    clauses: &mut Vec<String>,
+    params: &mut Vec<Value>,
+    column: &str,
+    operator: &str,
+    values: impl Iterator<Item = &'a str>,
+) {
+    let start = params.len();
+    params.extend(values.map(|value| Value::Text(value.to_string())));
+    let count = params.len() - start;
+    if count > 0 {
+        clauses.push(format!("{} {} ({})", column, operator, vec!["?"; count].join(", ")));
+    }
+}
+
+/// Text expression events are ordered by, and whether it ascends. Every
+/// key is a single value so a cursor can resume after any row.
+pub(crate) fn sort_key(sort: EventSort) -> (&'static str, bool) {
+    match sort {
+        EventSort::Start => ("date || ' ' || COALESCE(time, '')", true),
+        EventSort::StartDesc => ("date || ' ' || COALESCE(time, '')", false),
+        EventSort::Priority => (
+            "CASE priority WHEN 'urgent' THEN '0' WHEN 'high' THEN '1' WHEN 'medium' THEN '2' ELSE '3' END
+             || ' ' || date || ' ' || COALESCE(time, '')",
+            true,
+        ),
+        EventSort::Updated => ("updated_at", false),
+        EventSort::Title => ("lower(event)", true),
+    }
+}
+
+/// Cursor resuming after the row with this sort key and id
+pub(crate) fn encode_cursor(key: &str, id: &str) -> String {
+    format!("{}:{}", id, key)
+}
+
+/// Sort key and id of the row a cursor resumes after
+pub(crate) fn decode_cursor(cursor: &str) -> AppResult<(String, String)> {
+    cursor
+        .split_once(':')
+        .filter(|(id, _)| uuid::Uuid::parse_str(id).is_ok())
+        .map(|(id, key)| (key.to_string(), id.to_string()))
+        .ok_or_else(|| AppError::Validation(format!("Invalid cursor: {}", cursor)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calendar_core::{Category, Priority};
+
+    #[test]
+    fn test_filter_sql() {
+        let query = EventQuery::new()
+            .category(Category::Work)
+            .category(Category::Personal)
+            .priority(Priority::Urgent)
+            .tag("Review")
+            .exclude_status(calendar_core::EventStatus::Cancelled)
+            .has_location(false);
+        let (sql, params) = filter(&query);
+        assert!(sql.starts_with("deleted_at IS NULL AND category IN (?, ?) AND priority IN (?) AND status NOT IN (?) AND EXISTS"));
+        assert!(sql.ends_with("NOT (location IS NOT NULL AND location != 'null')"));
+        assert_eq!(params, vec![
+            Value::Text("work".into()),
+            Value::Text("personal".into()),
+            Value::Text("urgent".into()),
+            Value::Text("cancelled".into()),
+            Value::Text("Review".into()),
+        ]);
+
+        assert_eq!(filter(&EventQuery::new()), ("deleted_at IS NULL".to_string(), Vec::new()));
+    }
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let id = uuid::Uuid::new_v4().to_string();
+        let cursor = encode_cursor("2026-03-02 09:00", &id);
+        assert_eq!(decode_cursor(&cursor).unwrap(), ("2026-03-02 09:00".to_string(), id));
+        assert!(decode_cursor("garbage").is_err());
+        assert!(decode_cursor("not-a-uuid:2026").is_err());
+    }
+}
@@ -1,15 +1,28 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
-use rusqlite::{Connection, OptionalExtension};
+use std::sync::{Mutex, MutexGuard};
+use chrono::{NaiveDate, NaiveTime};
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
 use calendar_core::{AppError, AppResult};
-use calendar_core::{CalendarEvent, Category, Priority, EventStatus, Visibility};
+use calendar_core::{CalendarEvent, Category, Priority, EventStatus, Visibility, OccurrenceId, EditScope, Task};
+use calendar_core::{CategoryDefinition, CategoryRegistry, FreeBusy, FreeBusyQuery, SlotProposal, SlotRequest};
+use calendar_core::{Conflict, ConflictCheck, EventPage, EventQuery, RetentionPolicy, SearchHit, TrashedEvent};
+use calendar_core::time::{system_clock, SharedClock, TimeZone};
+use crate::migrations::Migrations;
+use crate::query;
+use crate::search::{match_expression, snippet_parts, HIGHLIGHT_CLOSE, HIGHLIGHT_OPEN};
 
+/// SQLite-backed event store. The connection is guarded by a mutex so the
+/// repository can be shared across threads (e.g. behind an `Arc`).
 pub struct CalendarRepository {
-    connection: Connection,
+    connection: Mutex<Connection>,
+    /// Stamps deletions and edits; retention counts back from its now
+    clock: SharedClock,
 }
 
 impl CalendarRepository {
     pub fn new(db_path: &PathBuf) -> AppResult<Self> {
-        let connection = Connection::open(db_path)
+        let mut connection = Connection::open(db_path)
             .map_err(|e| AppError::Database(format!("Connection failed: {}", e)))?;
 
         connection.pragma_update(None, "journal_mode", "WAL")
@@ -19,57 +32,28 @@ impl CalendarRepository {
         connection.pragma_update(None, "cache_size", "-64000")
             .map_err(|e| AppError::Database(format!("Failed to set cache size: {}", e)))?;
 
-        // Initialize schema
-        Self::init_schema(&connection)?;
+        Migrations::run(&mut connection)?;
 
         Ok(Self {
-            connection,
+            connection: Mutex::new(connection),
+            clock: system_clock(),
         })
     }
 
-    fn init_schema(conn: &Connection) -> AppResult<()> {
-        conn.execute_batch(
-            r#"
-            CREATE TABLE IF NOT EXISTS events (
-                id TEXT PRIMARY KEY,
-                created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL,
-                date TEXT NOT NULL,
-                time TEXT,
-                end_time TEXT,
-                event TEXT NOT NULL,
-                notes TEXT,
-                priority TEXT NOT NULL DEFAULT 'medium',
-                category TEXT NOT NULL DEFAULT 'other',
-                color TEXT,
-                tags TEXT,
-                status TEXT NOT NULL DEFAULT 'confirmed',
-                visibility TEXT NOT NULL DEFAULT 'private',
-                recurring TEXT,
-                reminder TEXT,
-                location TEXT,
-                metadata TEXT NOT NULL DEFAULT '{}'
-            );
-            
-            CREATE INDEX IF NOT EXISTS idx_events_date ON events(date);
-            CREATE INDEX IF NOT EXISTS idx_events_category ON events(category);
-            CREATE INDEX IF NOT EXISTS idx_events_priority ON events(priority);
-            
-            CREATE TABLE IF NOT EXISTS schema_migrations (
-                version INTEGER PRIMARY KEY,
-                applied_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
-            );
-            
-            INSERT OR IGNORE INTO schema_migrations (version) VALUES (1);
-            "#
-        )?;
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
 
-        Ok(())
+    fn connection(&self) -> AppResult<MutexGuard<'_, Connection>> {
+        self.connection.lock()
+            .map_err(|_| AppError::Database("Connection lock poisoned".to_string()))
     }
 
     pub fn get_by_id(&self, id: &str) -> AppResult<Option<CalendarEvent>> {
-        let mut stmt = self.connection.prepare(
-            "SELECT * FROM events WHERE id = ?1"
+        let conn = self.connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT * FROM events WHERE id = ?1 AND deleted_at IS NULL"
         )
         .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
 
@@ -80,18 +64,23 @@ impl CalendarRepository {
         .map_err(|e| AppError::Database(format!("Query failed: {}", e)))
     }
 
-    pub fn get_by_date(&self, date: &str) -> AppResult<Vec<CalendarEvent>> {
-        let mut stmt = self.connection.prepare(
-            "SELECT * FROM events WHERE date = ?1 ORDER BY time ASC"
+    pub fn get_by_date(&self, date: NaiveDate) -> AppResult<Vec<CalendarEvent>> {
+        let conn = self.connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT * FROM events
+             WHERE date <= ?1 AND COALESCE(end_date, date) >= ?1 AND deleted_at IS NULL
+             ORDER BY time ASC"
         )
         .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
 
         let mut events = Vec::new();
-        let mut rows = stmt.query([date])
+        let mut rows = stmt.query([date.to_string()])
             .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?;
 
-        while let Ok(true) = rows.next() {
-            events.push(Self::row_to_event(&rows)?);
+        while let Some(row) = rows.next()
+            .map_err(|e| AppError::Database(format!("Row read failed: {}", e)))? {
+            events.push(Self::row_to_event(row)
+                .map_err(|e| AppError::Database(format!("Row read failed: {}", e)))?);
         }
 
         Ok(events)
@@ -99,101 +88,414 @@ impl CalendarRepository {
 
     pub fn get_by_date_range(
         &self, 
-        start_date: &str, 
-        end_date: &str
+        start_date: NaiveDate, 
+        end_date: NaiveDate
     ) -> AppResult<Vec<CalendarEvent>> {
         // First, get all events (including recurring ones)
-        let mut stmt = self.connection.prepare(
-            "SELECT * FROM events 
-             WHERE (date >= ?1 AND date <= ?2) 
-             OR recurring IS NOT NULL
-             ORDER BY date ASC, time ASC"
-        )
-        .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
-
-        let mut base_events = Vec::new();
-        let mut rows = stmt.query([start_date, end_date])
-            .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?;
+        let conn = self.connection()?;
+        let base_events = Self::load_range_candidates(&conn, start_date, end_date)?;
 
-        while let Ok(true) = rows.next() {
-            base_events.push(Self::row_to_event(&rows)?);
+        // Multi-day occurrences that start before the range can still run
+        // into it, so overrides are loaded from the longest series' lookback
+        let series: Vec<&CalendarEvent> = base_events.iter()
+            .filter(|event| event.recurring.is_some())
+            .collect();
+        let longest_span = series.iter().map(|event| event.span_days()).max().unwrap_or(0);
+        let overrides = Self::load_overrides(
+            &conn,
+            series.iter().map(|event| event.id),
+            start_date - chrono::Duration::days(longest_span),
+            start_date,
+            end_date,
+        )?;
+        let mut moved: HashMap<uuid::Uuid, Vec<(NaiveDate, &CalendarEvent)>> = HashMap::new();
+        for (occurrence, modified) in &overrides {
+            if let Some(modified) = modified {
+                moved.entry(occurrence.series_id)
+                    .or_default()
+                    .push((occurrence.original_date, modified));
+            }
         }
         
         // Expand recurring events into instances
         let mut all_events = Vec::new();
         
-        for event in base_events {
+        for event in &base_events {
             if let Some(ref recurring) = event.recurring {
-                // Generate occurrences for this recurring event
-                let occurrences = recurring.generate_occurrences(&event.date, Some(365));
+                let lookback = start_date - chrono::Duration::days(event.span_days());
                 
-                // Filter occurrences within the requested range
-                for occurrence_date in occurrences {
-                    if occurrence_date >= start_date && occurrence_date <= end_date {
-                        // Create instance of recurring event for this date
-                        let mut instance = event.clone();
-                        instance.date = occurrence_date;
-                        instance.id = uuid::Uuid::new_v4(); // New ID for each instance
+                // Keep occurrences whose span touches the requested range,
+                // after applying any per-occurrence override
+                for occurrence_date in recurring.occurrences_between(event.date, lookback, end_date) {
+                    let occurrence = OccurrenceId::new(event.id, occurrence_date);
+                    let instance = match overrides.get(&occurrence) {
+                        Some(Some(modified)) => modified.clone(),
+                        Some(None) => continue, // Cancelled
+                        None => event.instance(occurrence.original_date),
+                    };
+                    if instance.overlaps_dates(start_date, end_date) {
                         all_events.push(instance);
                     }
                 }
-            } else {
-                // Non-recurring event, add as-is (if in range)
-                if event.date >= start_date && event.date <= end_date {
-                    all_events.push(event);
+                
+                // Occurrences moved into the range from outside it
+                for &(original_date, modified) in moved.get(&event.id).into_iter().flatten() {
+                    let outside = original_date < lookback || original_date > end_date;
+                    if outside
+                        && modified.overlaps_dates(start_date, end_date)
+                        && Self::occurs_on(recurring, event.date, original_date)
+                    {
+                        all_events.push(modified.clone());
+                    }
                 }
+            } else if event.overlaps_dates(start_date, end_date) {
+                // Non-recurring event, add as-is (if in range)
+                all_events.push(event.clone());
             }
         }
         
-        // Sort by date and time
-        all_events.sort_by(|a, b| {
-            match a.date.cmp(&b.date) {
-                std::cmp::Ordering::Equal => {
-                    a.time.as_ref().unwrap_or(&"00:00".to_string())
-                        .cmp(b.time.as_ref().unwrap_or(&"00:00".to_string()))
+        // Sort by start; untimed events sort as midnight
+        all_events.sort_by_key(|event| event.start_naive());
+
+        Ok(all_events)
+    }
+
+    /// Stored events touching the range without expanding series, for
+    /// export. Each recurring series comes once, followed by its edited
+    /// occurrences and, as cancelled instances, its deleted ones; both
+    /// carry `series_id` and `recurrence_id`.
+    pub fn get_series_in_range(
+        &self,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> AppResult<Vec<CalendarEvent>> {
+        let conn = self.connection()?;
+        let stored: Vec<CalendarEvent> = Self::load_range_candidates(&conn, start_date, end_date)?
+            .into_iter()
+            .filter(|event| match &event.recurring {
+                Some(recurring) => {
+                    let lookback = start_date - chrono::Duration::days(event.span_days());
+                    recurring.occurrences_between(event.date, lookback, end_date).next().is_some()
                 }
-                other => other,
+                None => event.overlaps_dates(start_date, end_date),
+            })
+            .collect();
+
+        let series = stored.iter().filter(|event| event.recurring.is_some()).map(|event| event.id);
+        let mut overrides: Vec<_> = Self::query_overrides(&conn, series, "", [])?.into_iter().collect();
+        overrides.sort_by_key(|(occurrence, _)| occurrence.original_date);
+        let mut exceptions: HashMap<uuid::Uuid, Vec<_>> = HashMap::new();
+        for (occurrence, modified) in overrides {
+            exceptions.entry(occurrence.series_id).or_default().push((occurrence.original_date, modified));
+        }
+
+        let mut events = Vec::new();
+        for event in stored {
+            let cancelled = |original_date| {
+                let mut cancelled = event.instance(original_date);
+                cancelled.status = EventStatus::Cancelled;
+                cancelled
+            };
+            let exceptions: Vec<_> = exceptions.remove(&event.id)
+                .into_iter()
+                .flatten()
+                .map(|(original_date, modified)| modified.unwrap_or_else(|| cancelled(original_date)))
+                .collect();
+            events.push(event);
+            events.extend(exceptions);
+        }
+
+        Ok(events)
+    }
+
+    /// Events that may touch the range: those whose dates overlap it and
+    /// every recurring series, in start order
+    fn load_range_candidates(
+        conn: &Connection,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> AppResult<Vec<CalendarEvent>> {
+        let mut stmt = conn.prepare(
+            "SELECT * FROM events 
+             WHERE ((date <= ?2 AND COALESCE(end_date, date) >= ?1) OR recurring IS NOT NULL)
+             AND deleted_at IS NULL
+             ORDER BY date ASC, time ASC"
+        )
+        .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
+
+        let mut events = Vec::new();
+        let mut rows = stmt.query([start_date.to_string(), end_date.to_string()])
+            .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?;
+
+        while let Some(row) = rows.next()
+            .map_err(|e| AppError::Database(format!("Row read failed: {}", e)))? {
+            events.push(Self::row_to_event(row)
+                .map_err(|e| AppError::Database(format!("Row read failed: {}", e)))?);
+        }
+
+        Ok(events)
+    }
+
+    /// Resolve an occurrence id to the expanded instance of its series.
+    /// Returns `None` if the series is gone or does not occur on that date.
+    pub fn get_occurrence(&self, occurrence: &OccurrenceId) -> AppResult<Option<CalendarEvent>> {
+        let series = match self.get_by_id(&occurrence.series_id.to_string())? {
+            Some(series) => series,
+            None => return Ok(None),
+        };
+
+        let occurs = series.recurring.as_ref()
+            .is_some_and(|r| Self::occurs_on(r, series.date, occurrence.original_date));
+
+        if !occurs {
+            return Ok(None);
+        }
+
+        let date = occurrence.original_date;
+        let overrides = Self::load_overrides(&*self.connection()?, [series.id], date, date, date)?;
+        Ok(match overrides.get(occurrence) {
+            Some(modified) => modified.clone(),
+            None => Some(series.instance(occurrence.original_date)),
+        })
+    }
+
+    fn occurs_on(recurring: &calendar_core::RecurrenceConfig, start_date: NaiveDate, date: NaiveDate) -> bool {
+        recurring.occurrences_between(start_date, date, date).next().is_some()
+    }
+
+    /// Save an edited occurrence of a recurring series.
+    ///
+    /// `This` stores an override for the one occurrence, `ThisAndFollowing`
+    /// ends the series before the occurrence and starts a new series from
+    /// the edited copy, which takes over the later overrides, and `All` applies the edit to the series itself,
+    /// shifting it and its overrides by however many days the occurrence
+    /// was moved. Events that are not occurrences are saved as-is, and
+    /// `This` fails with `NotFound` for dates the series never hits.
+    pub fn update_occurrence(&self, event: &CalendarEvent, scope: EditScope) -> AppResult<()> {
+        let occurrence = match event.occurrence_id() {
+            Some(occurrence) => occurrence,
+            None => return self.save_event(event),
+        };
+        let series = self.get_by_id(&occurrence.series_id.to_string())?
+            .ok_or(AppError::NotFound)?;
+
+        let mut conn = self.connection()?;
+        let tx = conn.transaction()
+            .map_err(|e| AppError::Database(format!("Transaction failed: {}", e)))?;
+
+        match scope {
+            EditScope::This => {
+                let occurs = series.recurring.as_ref()
+                    .is_some_and(|r| Self::occurs_on(r, series.date, occurrence.original_date));
+                if !occurs {
+                    return Err(AppError::NotFound);
+                }
+                let mut modified = event.clone();
+                modified.id = occurrence.instance_uuid();
+                Self::write_override(&tx, &occurrence, Some(&modified))?;
             }
-        });
+            EditScope::ThisAndFollowing if occurrence.original_date != series.date => {
+                let rule = series.recurring.clone().ok_or(AppError::NotFound)?;
+                let (head, tail) = rule.split_at(series.date, occurrence.original_date);
 
-        Ok(all_events)
+                let mut truncated = series.clone();
+                truncated.recurring = Some(head);
+                truncated.updated_at = self.clock.now();
+                Self::write_event(&tx, &truncated)?;
+
+                // Later occurrences move with the edited one, keeping their
+                // exceptions and overrides
+                let shift = event.date - occurrence.original_date;
+                let mut tail = tail;
+                for date in &mut tail.except_dates {
+                    *date += shift;
+                }
+
+                let mut continuation = event.clone();
+                continuation.id = uuid::Uuid::new_v4();
+                continuation.created_at = self.clock.now();
+                continuation.updated_at = continuation.created_at;
+                continuation.series_id = None;
+                continuation.recurrence_id = None;
+                continuation.recurring = Some(tail);
+                Self::write_event(&tx, &continuation)?;
+
+                let later = OccurrenceId::new(series.id, occurrence.original_date + chrono::Duration::days(1));
+                Self::move_overrides(&tx, &later, continuation.id, shift)?;
+                Self::delete_overrides_from(&tx, &occurrence)?;
+            }
+            EditScope::ThisAndFollowing | EditScope::All => {
+                // Move the series by as many days as the occurrence moved
+                let start = series.date + (event.date - occurrence.original_date);
+
+                let mut updated = event.clone();
+                updated.id = series.id;
+                updated.created_at = series.created_at;
+                updated.series_id = None;
+                updated.recurrence_id = None;
+                updated.shift_to(start);
+                Self::write_event(&tx, &updated)?;
+
+                let shift = start - series.date;
+                if !shift.is_zero() {
+                    let first = OccurrenceId::new(series.id, series.date);
+                    Self::move_overrides(&tx, &first, series.id, shift)?;
+                }
+            }
+        }
+
+        tx.commit()
+            .map_err(|e| AppError::Database(format!("Commit failed: {}", e)))
+    }
+
+    /// Delete part of a recurring series: one occurrence (stored as a
+    /// cancelled override), this and all later occurrences, or the whole
+    /// series, which goes to the trash. Returns `false` if the series does
+    /// not exist, and `NotFound` if it never occurs on the date.
+    pub fn delete_occurrence(&self, occurrence: &OccurrenceId, scope: EditScope) -> AppResult<bool> {
+        let series = match self.get_by_id(&occurrence.series_id.to_string())? {
+            Some(series) => series,
+            None => return Ok(false),
+        };
+
+        match (scope, &series.recurring) {
+            (EditScope::This, Some(rule)) => {
+                if !Self::occurs_on(rule, series.date, occurrence.original_date) {
+                    return Err(AppError::NotFound);
+                }
+                Self::write_override(&*self.connection()?, occurrence, None)?;
+                Ok(true)
+            }
+            (EditScope::ThisAndFollowing, Some(rule)) if occurrence.original_date != series.date => {
+                let (head, _) = rule.split_at(series.date, occurrence.original_date);
+                let mut truncated = series.clone();
+                truncated.recurring = Some(head);
+                truncated.updated_at = self.clock.now();
+
+                let mut conn = self.connection()?;
+                let tx = conn.transaction()
+                    .map_err(|e| AppError::Database(format!("Transaction failed: {}", e)))?;
+                Self::write_event(&tx, &truncated)?;
+                Self::delete_overrides_from(&tx, occurrence)?;
+                tx.commit()
+                    .map_err(|e| AppError::Database(format!("Commit failed: {}", e)))?;
+                Ok(true)
+            }
+            _ => self.delete_event(&series.id.to_string()),
+        }
     }
 
     pub fn save_event(&self, event: &CalendarEvent) -> AppResult<()> {
+        let conn = self.connection()?;
+        Self::write_event(&conn, event)
+    }
+
+    /// Apply a JSON Merge Patch (RFC 7396) to a stored event and save it.
+    /// Dates and times in the patch are read in `zone`, the zone the
+    /// client shows the event in.
+    ///
+    /// The read, merge and write happen under one lock and transaction, so
+    /// concurrent patches to different fields do not overwrite each other.
+    pub fn patch_event(&self, id: &str, patch: &serde_json::Value, zone: TimeZone) -> AppResult<CalendarEvent> {
+        self.modify_event(id, |current| {
+            let mut patched = current.apply_patch_in_zone(patch, zone)?;
+            patched.updated_at = self.clock.now();
+            Ok(patched)
+        })
+    }
+
+    /// Record an attendee's RSVP on a stored event
+    pub fn set_attendee_status(
+        &self,
+        id: &str,
+        email: &str,
+        status: calendar_core::ParticipationStatus,
+    ) -> AppResult<CalendarEvent> {
+        self.modify_event(id, |current| {
+            let mut updated = current.clone();
+            updated.set_attendee_status(email, status)?;
+            updated.updated_at = self.clock.now();
+            Ok(updated)
+        })
+    }
+
+    /// Read-modify-write of one event inside a single transaction
+    fn modify_event(
+        &self,
+        id: &str,
+        modify: impl FnOnce(&CalendarEvent) -> AppResult<CalendarEvent>,
+    ) -> AppResult<CalendarEvent> {
+        let mut conn = self.connection()?;
+        let tx = conn.transaction()
+            .map_err(|e| AppError::Database(format!("Transaction failed: {}", e)))?;
+
+        let current = tx.query_row(
+            "SELECT * FROM events WHERE id = ?1 AND deleted_at IS NULL",
+            [id],
+            Self::row_to_event,
+        )
+        .optional()
+        .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?
+        .ok_or(AppError::NotFound)?;
+
+        let updated = modify(&current)?;
+        Self::write_event(&tx, &updated)?;
+        tx.commit()
+            .map_err(|e| AppError::Database(format!("Commit failed: {}", e)))?;
+        Ok(updated)
+    }
+
+    /// Insert an event or update the stored one. A trashed event stays in
+    /// the trash; only `restore_event` takes it out.
+    fn write_event(conn: &Connection, event: &CalendarEvent) -> AppResult<()> {
         let recurring_json = event.recurring.as_ref()
             .and_then(|r| serde_json::to_string(r).ok());
-        let reminder_json = event.reminder.as_ref()
-            .and_then(|r| serde_json::to_string(r).ok());
+        let reminder_json = serde_json::to_string(&event.reminders).ok();
         let location_json = event.location.as_ref()
             .and_then(|l| serde_json::to_string(l).ok());
         let metadata_json = serde_json::to_string(&event.metadata)
             .unwrap_or_else(|_| "{}".to_string());
         
-        self.connection.execute(
-            r#"INSERT OR REPLACE INTO events (
+        conn.execute(
+            r#"INSERT INTO events (
                 id, created_at, updated_at, date, time, end_time,
                 event, notes, priority, category, color, tags,
-                status, visibility, recurring, reminder, location, metadata
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)"#,
-            &[
-                &event.id.to_string(),
-                &event.created_at.to_rfc3339(),
-                &event.updated_at.to_rfc3339(),
-                &event.date,
-                event.time.as_ref(),
-                event.end_time.as_ref(),
-                &event.event,
-                event.notes.as_ref(),
-                &event.priority.as_str(),
-                &event.category.as_str(),
-                event.color.as_ref(),
-                &serde_json::to_string(&event.tags).ok().as_ref(),
-                &event.status.as_str(),
-                &event.visibility.as_str(),
-                recurring_json.as_ref(),
-                reminder_json.as_ref(),
-                location_json.as_ref(),
-                &metadata_json,
+                status, visibility, recurring, reminder, location, metadata,
+                timezone, end_date, all_day, attendees
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22)
+            ON CONFLICT(id) DO UPDATE SET
+                created_at = excluded.created_at, updated_at = excluded.updated_at,
+                date = excluded.date, time = excluded.time, end_time = excluded.end_time,
+                event = excluded.event, notes = excluded.notes, priority = excluded.priority,
+                category = excluded.category, color = excluded.color, tags = excluded.tags,
+                status = excluded.status, visibility = excluded.visibility,
+                recurring = excluded.recurring, reminder = excluded.reminder,
+                location = excluded.location, metadata = excluded.metadata,
+                timezone = excluded.timezone, end_date = excluded.end_date,
+                all_day = excluded.all_day, attendees = excluded.attendees"#,
+            params![
+                event.id.to_string(),
+                event.created_at.to_rfc3339(),
+                event.updated_at.to_rfc3339(),
+                event.date.to_string(),
+                event.time.map(|t| t.format("%H:%M").to_string()),
+                event.end_time.map(|t| t.format("%H:%M").to_string()),
+                event.event,
+                event.notes,
+                event.priority.as_str(),
+                event.category.as_str(),
+                event.color,
+                serde_json::to_string(&event.tags).ok(),
+                event.status.as_str(),
+                event.visibility.as_str(),
+                recurring_json,
+                reminder_json,
+                location_json,
+                metadata_json,
+                event.timezone,
+                event.end_date.map(|d| d.to_string()),
+                event.all_day,
+                serde_json::to_string(&event.attendees).ok(),
             ],
         )
         .map_err(|e| AppError::Database(format!("Save failed: {}", e)))?;
@@ -201,19 +503,357 @@ impl CalendarRepository {
         Ok(())
     }
 
+    /// Move an event to the trash. It keeps its overrides, so restoring a
+    /// series brings back its edited and cancelled occurrences too.
+    /// Returns `false` if there is no such event outside the trash.
     pub fn delete_event(&self, id: &str) -> AppResult<bool> {
-        let rows_affected = self.connection.execute(
-            "DELETE FROM events WHERE id = ?1",
-            [id],
+        let rows_affected = self.connection()?.execute(
+            "UPDATE events SET deleted_at = ?2 WHERE id = ?1 AND deleted_at IS NULL",
+            params![id, self.clock.now().to_rfc3339()],
         )
         .map_err(|e| AppError::Database(format!("Delete failed: {}", e)))?;
 
         Ok(rows_affected > 0)
     }
 
+    /// Events in the trash, most recently deleted first
+    pub fn get_trash(&self) -> AppResult<Vec<TrashedEvent>> {
+        let conn = self.connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT * FROM events WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC"
+        )
+        .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
+
+        let rows = stmt.query_map([], |row| {
+            let deleted_at: String = row.get("deleted_at")?;
+            Ok(TrashedEvent {
+                event: Self::row_to_event(row)?,
+                deleted_at: deleted_at.parse().unwrap_or_else(|_| self.clock.now()),
+            })
+        })
+        .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::Database(format!("Row read failed: {}", e)))
+    }
+
+    /// Take an event back out of the trash. Returns `false` if it is not
+    /// in the trash.
+    pub fn restore_event(&self, id: &str) -> AppResult<bool> {
+        let rows_affected = self.connection()?.execute(
+            "UPDATE events SET deleted_at = NULL WHERE id = ?1 AND deleted_at IS NOT NULL",
+            [id],
+        )
+        .map_err(|e| AppError::Database(format!("Restore failed: {}", e)))?;
+
+        Ok(rows_affected > 0)
+    }
+
+    /// Permanently delete an event in the trash, with its overrides.
+    /// Returns `false` if it is not in the trash.
+    pub fn purge_event(&self, id: &str) -> AppResult<bool> {
+        self.purge("id = ?1", id).map(|purged| purged > 0)
+    }
+
+    /// Permanently delete events that went to the trash no later than
+    /// `before`, returning how many were purged
+    pub fn purge_trash(&self, before: chrono::DateTime<chrono::Utc>) -> AppResult<usize> {
+        self.purge("deleted_at <= ?1", &before.to_rfc3339())
+    }
+
+    /// Apply a retention policy, purging whatever has been in the trash
+    /// longer than it allows
+    pub fn apply_retention(&self, policy: &RetentionPolicy) -> AppResult<usize> {
+        self.purge_trash(policy.cutoff(self.clock.now()))
+    }
+
+    /// Delete trashed events matching `condition` and their overrides
+    fn purge(&self, condition: &str, param: &str) -> AppResult<usize> {
+        let mut conn = self.connection()?;
+        let tx = conn.transaction()
+            .map_err(|e| AppError::Database(format!("Transaction failed: {}", e)))?;
+
+        tx.execute(
+            &format!(
+                "DELETE FROM event_overrides WHERE series_id IN
+                 (SELECT id FROM events WHERE deleted_at IS NOT NULL AND {})",
+                condition
+            ),
+            [param],
+        )
+        .map_err(|e| AppError::Database(format!("Purge failed: {}", e)))?;
+        let purged = tx.execute(
+            &format!("DELETE FROM events WHERE deleted_at IS NOT NULL AND {}", condition),
+            [param],
+        )
+        .map_err(|e| AppError::Database(format!("Purge failed: {}", e)))?;
+
+        tx.commit()
+            .map_err(|e| AppError::Database(format!("Commit failed: {}", e)))?;
+        Ok(purged)
+    }
+
+    /// Overrides of the given series for occurrences originally on
+    /// `from..=to`, plus those moved to overlap `start..=end` from other
+    /// dates; `None` marks a cancelled occurrence
+    fn load_overrides(
+        conn: &Connection,
+        series: impl IntoIterator<Item = uuid::Uuid>,
+        from: NaiveDate,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> AppResult<HashMap<OccurrenceId, Option<CalendarEvent>>> {
+        Self::query_overrides(
+            conn,
+            series,
+            "AND (recurrence_id BETWEEN ?2 AND ?4
+                  OR (cancelled = 0
+                      AND json_extract(data, '$.date') <= ?4
+                      AND COALESCE(json_extract(data, '$.endDate'), json_extract(data, '$.date')) >= ?3))",
+            [from.to_string(), start.to_string(), end.to_string()],
+        )
+    }
+
+    /// Overrides of the given series matching `condition`, whose
+    /// placeholders start at `?2`
+    fn query_overrides<const N: usize>(
+        conn: &Connection,
+        series: impl IntoIterator<Item = uuid::Uuid>,
+        condition: &str,
+        condition_params: [String; N],
+    ) -> AppResult<HashMap<OccurrenceId, Option<CalendarEvent>>> {
+        let series_ids: Vec<String> = series.into_iter().map(|id| id.to_string()).collect();
+        let mut overrides = HashMap::new();
+        if series_ids.is_empty() {
+            return Ok(overrides);
+        }
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT series_id, recurrence_id, cancelled, data FROM event_overrides
+             WHERE series_id IN (SELECT value FROM json_each(?1)) {}",
+            condition
+        ))
+        .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
+
+        let series_json = serde_json::to_string(&series_ids)
+            .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?;
+        let params = std::iter::once(series_json).chain(condition_params);
+        let mut rows = stmt.query(params_from_iter(params))
+            .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?;
+
+        while let Some(row) = rows.next()
+            .map_err(|e| AppError::Database(format!("Row read failed: {}", e)))? {
+            let read = |e: rusqlite::Error| AppError::Database(format!("Row read failed: {}", e));
+            let series_id: String = row.get(0).map_err(read)?;
+            let recurrence_id: String = row.get(1).map_err(read)?;
+            let cancelled: bool = row.get(2).map_err(read)?;
+            let data: Option<String> = row.get(3).map_err(read)?;
+
+            let (series_id, recurrence_id) = match (series_id.parse(), recurrence_id.parse()) {
+                (Ok(id), Ok(date)) => (id, date),
+                _ => continue,
+            };
+            let modified = if cancelled {
+                None
+            } else {
+                match data.as_deref().and_then(|d| serde_json::from_str(d).ok()) {
+                    Some(event) => Some(event),
+                    None => continue,
+                }
+            };
+            overrides.insert(OccurrenceId::new(series_id, recurrence_id), modified);
+        }
+
+        Ok(overrides)
+    }
+
+    fn write_override(
+        conn: &Connection,
+        occurrence: &OccurrenceId,
+        modified: Option<&CalendarEvent>,
+    ) -> AppResult<()> {
+        let data = modified.and_then(|event| serde_json::to_string(event).ok());
+        conn.execute(
+            "INSERT OR REPLACE INTO event_overrides (series_id, recurrence_id, cancelled, data)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                occurrence.series_id.to_string(),
+                occurrence.original_date.to_string(),
+                modified.is_none(),
+                data,
+            ],
+        )
+        .map_err(|e| AppError::Database(format!("Save override failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Re-key the overrides on and after an occurrence to the same
+    /// occurrences of `target`, whose dates are `shift` from the old ones
+    fn move_overrides(
+        conn: &Connection,
+        from: &OccurrenceId,
+        target: uuid::Uuid,
+        shift: chrono::Duration,
+    ) -> AppResult<()> {
+        let moved = Self::query_overrides(
+            conn,
+            [from.series_id],
+            "AND recurrence_id >= ?2",
+            [from.original_date.to_string()],
+        )?;
+        Self::delete_overrides_from(conn, from)?;
+
+        for (occurrence, modified) in moved {
+            let occurrence = OccurrenceId::new(target, occurrence.original_date + shift);
+            let modified = modified.map(|mut event| {
+                event.shift_to(event.date + shift);
+                event.id = occurrence.instance_uuid();
+                event.series_id = Some(occurrence.series_id);
+                event.recurrence_id = Some(occurrence.original_date);
+                event
+            });
+            Self::write_override(conn, &occurrence, modified.as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove overrides on and after an occurrence, once the series no
+    /// longer reaches them
+    fn delete_overrides_from(conn: &Connection, occurrence: &OccurrenceId) -> AppResult<()> {
+        conn.execute(
+            "DELETE FROM event_overrides WHERE series_id = ?1 AND recurrence_id >= ?2",
+            params![occurrence.series_id.to_string(), occurrence.original_date.to_string()],
+        )
+        .map_err(|e| AppError::Database(format!("Delete override failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Events matching `query`. With search text, the best full-text
+    /// matches of title, notes, tags and location come first (title
+    /// matches weigh most) with a snippet of the matching text; words must
+    /// all match, `"quoted phrases"` match in order and `prefix*` matches
+    /// the start of a word. Without it, events come in the query's order.
+    /// At most one page is returned.
+    pub fn search(&self, query: &EventQuery) -> AppResult<Vec<SearchHit>> {
+        let Some(expression) = query.text.as_deref().and_then(match_expression) else {
+            let page = self.query_events(query)?;
+            return Ok(page.events.into_iter().map(|event| SearchHit {
+                event,
+                score: 0.0,
+                snippet: Vec::new(),
+            }).collect());
+        };
+        query.validate()?;
+
+        // Other filters run against `events` alone, where column names
+        // are unambiguous
+        let filters = EventQuery { text: None, ..query.clone() };
+        let (filter, filter_params) = query::filter(&filters);
+        let mut params: Vec<rusqlite::types::Value> = vec![
+            expression.into(),
+            HIGHLIGHT_OPEN.to_string().into(),
+            HIGHLIGHT_CLOSE.to_string().into(),
+        ];
+        params.extend(filter_params);
+        params.extend([(query.page_size() as i64).into(), (query.offset as i64).into()]);
+
+        let conn = self.connection()?;
+        let mut stmt = conn.prepare(&format!(
+            r#"SELECT events.*,
+                    bm25(events_fts, 0.0, 10.0, 2.0, 5.0, 3.0) AS rank,
+                    snippet(events_fts, -1, ?2, ?3, '…', 12) AS snippet
+               FROM events_fts
+               JOIN events ON events.id = events_fts.event_id
+               WHERE events_fts MATCH ?1
+                 AND events.id IN (SELECT id FROM events WHERE {})
+               ORDER BY rank, events.date
+               LIMIT ? OFFSET ?"#,
+            filter
+        ))
+        .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
+
+        let rows = stmt.query_map(params_from_iter(&params), |row| {
+            let rank: f64 = row.get("rank")?;
+            let snippet: String = row.get("snippet")?;
+            Ok(SearchHit {
+                event: Self::row_to_event(row)?,
+                score: -rank,
+                snippet: snippet_parts(&snippet),
+            })
+        })
+        .map_err(|e| AppError::Database(format!("Search failed: {}", e)))?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::Database(format!("Search failed: {}", e)))
+    }
+
+    /// One page of the stored events matching `query`, in its sort order.
+    /// Recurring series are returned once, not per occurrence.
+    pub fn query_events(&self, query: &EventQuery) -> AppResult<EventPage> {
+        query.validate()?;
+        let (filter, mut params) = query::filter(query);
+        let conn = self.connection()?;
+
+        let total: u64 = conn.query_row(
+            &format!("SELECT COUNT(*) FROM events WHERE {}", filter),
+            params_from_iter(&params),
+            |row| row.get(0),
+        )
+        .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?;
+
+        let (key, ascending) = query::sort_key(query.sort);
+        let (direction, after) = if ascending { ("ASC", ">") } else { ("DESC", "<") };
+        let mut page_filter = filter;
+        if let Some(cursor) = &query.cursor {
+            let (last_key, last_id) = query::decode_cursor(cursor)?;
+            page_filter = format!(
+                "({}) AND (({key}) {after} ? OR (({key}) = ? AND id {after} ?))",
+                page_filter,
+                key = key,
+                after = after
+            );
+            params.extend([last_key.clone().into(), last_key.into(), last_id.into()]);
+        }
+
+        // One extra row tells whether there is another page
+        let limit = query.page_size();
+        params.extend([((limit + 1) as i64).into(), (query.offset as i64).into()]);
+        let mut stmt = conn.prepare(&format!(
+            "SELECT *, {key} AS sort_key FROM events WHERE {filter}
+             ORDER BY sort_key {direction}, id {direction} LIMIT ? OFFSET ?",
+            key = key,
+            filter = page_filter,
+            direction = direction
+        ))
+        .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
+
+        let mut rows = stmt.query_map(params_from_iter(&params), |row| {
+            Ok((Self::row_to_event(row)?, row.get::<_, String>("sort_key")?))
+        })
+        .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Database(format!("Row read failed: {}", e)))?;
+
+        let next_cursor = if rows.len() > limit {
+            rows.truncate(limit);
+            rows.last().map(|(event, key)| query::encode_cursor(key, &event.id.to_string()))
+        } else {
+            None
+        };
+
+        Ok(EventPage {
+            events: rows.into_iter().map(|(event, _)| event).collect(),
+            total,
+            next_cursor,
+        })
+    }
+
     pub fn count(&self) -> AppResult<u64> {
-        let count: i64 = self.connection.query_row(
-            "SELECT COUNT(*) FROM events",
+        let count: i64 = self.connection()?.query_row(
+            "SELECT COUNT(*) FROM events WHERE deleted_at IS NULL",
             [],
             |row| row.get(0),
         )
@@ -226,9 +866,13 @@ impl CalendarRepository {
         let id: String = row.get(0)?;
         let created_at: String = row.get(1)?;
         let updated_at: String = row.get(2)?;
-        let date: String = row.get(3)?;
-        let time: Option<String> = row.get(4)?;
-        let end_time: Option<String> = row.get(5)?;
+        let date: NaiveDate = parse_column(3, &row.get::<_, String>(3)?)?;
+        let time: Option<NaiveTime> = row.get::<_, Option<String>>(4)?
+            .map(|t| parse_time_column(4, &t))
+            .transpose()?;
+        let end_time: Option<NaiveTime> = row.get::<_, Option<String>>(5)?
+            .map(|t| parse_time_column(5, &t))
+            .transpose()?;
         let event: String = row.get(6)?;
         let notes: Option<String> = row.get(7)?;
         let priority: String = row.get(8)?;
@@ -241,6 +885,12 @@ impl CalendarRepository {
         let reminder_str: Option<String> = row.get(15)?;
         let location_str: Option<String> = row.get(16)?;
         let metadata_str: Option<String> = row.get(17)?;
+        let timezone: Option<String> = row.get("timezone")?;
+        let end_date: Option<NaiveDate> = row.get::<_, Option<String>>("end_date")?
+            .map(|d| parse_column(row.as_ref().column_index("end_date")?, &d))
+            .transpose()?;
+        let all_day: bool = row.get("all_day")?;
+        let attendees_str: Option<String> = row.get("attendees")?;
 
         let tags: Vec<String> = tags_str
             .as_ref()
@@ -251,14 +901,25 @@ impl CalendarRepository {
             .as_ref()
             .and_then(|s| serde_json::from_str(s).ok());
 
-        let reminder: Option<calendar_core::ReminderConfig> = reminder_str
+        // Rows written before multiple reminders hold a single object
+        let reminders: Vec<calendar_core::ReminderConfig> = reminder_str
             .as_ref()
-            .and_then(|s| serde_json::from_str(s).ok());
+            .and_then(|s| {
+                serde_json::from_str(s)
+                    .or_else(|_| serde_json::from_str(s).map(|reminder| vec![reminder]))
+                    .ok()
+            })
+            .unwrap_or_default();
 
         let location: Option<calendar_core::Location> = location_str
             .as_ref()
             .and_then(|s| serde_json::from_str(s).ok());
 
+        let attendees: Vec<calendar_core::Attendee> = attendees_str
+            .as_ref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default();
+
         let metadata: serde_json::Value = metadata_str
             .as_ref()
             .and_then(|s| serde_json::from_str(s).ok())
@@ -271,6 +932,8 @@ impl CalendarRepository {
             date,
             time,
             end_time,
+            end_date,
+            all_day,
             event,
             notes,
             priority: priority.parse().unwrap_or(Priority::Medium),
@@ -280,76 +943,367 @@ impl CalendarRepository {
             status: status.parse().unwrap_or(EventStatus::Confirmed),
             visibility: visibility.parse().unwrap_or(Visibility::Private),
             recurring,
-            reminder,
+            series_id: None,
+            recurrence_id: None,
+            reminders,
             location,
+            attendees,
+            timezone,
             metadata,
         })
     }
 
-    /// Check for conflicting events (overlapping time on same date)
-    pub fn check_conflicts(&self, event: &CalendarEvent) -> AppResult<Vec<String>> {
-        if event.time.is_none() || event.end_time.is_none() {
-            return Ok(Vec::new()); // All-day or no-time events don't conflict
+    pub fn save_task(&self, task: &Task) -> AppResult<()> {
+        self.connection()?.execute(
+            r#"INSERT OR REPLACE INTO tasks (
+                id, created_at, updated_at, title, notes, due_date, due_time,
+                priority, category, percent_complete, completed_at, parent_id, tags
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)"#,
+            params![
+                task.id.to_string(),
+                task.created_at.to_rfc3339(),
+                task.updated_at.to_rfc3339(),
+                task.title,
+                task.notes,
+                task.due_date.map(|d| d.to_string()),
+                task.due_time.map(|t| t.format("%H:%M").to_string()),
+                task.priority.as_str(),
+                task.category.as_str(),
+                task.percent_complete,
+                task.completed_at.map(|at| at.to_rfc3339()),
+                task.parent_id.map(|id| id.to_string()),
+                serde_json::to_string(&task.tags).ok(),
+            ],
+        )
+        .map_err(|e| AppError::Database(format!("Save task failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    pub fn get_task(&self, id: &str) -> AppResult<Option<Task>> {
+        self.connection()?
+            .query_row("SELECT * FROM tasks WHERE id = ?1", [id], Self::row_to_task)
+            .optional()
+            .map_err(|e| AppError::Database(format!("Query failed: {}", e)))
+    }
+
+    /// Tasks not yet completed, soonest due first; undated tasks last
+    pub fn get_open_tasks(&self) -> AppResult<Vec<Task>> {
+        self.query_tasks("WHERE completed_at IS NULL", [])
+    }
+
+    /// Every task, completed or not
+    pub fn get_tasks(&self) -> AppResult<Vec<Task>> {
+        self.query_tasks("", [])
+    }
+
+    /// Direct subtasks of a task
+    pub fn get_subtasks(&self, parent_id: &str) -> AppResult<Vec<Task>> {
+        self.query_tasks("WHERE parent_id = ?1", [parent_id])
+    }
+
+    fn query_tasks<P: rusqlite::Params>(&self, filter: &str, params: P) -> AppResult<Vec<Task>> {
+        let conn = self.connection()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT * FROM tasks {}
+             ORDER BY due_date IS NULL, due_date, due_time IS NULL, due_time, created_at",
+            filter
+        ))
+        .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
+
+        let rows = stmt.query_map(params, Self::row_to_task)
+            .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::Database(format!("Row read failed: {}", e)))
+    }
+
+    /// Mark a task and all of its subtasks done. Subtasks completed earlier
+    /// keep their completion time.
+    pub fn complete_task(&self, id: &str) -> AppResult<Task> {
+        let now = self.clock.now().to_rfc3339();
+        let rows_affected = self.connection()?.execute(
+            r#"WITH RECURSIVE subtree(id) AS (
+                SELECT ?1
+                UNION
+                SELECT tasks.id FROM tasks JOIN subtree ON tasks.parent_id = subtree.id
+            )
+            UPDATE tasks
+            SET completed_at = COALESCE(completed_at, ?2), percent_complete = 100, updated_at = ?2
+            WHERE id IN subtree"#,
+            params![id, now],
+        )
+        .map_err(|e| AppError::Database(format!("Complete task failed: {}", e)))?;
+
+        if rows_affected == 0 {
+            return Err(AppError::NotFound);
         }
+        self.get_task(id)?.ok_or(AppError::NotFound)
+    }
 
-        let mut conflicts = Vec::new();
-        
-        let mut stmt = self.connection.prepare(
-            "SELECT id FROM events 
-             WHERE date = ?1 
-             AND id != ?2
-             AND time IS NOT NULL 
-             AND end_time IS NOT NULL"
+    /// Delete a task together with its subtasks
+    pub fn delete_task(&self, id: &str) -> AppResult<bool> {
+        let rows_affected = self.connection()?.execute(
+            r#"WITH RECURSIVE subtree(id) AS (
+                SELECT ?1
+                UNION
+                SELECT tasks.id FROM tasks JOIN subtree ON tasks.parent_id = subtree.id
+            )
+            DELETE FROM tasks WHERE id IN subtree"#,
+            [id],
         )
-        .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
+        .map_err(|e| AppError::Database(format!("Delete task failed: {}", e)))?;
 
-        let rows = stmt.query_map(
-            [&event.date, &event.id.to_string()],
-            |row| {
-                let other_id: String = row.get(0)?;
-                Ok(other_id)
-            }
+        Ok(rows_affected > 0)
+    }
+
+    fn row_to_task(row: &rusqlite::Row) -> Result<Task, rusqlite::Error> {
+        let column = |name: &str| row.as_ref().column_index(name);
+        let id: String = row.get("id")?;
+        let created_at: String = row.get("created_at")?;
+        let updated_at: String = row.get("updated_at")?;
+        let due_date: Option<NaiveDate> = row.get::<_, Option<String>>("due_date")?
+            .map(|d| parse_column(column("due_date")?, &d))
+            .transpose()?;
+        let due_time: Option<NaiveTime> = row.get::<_, Option<String>>("due_time")?
+            .map(|t| parse_time_column(column("due_time")?, &t))
+            .transpose()?;
+        let priority: String = row.get("priority")?;
+        let category: String = row.get("category")?;
+        let completed_at: Option<String> = row.get("completed_at")?;
+        let parent_id: Option<String> = row.get("parent_id")?;
+        let tags_str: Option<String> = row.get("tags")?;
+
+        Ok(Task {
+            id: id.parse().unwrap_or_else(|_| uuid::Uuid::new_v4()),
+            created_at: created_at.parse().unwrap_or_else(|_| chrono::Utc::now()),
+            updated_at: updated_at.parse().unwrap_or_else(|_| chrono::Utc::now()),
+            title: row.get("title")?,
+            notes: row.get("notes")?,
+            due_date,
+            due_time,
+            priority: priority.parse().unwrap_or(Priority::Medium),
+            category: category.parse().unwrap_or(Category::Other),
+            percent_complete: row.get("percent_complete")?,
+            completed_at: completed_at.and_then(|at| at.parse().ok()),
+            parent_id: parent_id.and_then(|id| id.parse().ok()),
+            tags: tags_str
+                .and_then(|s| serde_json::from_str(&s).ok())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Built-in categories merged with the user's own definitions
+    pub fn get_categories(&self) -> AppResult<CategoryRegistry> {
+        let conn = self.connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT name, display_name, color, icon, keywords FROM categories ORDER BY rowid"
         )
+        .map_err(|e| AppError::Database(format!("Prepare failed: {}", e)))?;
+
+        let rows = stmt.query_map([], |row| {
+            let name: String = row.get(0)?;
+            let keywords: Option<String> = row.get(4)?;
+            Ok((name, row.get(1)?, row.get(2)?, row.get(3)?, keywords))
+        })
         .map_err(|e| AppError::Database(format!("Query failed: {}", e)))?;
 
-        for row_result in rows {
-            let other_id = row_result
-                .map_err(|e| AppError::Database(format!("Row read failed: {}", e)))?;
-            
-            // Get the other event to check time overlap
-            if let Ok(Some(other_event)) = self.get_by_id(&other_id) {
-                if let (Some(start1), Some(end1), Some(start2), Some(end2)) = 
-                    (&event.time, &event.end_time, &other_event.time, &other_event.end_time) {
-                    // Check if times overlap
-                    if (start1 < end2 && end1 > start2) || (start2 < end1 && end2 > start1) {
-                        conflicts.push(other_id);
-                    }
-                }
-            }
+        let mut definitions = Vec::new();
+        for row in rows {
+            let (name, display_name, color, icon, keywords): (String, String, String, Option<String>, Option<String>) =
+                row.map_err(|e| AppError::Database(format!("Row read failed: {}", e)))?;
+            // Skip rows whose name is no longer valid rather than failing
+            let Ok(category) = name.parse::<Category>() else { continue };
+            definitions.push(CategoryDefinition {
+                category,
+                display_name,
+                color,
+                icon,
+                keywords: keywords
+                    .and_then(|k| serde_json::from_str(&k).ok())
+                    .unwrap_or_default(),
+            });
         }
 
-        Ok(conflicts)
+        Ok(CategoryRegistry::with_definitions(definitions))
+    }
+
+    /// Add a category, or change a built-in or existing one
+    pub fn save_category(&self, definition: &CategoryDefinition) -> AppResult<()> {
+        self.connection()?.execute(
+            "INSERT INTO categories (name, display_name, color, icon, keywords)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(name) DO UPDATE SET
+                display_name = excluded.display_name,
+                color = excluded.color,
+                icon = excluded.icon,
+                keywords = excluded.keywords",
+            params![
+                definition.category.as_str(),
+                definition.display_name,
+                definition.color,
+                definition.icon,
+                serde_json::to_string(&definition.keywords).ok(),
+            ],
+        )
+        .map_err(|e| AppError::Database(format!("Save category failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Remove a stored category definition; built-ins fall back to their
+    /// defaults. Events keep the category name.
+    pub fn delete_category(&self, category: &Category) -> AppResult<bool> {
+        let rows_affected = self.connection()?.execute(
+            "DELETE FROM categories WHERE name = ?1",
+            [category.as_str()],
+        )
+        .map_err(|e| AppError::Database(format!("Delete category failed: {}", e)))?;
+
+        Ok(rows_affected > 0)
+    }
+
+    /// Free and busy time for `query`, over the expanded occurrences in its
+    /// range, as seen from `zone`
+    pub fn free_busy(&self, query: &FreeBusyQuery, zone: TimeZone) -> AppResult<FreeBusy> {
+        // Events written in other zones can fall on a neighbouring date
+        let (first, last) = query.dates();
+        let events = self.get_by_date_range(first - chrono::Duration::days(1), last + chrono::Duration::days(1))?;
+        Ok(query.compute(&events, zone))
+    }
+
+    /// Up to `limit` ranked times for a new event, around what is already
+    /// scheduled
+    pub fn propose_slots(&self, request: &SlotRequest, zone: TimeZone, limit: usize) -> AppResult<Vec<SlotProposal>> {
+        let (first, last) = request.free_busy_query().dates();
+        let events = self.get_by_date_range(first - chrono::Duration::days(1), last + chrono::Duration::days(1))?;
+        Ok(request.propose(&events, zone, limit))
+    }
+
+    /// Conflicts of `event` with what is scheduled, seen from `zone`.
+    /// Recurring events on either side are compared occurrence by
+    /// occurrence; spans running across midnight or over several days
+    /// count in full.
+    pub fn check_conflicts(&self, event: &CalendarEvent, check: &ConflictCheck, zone: TimeZone) -> AppResult<Vec<Conflict>> {
+        // Events written in other zones, and buffers, can reach into
+        // neighbouring dates
+        let (first, last) = check.dates(event);
+        let others = self.get_by_date_range(first - chrono::Duration::days(1), last + chrono::Duration::days(1))?;
+        Ok(check.find(event, &others, zone))
     }
 }
 
+/// Parse a stored `YYYY-MM-DD` column, reporting bad data as a conversion failure
+fn parse_column(index: usize, value: &str) -> Result<NaiveDate, rusqlite::Error> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(index, rusqlite::types::Type::Text, Box::new(e))
+    })
+}
+
+/// Parse a stored `HH:MM` column
+fn parse_time_column(index: usize, value: &str) -> Result<NaiveTime, rusqlite::Error> {
+    calendar_core::time::parse_hhmm(value).ok_or_else(|| {
+        rusqlite::Error::FromSqlConversionFailure(
+            index,
+            rusqlite::types::Type::Text,
+            format!("invalid time: {}", value).into(),
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::path::PathBuf;
-    use calendar_core::{Priority, Category, RecurrenceConfig, RecurrenceFrequency, ReminderConfig, Location, LocationType};
+    use calendar_core::{Priority, Category, RecurrenceConfig, RecurrenceFrequency, ReminderConfig, ReminderChannel, Location, LocationType, EditScope};
+    use calendar_core::time::TimeZone;
+    use calendar_core::testing::{date, time};
     
     fn create_test_repo() -> CalendarRepository {
         let db_path = PathBuf::from(":memory:");
         CalendarRepository::new(&db_path).unwrap()
     }
     
-    fn create_test_event(title: &str, date: &str) -> CalendarEvent {
-        let mut event = CalendarEvent::new(title.to_string(), date.to_string());
-        event.time = Some("14:00".to_string());
-        event.end_time = Some("15:00".to_string());
+    /// Clock a test moves forward by hand
+    #[derive(Debug)]
+    struct ManualClock(Mutex<chrono::DateTime<chrono::Utc>>);
+    
+    impl ManualClock {
+        fn advance(&self, by: chrono::Duration) {
+            *self.0.lock().unwrap() += by;
+        }
+    }
+    
+    impl calendar_core::time::Clock for ManualClock {
+        fn now(&self) -> chrono::DateTime<chrono::Utc> {
+            *self.0.lock().unwrap()
+        }
+    }
+    
+    fn create_test_event(title: &str, day: &str) -> CalendarEvent {
+        let mut event = CalendarEvent::new(title.to_string(), date(day));
+        event.time = Some(time("14:00"));
+        event.end_time = Some(time("15:00"));
         event
     }
     
+    #[test]
+    fn test_patch_event_keeps_other_fields() {
+        let clock = std::sync::Arc::new(ManualClock(Mutex::new(chrono::Utc::now())));
+        let repo = create_test_repo().with_clock(clock.clone());
+        let mut event = create_test_event("Planning", "2026-01-20");
+        event.notes = Some("Agenda".to_string());
+        repo.save_event(&event).unwrap();
+        let id = event.id.to_string();
+
+        // Two writers each send only their own field
+        repo.patch_event(&id, &serde_json::json!({"notes": "Updated agenda"}), TimeZone::Local).unwrap();
+        repo.patch_event(&id, &serde_json::json!({"time": "16:00", "endTime": "17:00"}), TimeZone::Local).unwrap();
+
+        let stored = repo.get_by_id(&id).unwrap().unwrap();
+        assert_eq!(stored.event, "Planning");
+        assert_eq!(stored.notes.as_deref(), Some("Updated agenda"));
+        assert_eq!(stored.time, Some(time("16:00")));
+
+        // The change is stamped by the repository's clock
+        clock.advance(chrono::Duration::days(1));
+        let now = *clock.0.lock().unwrap();
+        let patched = repo.patch_event(&id, &serde_json::json!({"notes": "Final agenda"}), TimeZone::Local).unwrap();
+        assert_eq!(patched.updated_at, now);
+        assert_eq!(repo.get_by_id(&id).unwrap().unwrap().updated_at, now);
+
+        // Invalid patches leave the row untouched
+        assert!(repo.patch_event(&id, &serde_json::json!({"endTime": "15:00"}), TimeZone::Local).is_err());
+        assert_eq!(repo.get_by_id(&id).unwrap().unwrap().end_time, Some(time("17:00")));
+        assert!(matches!(
+            repo.patch_event(&uuid::Uuid::new_v4().to_string(), &serde_json::json!({}), TimeZone::Local),
+            Err(AppError::NotFound)
+        ));
+    }
+
+    #[test]
+    fn test_attendees_roundtrip_and_rsvp() {
+        use calendar_core::{Attendee, AttendeeRole, ParticipationStatus};
+
+        let repo = create_test_repo();
+        let mut event = create_test_event("Design review", "2026-01-20");
+        event.attendees = vec![
+            Attendee::named("Ana", "ana@example.com").with_role(AttendeeRole::Organizer),
+            Attendee::new("bo@example.com").with_role(AttendeeRole::Optional),
+        ];
+        repo.save_event(&event).unwrap();
+        let id = event.id.to_string();
+
+        assert_eq!(repo.get_by_id(&id).unwrap().unwrap().attendees, event.attendees);
+
+        repo.set_attendee_status(&id, "BO@example.com", ParticipationStatus::Accepted).unwrap();
+        let stored = repo.get_by_id(&id).unwrap().unwrap();
+        assert_eq!(stored.attendees[1].status, ParticipationStatus::Accepted);
+        assert_eq!(stored.organizer().unwrap().name.as_deref(), Some("Ana"));
+
+        assert!(repo.set_attendee_status(&id, "cy@example.com", ParticipationStatus::Declined).is_err());
+    }
+
     #[test]
     fn test_crud_lifecycle() {
         let repo = create_test_repo();
@@ -387,7 +1341,7 @@ mod tests {
         repo.save_event(&create_test_event("Event 2", "2026-01-21")).unwrap();
         repo.save_event(&create_test_event("Event 3", "2026-01-25")).unwrap();
         
-        let events = repo.get_by_date_range("2026-01-20", "2026-01-22").unwrap();
+        let events = repo.get_by_date_range(date("2026-01-20"), date("2026-01-22")).unwrap();
         assert_eq!(events.len(), 2);
     }
     
@@ -400,9 +1354,10 @@ mod tests {
             frequency: RecurrenceFrequency::Weekly,
             interval: 1,
             days_of_week: vec![1, 3, 5],
-            end_date: Some("2026-12-31".to_string()),
+            end_date: Some(date("2026-12-31")),
             occurrences: None,
-            except_dates: vec!["2026-02-10".to_string()],
+            except_dates: vec![date("2026-02-10")],
+            ..Default::default()
         });
         
         repo.save_event(&event).unwrap();
@@ -412,7 +1367,7 @@ mod tests {
         let rec = retrieved.recurring.unwrap();
         assert_eq!(rec.frequency, RecurrenceFrequency::Weekly);
         assert_eq!(rec.days_of_week, vec![1, 3, 5]);
-        assert_eq!(rec.except_dates, vec!["2026-02-10".to_string()]);
+        assert_eq!(rec.except_dates, vec![date("2026-02-10")]);
     }
     
     #[test]
@@ -420,19 +1375,39 @@ mod tests {
         let repo = create_test_repo();
         let mut event = create_test_event("With Reminder", "2026-01-20");
         
-        event.reminder = Some(ReminderConfig {
-            minutes_before: 30,
-            repeat_minutes: Some(10),
-            max_reminders: 5,
-        });
+        event.reminders = vec![
+            ReminderConfig {
+                minutes_before: 30,
+                repeat_minutes: Some(10),
+                max_reminders: 5,
+                ..Default::default()
+            },
+            ReminderConfig::at("2026-01-16T09:00:00Z".parse().unwrap())
+                .with_channel(ReminderChannel::Email),
+        ];
         
         repo.save_event(&event).unwrap();
         let retrieved = repo.get_by_id(&event.id.to_string()).unwrap().unwrap();
         
-        assert!(retrieved.reminder.is_some());
-        let rem = retrieved.reminder.unwrap();
-        assert_eq!(rem.minutes_before, 30);
-        assert_eq!(rem.repeat_minutes, Some(10));
+        assert_eq!(retrieved.reminders, event.reminders);
+        assert_eq!(retrieved.reminders[0].repeat_minutes, Some(10));
+        assert_eq!(retrieved.reminders[1].channel, ReminderChannel::Email);
+    }
+    
+    #[test]
+    fn test_legacy_single_reminder_row() {
+        let repo = create_test_repo();
+        let event = create_test_event("Old Reminder", "2026-01-20");
+        repo.save_event(&event).unwrap();
+        
+        repo.connection().unwrap().execute(
+            "UPDATE events SET reminder = ?1 WHERE id = ?2",
+            params![r#"{"minutesBefore":10,"repeatMinutes":null,"maxReminders":1}"#, event.id.to_string()],
+        ).unwrap();
+        
+        let retrieved = repo.get_by_id(&event.id.to_string()).unwrap().unwrap();
+        assert_eq!(retrieved.reminders.len(), 1);
+        assert_eq!(retrieved.reminders[0].minutes_before, 10);
     }
     
     #[test]
@@ -452,22 +1427,390 @@ mod tests {
         assert!(retrieved.location.is_some());
         assert_eq!(retrieved.location.unwrap().address, "123 Main St");
     }
+
+    #[test]
+    fn test_occurrence_ids_are_stable() {
+        let repo = create_test_repo();
+        let mut event = create_test_event("Weekly sync", "2026-01-06");
+        event.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Weekly,
+            occurrences: Some(4),
+            ..Default::default()
+        });
+        repo.save_event(&event).unwrap();
+        
+        let first = repo.get_by_date_range(date("2026-01-01"), date("2026-01-31")).unwrap();
+        let second = repo.get_by_date_range(date("2026-01-10"), date("2026-01-20")).unwrap();
+        assert_eq!(first[1].id, second[0].id);
+        
+        let occurrence = second[0].occurrence_id().unwrap();
+        assert_eq!(occurrence.to_string(), format!("{}:2026-01-13", event.id));
+        
+        let resolved = repo.get_occurrence(&occurrence).unwrap().unwrap();
+        assert_eq!(resolved.id, second[0].id);
+        assert_eq!(resolved.series_id, Some(event.id));
+        assert_eq!(resolved.date, date("2026-01-13"));
+        
+        // Not an occurrence of the series
+        let missing = OccurrenceId::new(event.id, date("2026-01-14"));
+        assert!(repo.get_occurrence(&missing).unwrap().is_none());
+    }
+    
+    fn save_weekly_series(repo: &CalendarRepository, count: u32) -> CalendarEvent {
+        let mut event = create_test_event("Weekly sync", "2026-01-06");
+        event.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Weekly,
+            occurrences: Some(count),
+            ..Default::default()
+        });
+        repo.save_event(&event).unwrap();
+        event
+    }
+    
+    #[test]
+    fn test_edit_single_occurrence() {
+        let repo = create_test_repo();
+        save_weekly_series(&repo, 4);
+        
+        let mut instances = repo.get_by_date_range(date("2026-01-01"), date("2026-01-31")).unwrap();
+        let mut moved = instances.remove(1);
+        moved.time = Some(time("16:00"));
+        moved.event = "Moved sync".to_string();
+        repo.update_occurrence(&moved, EditScope::This).unwrap();
+        
+        let cancelled = instances[1].occurrence_id().unwrap();
+        assert!(repo.delete_occurrence(&cancelled, EditScope::This).unwrap());
+        
+        let events = repo.get_by_date_range(date("2026-01-01"), date("2026-01-31")).unwrap();
+        let titles: Vec<_> = events.iter().map(|e| (e.date.to_string(), e.event.as_str())).collect();
+        assert_eq!(titles, vec![
+            ("2026-01-06".to_string(), "Weekly sync"),
+            ("2026-01-13".to_string(), "Moved sync"),
+            ("2026-01-27".to_string(), "Weekly sync"),
+        ]);
+        assert_eq!(events[1].id, moved.id);
+        assert_eq!(events[1].time, Some(time("16:00")));
+        
+        let resolved = repo.get_occurrence(&moved.occurrence_id().unwrap()).unwrap().unwrap();
+        assert_eq!(resolved.event, "Moved sync");
+        assert!(repo.get_occurrence(&cancelled).unwrap().is_none());
+    }
+    
+    #[test]
+    fn test_overrides_loaded_for_window_only() {
+        let repo = create_test_repo();
+        let series = save_weekly_series(&repo, 10);
+        let other = save_weekly_series(&repo, 10);
+
+        let instances = repo.get_by_date_range(date("2026-01-01"), date("2026-03-31")).unwrap();
+        let mut moved_in = instances.iter()
+            .find(|e| e.date == date("2026-03-10") && e.series_id == Some(series.id))
+            .unwrap()
+            .clone();
+        moved_in.date = date("2026-01-22");
+        repo.update_occurrence(&moved_in, EditScope::This).unwrap();
+        for event in instances.iter().filter(|e| e.date == date("2026-02-03")) {
+            repo.delete_occurrence(&event.occurrence_id().unwrap(), EditScope::This).unwrap();
+        }
+
+        let conn = repo.connection().unwrap();
+        let window = |from, to| {
+            let mut loaded: Vec<_> = CalendarRepository::load_overrides(&conn, [series.id], date(from), date(from), date(to))
+                .unwrap()
+                .into_keys()
+                .map(|occurrence| occurrence.original_date.to_string())
+                .collect();
+            loaded.sort();
+            loaded
+        };
+        // The moved occurrence is found from its new date as well as its original one
+        assert_eq!(window("2026-01-19", "2026-01-25"), vec!["2026-03-10"]);
+        assert_eq!(window("2026-03-09", "2026-03-15"), vec!["2026-03-10"]);
+        assert_eq!(window("2026-02-01", "2026-02-07"), vec!["2026-02-03"]);
+        assert!(window("2026-02-08", "2026-03-08").is_empty());
+        drop(conn);
+
+        let events = repo.get_by_date_range(date("2026-01-19"), date("2026-01-25")).unwrap();
+        let mut dates: Vec<_> = events.iter().map(|e| (e.date.to_string(), e.series_id == Some(other.id))).collect();
+        dates.sort();
+        assert_eq!(dates, vec![
+            ("2026-01-20".to_string(), false),
+            ("2026-01-20".to_string(), true),
+            ("2026-01-22".to_string(), false),
+        ]);
+    }
+
+    #[test]
+    fn test_edit_this_and_following_splits_series() {
+        let repo = create_test_repo();
+        let series = save_weekly_series(&repo, 4);
+        
+        let instances = repo.get_by_date_range(date("2026-01-01"), date("2026-01-31")).unwrap();
+        let mut edited = instances[2].clone();
+        edited.event = "New format".to_string();
+        repo.update_occurrence(&edited, EditScope::ThisAndFollowing).unwrap();
+        
+        assert_eq!(repo.count().unwrap(), 2);
+        let events = repo.get_by_date_range(date("2026-01-01"), date("2026-01-31")).unwrap();
+        let titles: Vec<_> = events.iter().map(|e| e.event.as_str()).collect();
+        assert_eq!(titles, vec!["Weekly sync", "Weekly sync", "New format", "New format"]);
+        assert_ne!(events[2].series_id, Some(series.id));
+        
+        // Deleting from the second occurrence on leaves only the first
+        let occurrence = events[1].occurrence_id().unwrap();
+        repo.delete_occurrence(&occurrence, EditScope::ThisAndFollowing).unwrap();
+        assert_eq!(repo.get_by_date_range(date("2026-01-01"), date("2026-01-12")).unwrap().len(), 1);
+        assert_eq!(repo.get_by_date_range(date("2026-01-13"), date("2026-01-31")).unwrap().len(), 2);
+    }
+    
+    #[test]
+    fn test_split_keeps_later_overrides() {
+        let repo = create_test_repo();
+        save_weekly_series(&repo, 4);
+        
+        let instances = repo.get_by_date_range(date("2026-01-01"), date("2026-01-31")).unwrap();
+        let mut special = instances[2].clone();
+        special.event = "Special".to_string();
+        repo.update_occurrence(&special, EditScope::This).unwrap();
+        repo.delete_occurrence(&instances[3].occurrence_id().unwrap(), EditScope::This).unwrap();
+        
+        let mut edited = instances[1].clone();
+        edited.event = "New format".to_string();
+        repo.update_occurrence(&edited, EditScope::ThisAndFollowing).unwrap();
+        
+        let events = repo.get_by_date_range(date("2026-01-01"), date("2026-01-31")).unwrap();
+        let titles: Vec<_> = events.iter().map(|e| (e.date.to_string(), e.event.as_str())).collect();
+        assert_eq!(titles, vec![
+            ("2026-01-06".to_string(), "Weekly sync"),
+            ("2026-01-13".to_string(), "New format"),
+            ("2026-01-20".to_string(), "Special"),
+        ]);
+        assert_eq!(events[2].series_id, events[1].series_id);
+        let overrides: i64 = repo.connection().unwrap()
+            .query_row("SELECT COUNT(*) FROM event_overrides", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(overrides, 2);
+    }
+    
+    #[test]
+    fn test_edit_all_occurrences() {
+        let repo = create_test_repo();
+        let series = save_weekly_series(&repo, 3);
+        
+        let instances = repo.get_by_date_range(date("2026-01-01"), date("2026-01-31")).unwrap();
+        let mut edited = instances[1].clone();
+        edited.date = date("2026-01-14");
+        edited.event = "Renamed".to_string();
+        repo.update_occurrence(&edited, EditScope::All).unwrap();
+        
+        let stored = repo.get_by_id(&series.id.to_string()).unwrap().unwrap();
+        assert_eq!(stored.date, date("2026-01-07"));
+        assert_eq!(stored.event, "Renamed");
+        assert_eq!(stored.series_id, None);
+        
+        let occurrence = edited.occurrence_id().unwrap();
+        assert!(repo.delete_occurrence(&occurrence, EditScope::All).unwrap());
+        assert_eq!(repo.count().unwrap(), 0);
+    }
+    
+    #[test]
+    fn test_edit_all_moves_overrides() {
+        let repo = create_test_repo();
+        let series = save_weekly_series(&repo, 3);
+        
+        let instances = repo.get_by_date_range(date("2026-01-01"), date("2026-01-31")).unwrap();
+        let mut special = instances[2].clone();
+        special.event = "Special".to_string();
+        repo.update_occurrence(&special, EditScope::This).unwrap();
+        
+        let mut moved = instances[0].clone();
+        moved.date = date("2026-01-08");
+        repo.update_occurrence(&moved, EditScope::All).unwrap();
+        
+        let events = repo.get_by_date_range(date("2026-01-01"), date("2026-01-31")).unwrap();
+        let titles: Vec<_> = events.iter().map(|e| (e.date.to_string(), e.event.as_str())).collect();
+        assert_eq!(titles, vec![
+            ("2026-01-08".to_string(), "Weekly sync"),
+            ("2026-01-15".to_string(), "Weekly sync"),
+            ("2026-01-22".to_string(), "Special"),
+        ]);
+        assert_eq!(events[2].occurrence_id(), Some(OccurrenceId::new(series.id, date("2026-01-22"))));
+    }
+    
+    #[test]
+    fn test_single_occurrence_must_exist() {
+        let repo = create_test_repo();
+        let series = save_weekly_series(&repo, 3);
+        
+        // Tuesdays only, and not past the third
+        for day in ["2026-01-07", "2026-01-27"] {
+            let stray = OccurrenceId::new(series.id, date(day));
+            assert!(matches!(repo.delete_occurrence(&stray, EditScope::This), Err(AppError::NotFound)));
+            let mut edited = series.instance(date(day));
+            edited.event = "Stray".to_string();
+            assert!(matches!(repo.update_occurrence(&edited, EditScope::This), Err(AppError::NotFound)));
+        }
+        let overrides: i64 = repo.connection().unwrap()
+            .query_row("SELECT COUNT(*) FROM event_overrides", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(overrides, 0);
+    }
+    
+    #[test]
+    fn test_long_running_series_in_range() {
+        let repo = create_test_repo();
+        let mut event = create_test_event("Standup", "2024-01-01");
+        event.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Daily,
+            ..Default::default()
+        });
+        repo.save_event(&event).unwrap();
+        
+        // Well past the first 365 occurrences
+        let events = repo.get_by_date_range(date("2026-03-01"), date("2026-03-31")).unwrap();
+        assert_eq!(events.len(), 31);
+        assert_eq!(events[0].date, date("2026-03-01"));
+        
+        // An occurrence moved into the range from outside it
+        let mut moved = repo.get_by_date_range(date("2026-02-27"), date("2026-02-27")).unwrap().remove(0);
+        moved.date = date("2026-03-02");
+        moved.time = Some(time("17:00"));
+        repo.update_occurrence(&moved, EditScope::This).unwrap();
+        
+        let events = repo.get_by_date_range(date("2026-03-02"), date("2026-03-02")).unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().any(|e| e.recurrence_id == Some(date("2026-02-27"))));
+    }
+    
+    #[test]
+    fn test_multi_day_event_in_range() {
+        let repo = create_test_repo();
+        let mut vacation = CalendarEvent::new("Vacation".to_string(), date("2026-07-27"));
+        vacation.all_day = true;
+        vacation.end_date = Some(date("2026-08-07"));
+        repo.save_event(&vacation).unwrap();
+        
+        // Starts before the range but runs into it
+        let events = repo.get_by_date_range(date("2026-08-01"), date("2026-08-31")).unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].all_day);
+        assert_eq!(events[0].end_date, Some(date("2026-08-07")));
+        
+        assert_eq!(repo.get_by_date(date("2026-08-03")).unwrap().len(), 1);
+        assert!(repo.get_by_date(date("2026-08-08")).unwrap().is_empty());
+    }
+    
+    #[test]
+    fn test_conflict_detection_overnight() {
+        let repo = create_test_repo();
+        let mut shift = create_test_event("Night shift", "2026-01-20");
+        shift.time = Some(time("22:00"));
+        shift.end_time = Some(time("06:00"));
+        shift.end_date = Some(date("2026-01-21"));
+        repo.save_event(&shift).unwrap();
+        
+        let mut early = create_test_event("Early call", "2026-01-21");
+        early.time = Some(time("05:00"));
+        early.end_time = Some(time("05:30"));
+        let check = ConflictCheck::default();
+        let conflicts = repo.check_conflicts(&early, &check, TimeZone::Local).unwrap();
+        let ids: Vec<_> = conflicts.iter().map(|c| c.event_id).collect();
+        assert_eq!(ids, vec![shift.id]);
+        assert_eq!(conflicts[0].overlap_minutes, 30);
+        
+        // All-day events never block time
+        let mut holiday = create_test_event("Holiday", "2026-01-21");
+        holiday.all_day = true;
+        repo.save_event(&holiday).unwrap();
+        early.time = Some(time("07:00"));
+        early.end_time = Some(time("08:00"));
+        assert!(repo.check_conflicts(&early, &check, TimeZone::Local).unwrap().is_empty());
+        
+        let conflicts = repo.check_conflicts(&early, &check.include_all_day(true), TimeZone::Local).unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].kind, calendar_core::ConflictKind::AllDay);
+    }
+    
+    #[test]
+    fn test_conflicts_with_recurring_events_and_buffers() {
+        let repo = create_test_repo();
+        let mut gym = create_test_event("Gym", "2026-03-03");
+        gym.time = Some(time("18:00"));
+        gym.end_time = Some(time("19:00"));
+        gym.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Weekly,
+            ..Default::default()
+        });
+        repo.save_event(&gym).unwrap();
+        
+        // Three weeks later, still an occurrence of the series
+        let mut dinner = create_test_event("Dinner", "2026-03-24");
+        dinner.time = Some(time("19:10"));
+        dinner.end_time = Some(time("21:00"));
+        let check = ConflictCheck::default();
+        assert!(repo.check_conflicts(&dinner, &check, TimeZone::Local).unwrap().is_empty());
+        
+        let conflicts = repo.check_conflicts(&dinner, &check.buffer_minutes(20), TimeZone::Local).unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].kind, calendar_core::ConflictKind::Buffer);
+        assert_eq!(conflicts[0].series_id, Some(gym.id));
+        assert_eq!(conflicts[0].gap_minutes, 10);
+        
+        // A new weekly class runs into every gym session it shares
+        let mut class = create_test_event("Yoga", "2026-03-05");
+        class.time = Some(time("18:30"));
+        class.end_time = Some(time("19:30"));
+        class.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Weekly,
+            occurrences: Some(4),
+            ..Default::default()
+        });
+        assert!(repo.check_conflicts(&class, &check, TimeZone::Local).unwrap().is_empty());
+        class.date = date("2026-03-10");
+        let conflicts = repo.check_conflicts(&class, &check, TimeZone::Local).unwrap();
+        assert_eq!(conflicts.len(), 4);
+        assert!(conflicts.iter().all(|c| c.overlap_minutes == 30 && c.title == "Gym"));
+    }
+    
+    #[test]
+    fn test_timezone_recurrence_across_dst() {
+        let repo = create_test_repo();
+        let mut event = create_test_event("NY standup", "2026-03-02");
+        event.time = Some(time("09:00"));
+        event.timezone = Some("America/New_York".to_string());
+        event.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Weekly,
+            occurrences: Some(2),
+            ..Default::default()
+        });
+        repo.save_event(&event).unwrap();
+
+        let instances = repo.get_by_date_range(date("2026-03-01"), date("2026-03-31")).unwrap();
+        assert_eq!(instances.len(), 2);
+        assert_eq!(instances[1].timezone.as_deref(), Some("America/New_York"));
+
+        // Same wall-clock time, one hour earlier in UTC once DST starts
+        let starts: Vec<String> = instances.iter()
+            .map(|e| e.start_utc(TimeZone::Utc).unwrap().to_rfc3339())
+            .collect();
+        assert_eq!(starts, vec!["2026-03-02T14:00:00+00:00", "2026-03-09T13:00:00+00:00"]);
+    }
     
     #[test]
     fn test_conflict_detection_overlapping() {
         let repo = create_test_repo();
         
         let mut event1 = create_test_event("Event 1", "2026-01-20");
-        event1.time = Some("14:00".to_string());
-        event1.end_time = Some("15:00".to_string());
+        event1.time = Some(time("14:00"));
+        event1.end_time = Some(time("15:00"));
         repo.save_event(&event1).unwrap();
         
         let mut event2 = create_test_event("Event 2", "2026-01-20");
-        event2.time = Some("14:30".to_string());
-        event2.end_time = Some("15:30".to_string());
+        event2.time = Some(time("14:30"));
+        event2.end_time = Some(time("15:30"));
         
-        let conflicts = repo.check_conflicts(&event2).unwrap();
-        assert!(conflicts.contains(&event1.id.to_string()));
+        let conflicts = repo.check_conflicts(&event2, &ConflictCheck::default(), TimeZone::Local).unwrap();
+        assert!(conflicts.iter().any(|c| c.event_id == event1.id));
     }
     
     #[test]
@@ -475,15 +1818,15 @@ mod tests {
         let repo = create_test_repo();
         
         let mut event1 = create_test_event("Event 1", "2026-01-20");
-        event1.time = Some("14:00".to_string());
-        event1.end_time = Some("15:00".to_string());
+        event1.time = Some(time("14:00"));
+        event1.end_time = Some(time("15:00"));
         repo.save_event(&event1).unwrap();
         
         let mut event2 = create_test_event("Event 2", "2026-01-20");
-        event2.time = Some("15:00".to_string());
-        event2.end_time = Some("16:00".to_string());
+        event2.time = Some(time("15:00"));
+        event2.end_time = Some(time("16:00"));
         
-        let conflicts = repo.check_conflicts(&event2).unwrap();
+        let conflicts = repo.check_conflicts(&event2, &ConflictCheck::default(), TimeZone::Local).unwrap();
         assert_eq!(conflicts.len(), 0);
     }
     
@@ -511,12 +1854,13 @@ mod tests {
             end_date: None,
             occurrences: Some(4),
             except_dates: vec![],
+            ..Default::default()
         });
         
         repo.save_event(&recurring_event).unwrap();
         
         // Query should return 4 instances
-        let events = repo.get_by_date_range("2026-01-01", "2026-02-28").unwrap();
+        let events = repo.get_by_date_range(date("2026-01-01"), date("2026-02-28")).unwrap();
         
         let meeting_events: Vec<_> = events.iter()
             .filter(|e| e.event == "Weekly Meeting")
@@ -525,7 +1869,364 @@ mod tests {
         assert_eq!(meeting_events.len(), 4);
         
         // Verify dates are 7 days apart
-        assert_eq!(meeting_events[0].date, "2026-01-20");
-        assert_eq!(meeting_events[1].date, "2026-01-27");
+        assert_eq!(meeting_events[0].date, date("2026-01-20"));
+        assert_eq!(meeting_events[1].date, date("2026-01-27"));
+    }
+    
+    #[test]
+    fn test_free_busy_over_recurring_events() {
+        let repo = create_test_repo();
+        let mut standup = create_test_event("Standup", "2026-03-02");
+        standup.time = Some(time("09:00"));
+        standup.end_time = Some(time("09:30"));
+        standup.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Daily,
+            ..Default::default()
+        });
+        repo.save_event(&standup).unwrap();
+        repo.save_event(&create_test_event("Review", "2026-03-05")).unwrap();
+        
+        // Thursday afternoon
+        let start = date("2026-03-05").and_time(time("12:00"));
+        let query = FreeBusyQuery::new(start, start + chrono::Duration::hours(6))
+            .working_hours(calendar_core::WorkingHours::default());
+        let result = repo.free_busy(&query, TimeZone::Local).unwrap();
+        assert_eq!(result.busy.len(), 1);
+        assert_eq!(result.busy[0].titles, vec!["Review"]);
+        let free: Vec<_> = result.free.iter().map(|slot| (slot.start.time(), slot.end.time())).collect();
+        assert_eq!(free, vec![(time("13:00"), time("14:00")), (time("15:00"), time("17:00"))]);
+        
+        let result = repo.free_busy(&FreeBusyQuery::day(date("2026-03-05")), TimeZone::Local).unwrap();
+        assert_eq!(result.busy.len(), 2);
+        assert_eq!(result.busy[0].titles, vec!["Standup"]);
+    }
+    
+    #[test]
+    fn test_tasks_open_and_complete() {
+        let repo = create_test_repo();
+        
+        let mut parent = Task::new("Move house").due(date("2026-05-30"));
+        parent.priority = Priority::High;
+        let van = Task::subtask_of(&parent, "Book van").due(date("2026-05-01"));
+        let boxes = Task::subtask_of(&parent, "Buy boxes");
+        let mut done = Task::new("Old chore");
+        done.complete(chrono::Utc::now() - chrono::Duration::days(1));
+        for task in [&parent, &van, &boxes, &done] {
+            repo.save_task(task).unwrap();
+        }
+        
+        // Soonest due first, undated last, completed excluded
+        let open: Vec<_> = repo.get_open_tasks().unwrap().into_iter().map(|t| t.title).collect();
+        assert_eq!(open, vec!["Book van", "Move house", "Buy boxes"]);
+        assert_eq!(repo.get_subtasks(&parent.id.to_string()).unwrap().len(), 2);
+        
+        let stored = repo.get_task(&van.id.to_string()).unwrap().unwrap();
+        assert_eq!(stored, van);
+        
+        let completed = repo.complete_task(&parent.id.to_string()).unwrap();
+        assert!(completed.is_completed());
+        assert_eq!(completed.percent_complete, 100);
+        assert!(repo.get_open_tasks().unwrap().is_empty());
+        
+        // Earlier completion times survive
+        let old = repo.get_task(&done.id.to_string()).unwrap().unwrap();
+        assert_eq!(old.completed_at.map(|at| at.timestamp()), done.completed_at.map(|at| at.timestamp()));
+        
+        assert!(matches!(repo.complete_task("missing"), Err(AppError::NotFound)));
+    }
+    
+    #[test]
+    fn test_delete_task_removes_subtasks() {
+        let repo = create_test_repo();
+        let parent = Task::new("Trip");
+        let child = Task::subtask_of(&parent, "Pack");
+        repo.save_task(&parent).unwrap();
+        repo.save_task(&child).unwrap();
+        
+        assert!(repo.delete_task(&parent.id.to_string()).unwrap());
+        assert!(repo.get_tasks().unwrap().is_empty());
+        assert!(!repo.delete_task(&parent.id.to_string()).unwrap());
+    }
+    
+    #[test]
+    fn test_user_defined_categories() {
+        let repo = create_test_repo();
+        assert_eq!(repo.get_categories().unwrap(), CategoryRegistry::default());
+        
+        let garden: Category = "garden".parse().unwrap();
+        let definition = CategoryDefinition::new(garden.clone(), "Garden", "#22C55E")
+            .with_icon("🌱")
+            .with_keywords(["lawn", "plant"]);
+        repo.save_category(&definition).unwrap();
+        repo.save_category(&CategoryDefinition::built_in(Category::Work).with_keywords(["standup"])).unwrap();
+        
+        let registry = repo.get_categories().unwrap();
+        assert_eq!(registry.get(&garden), Some(&definition));
+        assert_eq!(registry.detect("Mow the lawn"), Some(garden.clone()));
+        assert_eq!(registry.detect("Team meeting"), None);
+        
+        // Events keep custom categories instead of falling back to Other
+        let mut event = create_test_event("Plant tomatoes", "2026-04-01");
+        event.category = garden.clone();
+        repo.save_event(&event).unwrap();
+        assert_eq!(repo.get_by_id(&event.id.to_string()).unwrap().unwrap().category, garden);
+        
+        assert!(repo.delete_category(&Category::Work).unwrap());
+        assert_eq!(repo.get_categories().unwrap().detect("Team meeting"), Some(Category::Work));
+    }
+    
+    #[test]
+    fn test_reopen_upgrades_and_refuses_newer_schema() {
+        let db_path = std::env::temp_dir().join(format!("calendar-{}.db", uuid::Uuid::new_v4()));
+        
+        // A database from before the migration runner: original schema, version 1
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE events (
+                    id TEXT PRIMARY KEY, created_at TEXT NOT NULL, updated_at TEXT NOT NULL,
+                    date TEXT NOT NULL, time TEXT, end_time TEXT, event TEXT NOT NULL, notes TEXT,
+                    priority TEXT NOT NULL DEFAULT 'medium', category TEXT NOT NULL DEFAULT 'other',
+                    color TEXT, tags TEXT, status TEXT NOT NULL DEFAULT 'confirmed',
+                    visibility TEXT NOT NULL DEFAULT 'private', recurring TEXT, reminder TEXT,
+                    location TEXT, metadata TEXT NOT NULL DEFAULT '{}'
+                );
+                CREATE TABLE schema_migrations (
+                    version INTEGER PRIMARY KEY,
+                    applied_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+                );
+                INSERT INTO schema_migrations (version) VALUES (1);
+                INSERT INTO events (id, created_at, updated_at, date, time, event)
+                VALUES ('6f9619ff-8b86-d011-b42d-00c04fc964ff', '2026-01-01T00:00:00Z',
+                        '2026-01-01T00:00:00Z', '2026-01-05', '09:00', 'Dentist');",
+            ).unwrap();
+        }
+        
+        let repo = CalendarRepository::new(&db_path).unwrap();
+        let event = repo.get_by_id("6f9619ff-8b86-d011-b42d-00c04fc964ff").unwrap().unwrap();
+        assert_eq!(event.event, "Dentist");
+        assert!(!event.all_day);
+        repo.save_task(&Task::new("Floss")).unwrap();
+        drop(repo);
+        
+        Connection::open(&db_path).unwrap()
+            .execute("INSERT INTO schema_migrations (version) VALUES (?1)", [Migrations::latest_version() + 1])
+            .unwrap();
+        let err = CalendarRepository::new(&db_path).err().unwrap();
+        assert!(err.to_string().contains("newer than this build supports"), "{}", err);
+        
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{}", db_path.display(), suffix));
+        }
+    }
+    
+    #[test]
+    fn test_full_text_search() {
+        let repo = create_test_repo();
+        let mut review = create_test_event("Quarterly review", "2026-03-02");
+        review.tags = vec!["finance".to_string()];
+        let mut lunch = create_test_event("Lunch", "2026-03-03");
+        lunch.notes = Some("Bring the quarterly numbers for review".to_string());
+        lunch.location = Some(Location {
+            location_type: LocationType::Physical,
+            address: "Café Müller, Hauptstraße 5".to_string(),
+            coordinates: None,
+        });
+        let standup = create_test_event("Standup", "2026-03-04");
+        for event in [&review, &lunch, &standup] {
+            repo.save_event(event).unwrap();
+        }
+        let titles = |query: &str| -> Vec<String> {
+            repo.search(&EventQuery::new().text(query)).unwrap().into_iter().map(|hit| hit.event.event).collect()
+        };
+        
+        // Title matches rank above notes matches
+        assert_eq!(titles("quarterly review"), vec!["Quarterly review", "Lunch"]);
+        assert_eq!(titles("\"quarterly review\""), vec!["Quarterly review"]);
+        assert_eq!(titles("stand*"), vec!["Standup"]);
+        assert_eq!(titles("finance"), vec!["Quarterly review"]);
+        assert_eq!(titles("cafe muller"), vec!["Lunch"]);
+        assert!(titles("\"unbalanced AND (").is_empty());
+        // Without search text every event matches
+        assert_eq!(titles("  ").len(), 3);
+        
+        // Other filters narrow the ranked results
+        let work = EventQuery::new().text("quarterly").tag("finance");
+        let hits = repo.search(&work).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].event.event, "Quarterly review");
+        
+        let hits = repo.search(&EventQuery::new().text("numbers")).unwrap();
+        assert!(hits[0].score > 0.0);
+        assert_eq!(hits[0].highlighted("[", "]"), "Bring the quarterly [numbers] for review");
+        
+        // The index follows updates and deletes
+        let mut renamed = standup.clone();
+        renamed.event = "Daily sync".to_string();
+        repo.save_event(&renamed).unwrap();
+        assert!(titles("standup").is_empty());
+        assert_eq!(titles("sync"), vec!["Daily sync"]);
+        repo.patch_event(&lunch.id.to_string(), &serde_json::json!({ "notes": null }), TimeZone::Local).unwrap();
+        assert_eq!(titles("numbers"), Vec::<String>::new());
+        repo.delete_event(&review.id.to_string()).unwrap();
+        assert!(titles("finance").is_empty());
+    }
+    
+    #[test]
+    fn test_query_events() {
+        use calendar_core::EventSort;
+        let repo = create_test_repo();
+        
+        let mut review = create_test_event("Code review", "2026-03-02");
+        review.category = Category::Work;
+        review.priority = Priority::High;
+        review.tags = vec!["Review".to_string()];
+        review.reminders = vec![ReminderConfig::before(10)];
+        let mut dentist = create_test_event("Dentist", "2026-03-05");
+        dentist.category = Category::Health;
+        dentist.location = Some(Location {
+            location_type: LocationType::Physical,
+            address: "12 Tooth Lane".to_string(),
+            coordinates: None,
+        });
+        let mut retro = create_test_event("Retro", "2026-03-05");
+        retro.time = Some(time("09:00"));
+        retro.category = Category::Work;
+        retro.priority = Priority::Urgent;
+        retro.status = EventStatus::Cancelled;
+        let mut weekly = create_test_event("Weekly sync", "2026-01-05");
+        weekly.category = Category::Work;
+        weekly.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Weekly,
+            end_date: Some(date("2026-02-23")),
+            ..Default::default()
+        });
+        let april = create_test_event("April fools", "2026-04-01");
+        for event in [&review, &dentist, &retro, &weekly, &april] {
+            repo.save_event(event).unwrap();
+        }
+        let titles = |query: EventQuery| -> Vec<String> {
+            repo.query_events(&query).unwrap().events.into_iter().map(|e| e.event).collect()
+        };
+        
+        assert_eq!(
+            titles(EventQuery::new()),
+            vec!["Weekly sync", "Code review", "Retro", "Dentist", "April fools"]
+        );
+        // The series ended before March
+        assert_eq!(
+            titles(EventQuery::new().between(date("2026-03-01"), date("2026-03-31"))),
+            vec!["Code review", "Retro", "Dentist"]
+        );
+        assert_eq!(
+            titles(EventQuery::new().between(date("2026-02-01"), date("2026-02-28"))),
+            vec!["Weekly sync"]
+        );
+        assert_eq!(
+            titles(EventQuery::new().category(Category::Work).status(EventStatus::Confirmed)),
+            vec!["Weekly sync", "Code review"]
+        );
+        assert_eq!(titles(EventQuery::new().tag("review").has_reminder(true)), vec!["Code review"]);
+        assert_eq!(titles(EventQuery::new().has_location(true)), vec!["Dentist"]);
+        assert_eq!(titles(EventQuery::new().text("tooth")), vec!["Dentist"]);
+        assert_eq!(
+            titles(EventQuery::new().sort(EventSort::Priority).limit(3)),
+            vec!["Retro", "Code review", "Weekly sync"]
+        );
+        assert_eq!(titles(EventQuery::new().sort(EventSort::Title).offset(3)), vec!["Retro", "Weekly sync"]);
+        
+        // Cursors page through everything exactly once
+        let mut seen = Vec::new();
+        let mut query = EventQuery::new().sort(EventSort::StartDesc).limit(2);
+        loop {
+            let page = repo.query_events(&query).unwrap();
+            assert_eq!(page.total, 5);
+            seen.extend(page.events.into_iter().map(|e| e.event));
+            match page.next_cursor {
+                Some(cursor) => query = query.cursor(cursor),
+                None => break,
+            }
+        }
+        assert_eq!(seen, vec!["April fools", "Dentist", "Retro", "Code review", "Weekly sync"]);
+        
+        assert!(matches!(repo.query_events(&EventQuery::new().cursor("nonsense")), Err(AppError::Validation(_))));
+        assert!(matches!(repo.query_events(&EventQuery::new().limit(0)), Err(AppError::InvalidFields(_))));
+    }
+    
+    #[test]
+    fn test_trash_restore_and_purge() {
+        let clock = std::sync::Arc::new(ManualClock(Mutex::new(chrono::Utc::now())));
+        let repo = create_test_repo().with_clock(clock.clone());
+        let dentist = create_test_event("Dentist", "2026-03-05");
+        let mut standup = create_test_event("Standup", "2026-03-02");
+        standup.recurring = Some(RecurrenceConfig {
+            frequency: RecurrenceFrequency::Daily,
+            ..Default::default()
+        });
+        let old = create_test_event("Old", "2026-01-05");
+        for event in [&dentist, &standup, &old] {
+            repo.save_event(event).unwrap();
+        }
+        let cancelled = OccurrenceId::new(standup.id, date("2026-03-03"));
+        repo.delete_occurrence(&cancelled, EditScope::This).unwrap();
+        let [dentist_id, standup_id, old_id] = [&dentist, &standup, &old].map(|e| e.id.to_string());
+        
+        // Trashed events drop out of every normal query
+        assert!(repo.delete_event(&dentist_id).unwrap());
+        assert!(repo.delete_occurrence(&OccurrenceId::new(standup.id, date("2026-03-04")), EditScope::All).unwrap());
+        assert!(!repo.delete_event(&dentist_id).unwrap());
+        assert!(repo.get_by_id(&dentist_id).unwrap().is_none());
+        assert!(repo.get_by_date(date("2026-03-05")).unwrap().is_empty());
+        assert!(repo.get_by_date_range(date("2026-03-01"), date("2026-03-07")).unwrap().is_empty());
+        assert!(repo.search(&EventQuery::new().text("dentist")).unwrap().is_empty());
+        assert_eq!(repo.query_events(&EventQuery::new()).unwrap().total, 1);
+        assert_eq!(repo.count().unwrap(), 1);
+        assert!(matches!(
+            repo.patch_event(&dentist_id, &serde_json::json!({ "notes": "x" }), TimeZone::Local),
+            Err(AppError::NotFound)
+        ));
+        
+        let trash = repo.get_trash().unwrap();
+        let titles: Vec<_> = trash.iter().map(|t| t.event.event.as_str()).collect();
+        assert_eq!(titles.len(), 2);
+        assert!(titles.contains(&"Dentist") && titles.contains(&"Standup"));
+        assert!(trash.iter().all(|t| t.deleted_at == calendar_core::time::Clock::now(clock.as_ref())));
+        
+        // Saving a trashed event updates it where it is
+        let mut edited = dentist.clone();
+        edited.notes = Some("Bring x-rays".to_string());
+        repo.save_event(&edited).unwrap();
+        assert!(repo.get_by_id(&dentist_id).unwrap().is_none());
+        assert!(repo.get_by_date_range(date("2026-03-01"), date("2026-03-07")).unwrap().is_empty());
+        assert!(repo.search(&EventQuery::new().text("x-rays")).unwrap().is_empty());
+        let trashed = repo.get_trash().unwrap().into_iter().find(|t| t.event.id == dentist.id).unwrap();
+        assert_eq!(trashed.event.notes.as_deref(), Some("Bring x-rays"));
+        
+        // Restoring a series brings back its overrides
+        assert!(repo.restore_event(&standup_id).unwrap());
+        assert!(!repo.restore_event(&standup_id).unwrap());
+        let days: Vec<_> = repo.get_by_date_range(date("2026-03-02"), date("2026-03-04")).unwrap()
+            .into_iter().map(|e| e.date.to_string()).collect();
+        assert_eq!(days, vec!["2026-03-02", "2026-03-04"]);
+        
+        // Only trashed events can be purged
+        assert!(!repo.purge_event(&standup_id).unwrap());
+        assert!(repo.purge_event(&dentist_id).unwrap());
+        assert!(repo.get_trash().unwrap().is_empty());
+        assert!(!repo.restore_event(&dentist_id).unwrap());
+        
+        // Retention purges what has been in the trash longest
+        repo.delete_event(&old_id).unwrap();
+        clock.advance(chrono::Duration::days(40));
+        repo.delete_event(&standup_id).unwrap();
+        assert_eq!(repo.apply_retention(&RetentionPolicy::default()).unwrap(), 1);
+        let trash = repo.get_trash().unwrap();
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].event.id, standup.id);
+        assert_eq!(repo.apply_retention(&RetentionPolicy::days(0)).unwrap(), 1);
+        let overrides: i64 = repo.connection().unwrap()
+            .query_row("SELECT COUNT(*) FROM event_overrides", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(overrides, 0);
     }
 }
@@ -0,0 +1,98 @@
+//! Translating user search text to FTS5 queries and reading back snippets.
+
+use calendar_core::SnippetPart;
+
+/// Wrap matched terms in snippets; control characters cannot appear in the
+/// indexed text, so they never clash with what the user wrote
+pub(crate) const HIGHLIGHT_OPEN: &str = "\u{2}";
+pub(crate) const HIGHLIGHT_CLOSE: &str = "\u{3}";
+
+/// FTS5 `MATCH` expression for what a user typed, or `None` when there is
+/// nothing to search for. Words and `"quoted phrases"` must all match; a
+/// trailing `*` matches as a prefix (`stand*`). Everything is quoted so
+/// punctuation and FTS5 operators in the input are searched as text
+/// instead of failing as syntax.
+pub(crate) fn match_expression(input: &str) -> Option<String> {
+    let mut terms = Vec::new();
+    let mut rest = input.trim_start();
+
+    while !rest.is_empty() {
+        let (text, after) = match rest.strip_prefix('"') {
+            // An unterminated quote runs to the end
+            Some(quoted) => match quoted.find('"') {
+                Some(end) => (&quoted[..end], &quoted[end + 1..]),
+                None => (quoted, ""),
+            },
+            None => {
+                let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                (&rest[..end], &rest[end..])
+            }
+        };
+        let (text, after, prefix) = match (text.strip_suffix('*'), after.strip_prefix('*')) {
+            (Some(stem), _) => (stem, after, true),
+            (None, Some(after)) => (text, after, true),
+            (None, None) => (text, after, false),
+        };
+
+        if text.chars().any(char::is_alphanumeric) {
+            let quoted = format!("\"{}\"", text.replace('"', "\"\""));
+            terms.push(if prefix { quoted + "*" } else { quoted });
+        }
+        rest = after.trim_start();
+    }
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
+}
+
+/// Split a snippet marked with [`HIGHLIGHT_OPEN`] and [`HIGHLIGHT_CLOSE`]
+pub(crate) fn snippet_parts(snippet: &str) -> Vec<SnippetPart> {
+    let mut parts = Vec::new();
+    let mut rest = snippet;
+
+    while !rest.is_empty() {
+        let (text, highlighted, after) = match rest.strip_prefix(HIGHLIGHT_OPEN) {
+            Some(inner) => {
+                let end = inner.find(HIGHLIGHT_CLOSE).unwrap_or(inner.len());
+                (&inner[..end], true, inner[end..].strip_prefix(HIGHLIGHT_CLOSE).unwrap_or(""))
+            }
+            None => {
+                let end = rest.find(HIGHLIGHT_OPEN).unwrap_or(rest.len());
+                (&rest[..end], false, &rest[end..])
+            }
+        };
+        if !text.is_empty() {
+            parts.push(SnippetPart { text: text.to_string(), highlighted });
+        }
+        rest = after;
+    }
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_expression() {
+        assert_eq!(match_expression("team sync").as_deref(), Some(r#""team" "sync""#));
+        assert_eq!(match_expression(r#"stand* "weekly review""#).as_deref(), Some(r#""stand"* "weekly review""#));
+        assert_eq!(match_expression(r#""weekly rev"*"#).as_deref(), Some(r#""weekly rev"*"#));
+        // Operators and stray punctuation are plain text
+        assert_eq!(match_expression("NOT a-b & (c").as_deref(), Some(r#""NOT" "a-b" "(c""#));
+        assert_eq!(match_expression(r#"say "hi"#).as_deref(), Some(r#""say" "hi""#));
+        assert_eq!(match_expression(r#"5" screen"#).as_deref(), Some(r#""5""" "screen""#));
+        assert_eq!(match_expression("  * & "), None);
+    }
+
+    #[test]
+    fn test_snippet_parts() {
+        let parts = snippet_parts("Weekly \u{2}team\u{3} \u{2}sync\u{3}…");
+        let texts: Vec<_> = parts.iter().map(|p| (p.text.as_str(), p.highlighted)).collect();
+        assert_eq!(texts, vec![("Weekly ", false), ("team", true), (" ", false), ("sync", true), ("…", false)]);
+    }
+}